@@ -124,6 +124,7 @@ pub struct Attributes {
     pub missing: Platform,
     pub depends: Vec<String>,
     pub since: String,
+    pub nullable: Vec<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Default)]
@@ -357,6 +357,9 @@ fn parse_member_function_proto(
 
     for inner in pair.clone().into_inner() {
         match inner.as_rule() {
+            Rule::attribute => {
+                parse_attribute(&inner, scratch)?;
+            }
             Rule::kw_protected => {
                 scratch.wip_mem_fn_proto.access = AccessModifier::Protected;
             }
@@ -644,11 +647,10 @@ fn parse_attribute(pair: &pest::iterators::Pair<Rule>, scratch: &mut ScratchData
                             }
                         }
                         Rule::missing_attribute => {
+                            // `platform` is silent, the pairs are the platform keywords
                             scratch.wip_attributes.missing = Platform::None;
                             for p in attr.into_inner() {
-                                if p.as_rule() == Rule::platform {
-                                    scratch.wip_attributes.missing |= str_to_platform(p.as_str());
-                                }
+                                scratch.wip_attributes.missing |= str_to_platform(p.as_str());
                             }
                         }
                         Rule::since_attribute => {
@@ -660,6 +662,16 @@ fn parse_attribute(pair: &pest::iterators::Pair<Rule>, scratch: &mut ScratchData
                                 }
                             }
                         }
+                        Rule::nullable_attribute => {
+                            for target in attr.into_inner() {
+                                if target.as_rule() == Rule::nullable_target {
+                                    scratch
+                                        .wip_attributes
+                                        .nullable
+                                        .push(target.as_str().to_string());
+                                }
+                            }
+                        }
                         _ => {}
                     }
                 }
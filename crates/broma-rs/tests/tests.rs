@@ -129,6 +129,30 @@ fn test_class_bro_contents() {
         .expect("should be function bind");
 }
 
+#[test]
+fn test_missing_attribute() {
+    let result =
+        parse_file(Path::new("testdata/Cocos2d.bro")).expect("failed to parse Cocos2d.bro");
+    let application = result
+        .find_class("cocos2d::CCApplication")
+        .expect("CCApplication not found");
+
+    let root_path = application
+        .find_field("getResourceRootPath")
+        .and_then(|field| field.as_function_bind())
+        .expect("getResourceRootPath not found");
+    assert_eq!(
+        root_path.prototype.attributes.missing,
+        Platform::Android | Platform::IOS
+    );
+
+    let open_url = application
+        .find_field("openURL")
+        .and_then(|field| field.as_function_bind())
+        .expect("openURL not found");
+    assert_eq!(open_url.prototype.attributes.missing, Platform::None);
+}
+
 #[test]
 fn test_free_bro_contents() {
     let result = parse_file(Path::new("testdata/free.bro")).expect("failed to parse free.bro");
@@ -302,6 +326,28 @@ fn test_platform_number_inline() {
     assert!(!bind.inner.is_empty());
 }
 
+#[test]
+fn test_nullable_attribute() {
+    let result = parse_file(Path::new("testdata/class.bro")).expect("failed to parse class.bro");
+    let test_class = result.find_class("Test").expect("Test class not found");
+
+    let maybe_null = test_class
+        .find_field("maybe_null")
+        .expect("maybe_null not found");
+    let bind = maybe_null
+        .as_function_bind()
+        .expect("should be function bind");
+    assert_eq!(
+        bind.prototype.attributes.nullable,
+        vec!["return", "parent", "1"]
+    );
+    assert_eq!(bind.binds.win, 0x10);
+
+    let thing = test_class.find_field("thing").expect("thing not found");
+    let thing_bind = thing.as_function_bind().expect("should be function bind");
+    assert!(thing_bind.prototype.attributes.nullable.is_empty());
+}
+
 #[test]
 fn test_pad_fields() {
     let result = parse_file(Path::new("testdata/class.bro")).expect("failed to parse class.bro");
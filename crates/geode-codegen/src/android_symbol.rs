@@ -1,6 +1,9 @@
 /// Port of bindings/codegen/src/AndroidSymbol.cpp
 use broma_rs::{FunctionBindField, FunctionType};
 
+use crate::declaration::{Builtin, CxxType, Declaration, FunctionKind, NameComponent, TemplateArg};
+use crate::platform::Platform;
+
 fn mangle_ident(s: &str, ne: bool) -> String {
     if s.contains("::") {
         let mut result = if ne { "N".to_string() } else { String::new() };
@@ -213,10 +216,8 @@ fn mangle_type(seen: &mut Vec<String>, name: &str, subs: bool, is_template: bool
     // qualified name
     if name.contains("::") {
         let full_name = mangle_ident(name, true);
-        if subs {
-            if let Some(x) = look_for_seen(seen, &full_name) {
-                return x;
-            }
+        if subs && let Some(x) = look_for_seen(seen, &full_name) {
+            return x;
         }
 
         let mut result = String::new();
@@ -411,3 +412,187 @@ pub fn generate_android_symbols(class_name: &str, func: &FunctionBindField) -> V
         FunctionType::Normal => vec![symbol],
     }
 }
+
+// Itanium mangling for `declaration::Declaration`, which unlike broma types spell out the
+// standard library. Geode links against libc++, whose `std` lives in an inline namespace
+// that differs between the NDK and Apple's toolchain.
+struct DeclarationMangler {
+    seen: Vec<String>,
+    inline_namespace: &'static str,
+    size_type: Builtin,
+}
+
+impl DeclarationMangler {
+    fn substitution(&self, key: &str) -> Option<String> {
+        look_for_seen(&self.seen, key)
+    }
+
+    fn add(&mut self, key: String) {
+        self.seen.push(key);
+    }
+
+    fn libcxx_path(&self, path: &[NameComponent]) -> Vec<NameComponent> {
+        let mut result = path.to_vec();
+        if result
+            .first()
+            .is_some_and(|component| component.name == "std")
+        {
+            let inline = NameComponent {
+                name: self.inline_namespace.to_string(),
+                template_args: None,
+            };
+            result.insert(1, inline);
+            if result
+                .get(2)
+                .is_some_and(|component| component.name == "filesystem")
+            {
+                let fs = NameComponent {
+                    name: "__fs".to_string(),
+                    template_args: None,
+                };
+                result.insert(2, fs);
+            }
+        }
+        result
+    }
+
+    fn prefix(&mut self, path: &[NameComponent]) -> String {
+        let key = format!("{path:?}");
+        if let Some(substitution) = self.substitution(&key) {
+            return substitution;
+        }
+
+        let (last, parent) = path.split_last().unwrap();
+        let mut mangled = match parent {
+            [] => String::new(),
+            [std] if std.name == "std" => "St".to_string(),
+            parent => self.prefix(parent),
+        };
+        mangled.push_str(&mangle_ident(&last.name, false));
+
+        if let Some(args) = &last.template_args {
+            let template_key = format!("{parent:?}::{}", last.name);
+            match self.substitution(&template_key) {
+                Some(substitution) => mangled = substitution,
+                None => self.add(template_key),
+            }
+            mangled.push('I');
+            for arg in args {
+                mangled.push_str(&self.template_arg(arg));
+            }
+            mangled.push('E');
+        }
+
+        self.add(key);
+        mangled
+    }
+
+    fn name(&mut self, path: &[NameComponent]) -> String {
+        let path = self.libcxx_path(path);
+        if let Some(substitution) = self.substitution(&format!("{path:?}")) {
+            return substitution;
+        }
+        let unscoped = path.len() == 1 || (path.len() == 2 && path[0].name == "std");
+        let mangled = self.prefix(&path);
+        if unscoped {
+            mangled
+        } else {
+            format!("N{mangled}E")
+        }
+    }
+
+    fn template_arg(&mut self, arg: &TemplateArg) -> String {
+        match arg {
+            TemplateArg::Type(ty) => self.ty(ty),
+            TemplateArg::Integer(value) => {
+                let code = builtin_code(self.size_type);
+                let value = if self.size_type == Builtin::UnsignedInt {
+                    *value as u32 as u128
+                } else {
+                    *value as u64 as u128
+                };
+                format!("L{code}{value}E")
+            }
+        }
+    }
+
+    fn ty(&mut self, ty: &CxxType) -> String {
+        let (marker, inner) = match ty {
+            CxxType::Builtin(builtin) => return builtin_code(*builtin).to_string(),
+            CxxType::Named { path, .. } => return self.name(path),
+            CxxType::Const(inner) => ('K', inner),
+            CxxType::Pointer(inner) => ('P', inner),
+            CxxType::Reference(inner) => ('R', inner),
+        };
+        let key = format!("{ty:?}");
+        if let Some(substitution) = self.substitution(&key) {
+            return substitution;
+        }
+        let mangled = format!("{marker}{}", self.ty(inner));
+        self.add(key);
+        mangled
+    }
+}
+
+fn builtin_code(builtin: Builtin) -> &'static str {
+    match builtin {
+        Builtin::Void => "v",
+        Builtin::Bool => "b",
+        Builtin::Char => "c",
+        Builtin::SignedChar => "a",
+        Builtin::UnsignedChar => "h",
+        Builtin::Short => "s",
+        Builtin::UnsignedShort => "t",
+        Builtin::Int => "i",
+        Builtin::UnsignedInt => "j",
+        Builtin::Long => "l",
+        Builtin::UnsignedLong => "m",
+        Builtin::LongLong => "x",
+        Builtin::UnsignedLongLong => "y",
+        Builtin::Float => "f",
+        Builtin::Double => "d",
+        Builtin::Size | Builtin::PtrDiff | Builtin::Int64 | Builtin::UInt64 => {
+            unreachable!("platform dependent types are resolved before mangling")
+        }
+    }
+}
+
+pub fn generate_declaration_symbol(decl: &Declaration, platform: Platform) -> String {
+    let mut mangler = DeclarationMangler {
+        seen: Vec::new(),
+        inline_namespace: if matches!(platform, Platform::Android32 | Platform::Android64) {
+            "__ndk1"
+        } else {
+            "__1"
+        },
+        size_type: Builtin::Size.resolve(platform),
+    };
+
+    let unqualified = match decl.kind {
+        FunctionKind::Constructor => "C1".to_string(),
+        FunctionKind::Destructor => "D1".to_string(),
+        _ => mangle_ident(&decl.name, false),
+    };
+
+    let mut mangled = "_Z".to_string();
+    if decl.scope.is_empty() {
+        mangled.push_str(&unqualified);
+    } else {
+        let scope = mangler.libcxx_path(&decl.scope);
+        mangled.push('N');
+        if decl.is_const {
+            mangled.push('K');
+        }
+        mangled.push_str(&mangler.prefix(&scope));
+        mangled.push_str(&unqualified);
+        mangled.push('E');
+    }
+
+    if decl.args.is_empty() {
+        mangled.push('v');
+    }
+    for arg in &decl.args {
+        mangled.push_str(&mangler.ty(arg.strip_const()));
+    }
+    mangled
+}
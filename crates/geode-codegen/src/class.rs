@@ -1,13 +1,19 @@
-use std::collections::HashMap;
-
 use broma_rs::{Class, FieldInner, FunctionType, Root};
 
-use crate::function::generate_member_function;
+use crate::function::{
+    generate_member_function, sanitize_function_name, should_generate_member_function,
+};
 use crate::member::generate_field;
+use crate::overload::FunctionNames;
+use crate::platform::Platform;
+use crate::protocol::{generate_protocol, is_protocol};
+use crate::vtable::{Slot, primary_vtable};
 
 pub fn generate_class(
     class: &Class,
     root: &Root,
+    vtables: &Root,
+    names: &FunctionNames,
     generate_docs: bool,
     generate_prelude: bool,
     import_classes: bool,
@@ -25,7 +31,7 @@ pub fn generate_class(
     }
 
     if generate_docs && !class.attributes.docs.is_empty() {
-        output.push_str(&format!("/// {}\n", class.attributes.docs));
+        output.push_str(&crate::doc_comment(&class.attributes.docs));
     }
 
     let class_name = serialize_name(&class.name);
@@ -85,189 +91,190 @@ pub fn generate_class(
             "impl std::ops::DerefMut for {class_name} {{\n    fn deref_mut(&mut self) -> &mut Self::Target {{\n        &mut self.base\n    }}\n}}\n\n"
         ));
     }
-    output.push_str(&generate_impl_block(class, generate_docs));
+    if derives_from_ccobject(class, root) {
+        output.push_str(&format!(
+            "unsafe impl crate::refs::CocosObject for {class_name} {{\n    const CLASS_NAME: Option<&'static str> = Some({:?});\n}}\n\n",
+            class.name
+        ));
+    }
+    output.push_str(&generate_impl_block(
+        class,
+        root,
+        vtables,
+        names,
+        generate_docs,
+    ));
+    if is_protocol(class) {
+        output.push_str(&generate_protocol(class));
+    }
     output
 }
 
-fn generate_impl_block(class: &Class, generate_docs: bool) -> String {
+fn generate_impl_block(
+    class: &Class,
+    root: &Root,
+    vtables: &Root,
+    names: &FunctionNames,
+    generate_docs: bool,
+) -> String {
     let mut output = String::new();
     let class_name = serialize_name(&class.name);
 
     output.push_str(&format!("impl {} {{\n", class_name));
+    output.push_str(&generate_vtable_consts(class, root, vtables, names));
 
-    let mut function_counts: HashMap<String, usize> = HashMap::new();
-    let mut function_indices: HashMap<String, usize> = HashMap::new();
-
-    for field in &class.fields {
-        if let FieldInner::FunctionBind(func) = &field.inner
-            && func.prototype.fn_type == FunctionType::Normal
-        {
-            let base_name = get_function_base_name(func);
-            *function_counts.entry(base_name).or_insert(0) += 1;
+    for fn_type in [
+        FunctionType::Normal,
+        FunctionType::Constructor,
+        FunctionType::Destructor,
+    ] {
+        for field in &class.fields {
+            if let FieldInner::FunctionBind(func) = &field.inner
+                && func.prototype.fn_type == fn_type
+            {
+                let name = names.get(&(class.name.clone(), field.field_id));
+                let generated = generate_member_function(
+                    func,
+                    class.attributes.links,
+                    &class.name,
+                    class_name,
+                    generate_docs,
+                    name.map(String::as_str),
+                    true,
+                );
+                for line in generated.lines() {
+                    output.push_str("    ");
+                    output.push_str(line);
+                    output.push('\n');
+                }
+            }
         }
     }
 
-    for field in &class.fields {
-        if let FieldInner::FunctionBind(func) = &field.inner
-            && func.prototype.fn_type == FunctionType::Normal
-        {
-            let base_name = get_function_base_name(func);
-            let count = function_counts.get(&base_name).copied().unwrap_or(1);
-            let suffix = if count > 1 {
-                let idx = function_indices.entry(base_name.clone()).or_insert(0);
-                let s = if *idx == 0 {
-                    None
-                } else {
-                    Some(format!("_{}", idx))
-                };
-                *idx += 1;
-                s
-            } else {
-                None
-            };
+    output.push_str("}\n\n");
+    output
+}
 
-            let generated = generate_member_function(
-                func,
-                class.attributes.links,
-                &class.name,
-                class_name,
-                generate_docs,
-                suffix.as_deref(),
-                true,
-            );
-            for line in generated.lines() {
-                output.push_str("    ");
-                output.push_str(line);
-                output.push('\n');
-            }
-        }
+// `VTABLE_SLOTS` and a `<NAME>_SLOT` for every bound virtual the class declares, what
+// #[inherit] overrides clone and patch. None where Broma doesn't describe the vtable, or the
+// virtual doesn't exist on that platform. Overrides of a secondary base's virtual fail to compile
+// once used, C++ calls them through a vtable the overrides don't patch.
+fn generate_vtable_consts(
+    class: &Class,
+    root: &Root,
+    vtables: &Root,
+    names: &FunctionNames,
+) -> String {
+    let virtuals: Vec<_> = class
+        .fields
+        .iter()
+        .filter_map(|field| Some((field.field_id, field.as_function_bind()?)))
+        .filter(|(_, func)| {
+            func.prototype.is_virtual
+                && func.prototype.fn_type == FunctionType::Normal
+                && should_generate_member_function(class.attributes.links, &class.name, func)
+        })
+        .collect();
+    // subclasses without virtuals of their own still need `VTABLE_SLOTS` to be inherited from
+    if !class_has_virtual_functions(class) && class.superclasses.is_empty() {
+        return String::new();
     }
 
-    let mut ctor_count = 0;
-    for field in &class.fields {
-        if let FieldInner::FunctionBind(func) = &field.inner
-            && func.prototype.fn_type == FunctionType::Constructor
-        {
-            ctor_count += 1;
+    let layouts: Option<Vec<_>> = Platform::all()
+        .iter()
+        .map(|&platform| Some((platform, primary_vtable(class, root, vtables, platform)?)))
+        .collect();
+    let Some(layouts) = layouts else {
+        let mut output = "    pub const VTABLE_SLOTS: Option<usize> = None;\n".to_string();
+        for (field_id, func) in virtuals {
+            output.push_str(&format!(
+                "    pub const {}_SLOT: Option<usize> = None;\n",
+                slot_const_prefix(class, names, field_id, &func.prototype.name)
+            ));
         }
-    }
-    let mut ctor_idx = 0;
-    for field in &class.fields {
-        if let FieldInner::FunctionBind(func) = &field.inner
-            && func.prototype.fn_type == FunctionType::Constructor
-        {
-            let suffix = if ctor_count > 1 {
-                let s = if ctor_idx == 0 {
-                    None
-                } else {
-                    Some(format!("_{}", ctor_idx))
+        output.push('\n');
+        return output;
+    };
+
+    let mut output = platform_consts(
+        "VTABLE_SLOTS",
+        layouts
+            .iter()
+            .map(|(platform, layout)| (*platform, format!("Some({})", layout.slots))),
+    );
+    for (field_id, func) in virtuals {
+        output.push_str(&platform_consts(
+            &format!(
+                "{}_SLOT",
+                slot_const_prefix(class, names, field_id, &func.prototype.name)
+            ),
+            layouts.iter().map(|(platform, layout)| {
+                let value = match layout.own.get(&field_id) {
+                    Some(Slot::Primary(slot)) => format!("Some({slot})"),
+                    Some(Slot::Secondary(base)) => format!(
+                        "panic!(\"{}::{} is called through the {base} vtable, which #[override_virtual] doesn't patch\")",
+                        class.name, func.prototype.name
+                    ),
+                    None => "None".to_string(),
                 };
-                ctor_idx += 1;
-                s
-            } else {
-                None
-            };
-            let generated = generate_member_function(
-                func,
-                class.attributes.links,
-                &class.name,
-                class_name,
-                generate_docs,
-                suffix.as_deref(),
-                true,
-            );
-            for line in generated.lines() {
-                output.push_str("    ");
-                output.push_str(line);
-                output.push('\n');
-            }
-        }
+                (*platform, value)
+            }),
+        ));
     }
+    output.push('\n');
+    output
+}
 
-    let mut dtor_count = 0;
-    for field in &class.fields {
-        if let FieldInner::FunctionBind(func) = &field.inner
-            && func.prototype.fn_type == FunctionType::Destructor
-        {
-            dtor_count += 1;
+fn slot_const_prefix(class: &Class, names: &FunctionNames, field_id: usize, name: &str) -> String {
+    names
+        .get(&(class.name.clone(), field_id))
+        .cloned()
+        .unwrap_or_else(|| sanitize_function_name(name))
+        .to_uppercase()
+}
+
+// one const per distinct value, hosts share the 64-bit Itanium layout
+fn platform_consts(name: &str, values: impl Iterator<Item = (Platform, String)>) -> String {
+    let mut groups: Vec<(String, Vec<&str>)> = Vec::new();
+    for (platform, value) in values {
+        let mut conditions = vec![platform.cfg_condition()];
+        if platform == Platform::Android64 {
+            conditions.push(Platform::host_cfg_condition());
         }
-    }
-    let mut dtor_idx = 0;
-    for field in &class.fields {
-        if let FieldInner::FunctionBind(func) = &field.inner
-            && func.prototype.fn_type == FunctionType::Destructor
-        {
-            let suffix = if dtor_count > 1 {
-                let s = if dtor_idx == 0 {
-                    None
-                } else {
-                    Some(format!("_{}", dtor_idx))
-                };
-                dtor_idx += 1;
-                s
-            } else {
-                None
-            };
-            let generated = generate_member_function(
-                func,
-                class.attributes.links,
-                &class.name,
-                class_name,
-                generate_docs,
-                suffix.as_deref(),
-                true,
-            );
-            for line in generated.lines() {
-                output.push_str("    ");
-                output.push_str(line);
-                output.push('\n');
-            }
+        match groups.iter_mut().find(|(group, _)| *group == value) {
+            Some((_, existing)) => existing.extend(conditions),
+            None => groups.push((value, conditions)),
         }
     }
 
-    output.push_str("}\n\n");
-    output
-}
-
-fn get_function_base_name(func: &broma_rs::FunctionBindField) -> String {
-    if func.prototype.fn_type == FunctionType::Constructor {
-        "ctor".to_string()
-    } else if func.prototype.fn_type == FunctionType::Destructor {
-        "dtor".to_string()
-    } else {
-        to_snake_case(&func.prototype.name)
+    let mut output = String::new();
+    for (value, conditions) in groups {
+        output.push_str(&format!(
+            "    #[cfg(any({}))]\n    pub const {name}: Option<usize> = {value};\n",
+            conditions.join(", ")
+        ));
     }
+    output
 }
 
-fn to_snake_case(s: &str) -> String {
-    let mut result = String::with_capacity(s.len());
-    let mut chars = s.chars().peekable();
-    let mut prev_is_lower_or_digit = false;
-    while let Some(c) = chars.next() {
-        if c == ':' && chars.peek() == Some(&':') {
-            chars.next();
-            if !result.is_empty() && !result.ends_with('_') {
-                result.push('_');
-            }
-            prev_is_lower_or_digit = false;
-            continue;
+// Only through primary bases, which keeps the `CCObject` at offset 0
+fn derives_from_ccobject(class: &Class, root: &Root) -> bool {
+    let mut current = class;
+    // broma doesn't rule out cycles
+    for _ in 0..64 {
+        if current.name == "cocos2d::CCObject" {
+            return true;
         }
-        if c.is_uppercase() {
-            let next_is_lower = chars.peek().is_some_and(|n| n.is_lowercase());
-            if !result.is_empty()
-                && (prev_is_lower_or_digit || next_is_lower)
-                && !result.ends_with('_')
-            {
-                result.push('_');
-            }
-            result.extend(c.to_lowercase());
-            prev_is_lower_or_digit = false;
-        } else {
-            result.push(c);
-            prev_is_lower_or_digit = c.is_lowercase() || c.is_ascii_digit();
+        match current
+            .superclasses
+            .first()
+            .and_then(|base| root.find_class(base))
+        {
+            Some(base) => current = base,
+            None => return false,
         }
     }
-    result
+    false
 }
 
 pub fn serialize_name(full_name: &str) -> &str {
@@ -0,0 +1,697 @@
+use anyhow::{Result, bail};
+use broma_rs::AccessModifier;
+
+use crate::platform::Platform;
+use crate::{android_symbol, windows_symbol};
+
+// C++ declarations written the way they read in a header, for functions that are not in
+// broma such as the loader API:
+//
+//   geode::Loader::isModLoaded(std::string_view) const -> bool
+//   static geode::Loader::get() -> geode::Loader*
+//   protected geode::Loader::takeNextMod() -> geode::Mod*
+//   matjson::Value::Value(matjson::Value const&)
+//
+// Types are fully qualified. Records are classes unless written as `struct geode::LoadProblem`
+// and enums have to be written as `enum geode::Loader::LoadingState`, MSVC mangles the three
+// differently. The usual standard library aliases are expanded to the templates they name.
+// The broma manglers guess records from GD naming conventions, which does not work here.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FunctionKind {
+    Method,
+    Virtual,
+    Static,
+    Free,
+    Constructor,
+    Destructor,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Declaration {
+    pub scope: Vec<NameComponent>,
+    pub name: String,
+    pub kind: FunctionKind,
+    pub access: AccessModifier,
+    pub is_const: bool,
+    pub args: Vec<CxxType>,
+    pub ret: CxxType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NameComponent {
+    pub name: String,
+    pub template_args: Option<Vec<TemplateArg>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateArg {
+    Type(CxxType),
+    // non-type arguments are taken to be `size_t`, like array sizes and span extents
+    Integer(i128),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecordKind {
+    Class,
+    Struct,
+    Enum,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CxxType {
+    Builtin(Builtin),
+    Named {
+        kind: RecordKind,
+        path: Vec<NameComponent>,
+    },
+    Const(Box<CxxType>),
+    Pointer(Box<CxxType>),
+    Reference(Box<CxxType>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Builtin {
+    Void,
+    Bool,
+    Char,
+    SignedChar,
+    UnsignedChar,
+    Short,
+    UnsignedShort,
+    Int,
+    UnsignedInt,
+    Long,
+    UnsignedLong,
+    LongLong,
+    UnsignedLongLong,
+    Float,
+    Double,
+    // aliases whose underlying type differs between platforms
+    Size,
+    PtrDiff,
+    Int64,
+    UInt64,
+}
+
+impl Builtin {
+    pub fn resolve(self, platform: Platform) -> Builtin {
+        match (self, platform) {
+            (Builtin::Size, Platform::Windows) => Builtin::UnsignedLongLong,
+            (Builtin::Size, Platform::Android32) => Builtin::UnsignedInt,
+            (Builtin::Size, _) => Builtin::UnsignedLong,
+            (Builtin::PtrDiff, Platform::Windows) => Builtin::LongLong,
+            (Builtin::PtrDiff, Platform::Android32) => Builtin::Int,
+            (Builtin::PtrDiff, _) => Builtin::Long,
+            (Builtin::Int64, Platform::Android64) => Builtin::Long,
+            (Builtin::Int64, _) => Builtin::LongLong,
+            (Builtin::UInt64, Platform::Android64) => Builtin::UnsignedLong,
+            (Builtin::UInt64, _) => Builtin::UnsignedLongLong,
+            (builtin, _) => builtin,
+        }
+    }
+}
+
+impl Declaration {
+    pub fn symbol(&self, platform: Platform) -> String {
+        let decl = self.for_platform(platform);
+        match platform {
+            Platform::Windows => windows_symbol::generate_declaration_symbol(&decl),
+            _ => android_symbol::generate_declaration_symbol(&decl, platform),
+        }
+    }
+
+    pub fn has_this(&self) -> bool {
+        !matches!(self.kind, FunctionKind::Static | FunctionKind::Free)
+    }
+
+    // `geode::dirs::getGameDir()` reads the same as a member function, a binding without
+    // the `this` argument makes it a free one. `const` ones are members either way.
+    pub fn match_binding_args(&mut self, binding_args: usize) -> Result<()> {
+        let cpp_args = self.args.len();
+        if self.kind == FunctionKind::Method && !self.is_const && binding_args == cpp_args {
+            self.kind = FunctionKind::Free;
+        }
+        if binding_args != cpp_args + usize::from(self.has_this()) {
+            let this = if self.has_this() { " plus `this`" } else { "" };
+            bail!(
+                "the declaration takes {cpp_args} argument(s){this}, the binding takes {binding_args}"
+            );
+        }
+        Ok(())
+    }
+
+    fn for_platform(&self, platform: Platform) -> Declaration {
+        Declaration {
+            scope: self
+                .scope
+                .iter()
+                .map(|component| component.for_platform(platform))
+                .collect(),
+            args: self
+                .args
+                .iter()
+                .map(|arg| arg.for_platform(platform))
+                .collect(),
+            ret: self.ret.for_platform(platform),
+            ..self.clone()
+        }
+    }
+}
+
+impl NameComponent {
+    fn new(name: &str, template_args: Option<Vec<TemplateArg>>) -> Self {
+        Self {
+            name: name.to_string(),
+            template_args,
+        }
+    }
+
+    fn for_platform(&self, platform: Platform) -> NameComponent {
+        NameComponent {
+            name: self.name.clone(),
+            template_args: self.template_args.as_ref().map(|args| {
+                args.iter()
+                    .map(|arg| match arg {
+                        TemplateArg::Type(ty) => TemplateArg::Type(ty.for_platform(platform)),
+                        TemplateArg::Integer(value) => TemplateArg::Integer(*value),
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
+impl CxxType {
+    pub fn strip_const(&self) -> &CxxType {
+        match self {
+            CxxType::Const(inner) => inner.strip_const(),
+            ty => ty,
+        }
+    }
+
+    fn for_platform(&self, platform: Platform) -> CxxType {
+        match self {
+            CxxType::Builtin(builtin) => CxxType::Builtin(builtin.resolve(platform)),
+            CxxType::Named { kind, path } => CxxType::Named {
+                kind: *kind,
+                path: path
+                    .iter()
+                    .map(|component| component.for_platform(platform))
+                    .collect(),
+            },
+            CxxType::Const(inner) => CxxType::Const(Box::new(inner.for_platform(platform))),
+            CxxType::Pointer(inner) => CxxType::Pointer(Box::new(inner.for_platform(platform))),
+            CxxType::Reference(inner) => CxxType::Reference(Box::new(inner.for_platform(platform))),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Ident(String),
+    Integer(i128),
+    Punct(&'static str),
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        if c.is_whitespace() {
+            index += 1;
+        } else if c.is_ascii_alphabetic() || c == '_' || c == '~' {
+            let start = index;
+            index += 1;
+            while index < chars.len()
+                && (chars[index].is_ascii_alphanumeric() || chars[index] == '_')
+            {
+                index += 1;
+            }
+            tokens.push(Token::Ident(chars[start..index].iter().collect()));
+        } else if c.is_ascii_digit()
+            || (c == '-' && chars.get(index + 1).is_some_and(char::is_ascii_digit))
+        {
+            let start = index;
+            index += 1;
+            while index < chars.len() && chars[index].is_ascii_digit() {
+                index += 1;
+            }
+            let literal: String = chars[start..index].iter().collect();
+            tokens.push(Token::Integer(literal.parse()?));
+        } else {
+            let punct = match (c, chars.get(index + 1)) {
+                (':', Some(':')) => "::",
+                ('-', Some('>')) => "->",
+                ('<', _) => "<",
+                ('>', _) => ">",
+                ('(', _) => "(",
+                (')', _) => ")",
+                (',', _) => ",",
+                ('*', _) => "*",
+                ('&', _) => "&",
+                _ => bail!("unexpected character `{c}`"),
+            };
+            index += punct.len();
+            tokens.push(Token::Punct(punct));
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn peek_ident(&self) -> Option<&str> {
+        match self.peek() {
+            Some(Token::Ident(ident)) => Some(ident),
+            _ => None,
+        }
+    }
+
+    fn eat_punct(&mut self, punct: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Punct(found)) if *found == punct) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_ident(&mut self, ident: &str) -> bool {
+        if self.peek_ident() == Some(ident) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_punct(&mut self, punct: &str) -> Result<()> {
+        if !self.eat_punct(punct) {
+            bail!("expected `{punct}`, found {}", self.describe());
+        }
+        Ok(())
+    }
+
+    fn ident(&mut self) -> Result<String> {
+        match self.peek() {
+            Some(Token::Ident(ident)) => {
+                let ident = ident.clone();
+                self.position += 1;
+                Ok(ident)
+            }
+            _ => bail!("expected an identifier, found {}", self.describe()),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self.peek() {
+            Some(Token::Ident(ident)) => format!("`{ident}`"),
+            Some(Token::Integer(value)) => format!("`{value}`"),
+            Some(Token::Punct(punct)) => format!("`{punct}`"),
+            None => "the end of the declaration".to_string(),
+        }
+    }
+
+    fn path(&mut self) -> Result<Vec<NameComponent>> {
+        let mut path = Vec::new();
+        loop {
+            let name = self.ident()?;
+            let template_args = if self.eat_punct("<") {
+                Some(self.template_args()?)
+            } else {
+                None
+            };
+            path.push(NameComponent {
+                name,
+                template_args,
+            });
+            if !self.eat_punct("::") {
+                return Ok(path);
+            }
+        }
+    }
+
+    fn template_args(&mut self) -> Result<Vec<TemplateArg>> {
+        let mut args = Vec::new();
+        if self.eat_punct(">") {
+            return Ok(args);
+        }
+        loop {
+            if let Some(Token::Integer(value)) = self.peek() {
+                args.push(TemplateArg::Integer(*value));
+                self.position += 1;
+            } else {
+                args.push(TemplateArg::Type(self.ty()?));
+            }
+            if self.eat_punct(">") {
+                return Ok(args);
+            }
+            self.expect_punct(",")?;
+        }
+    }
+
+    fn ty(&mut self) -> Result<CxxType> {
+        let mut is_const = false;
+        while self.eat_ident("const") {
+            is_const = true;
+        }
+
+        let mut ty = if let Some(builtin) = self.builtin()? {
+            CxxType::Builtin(builtin)
+        } else {
+            let kind = if self.eat_ident("struct") {
+                Some(RecordKind::Struct)
+            } else if self.eat_ident("class") {
+                Some(RecordKind::Class)
+            } else if self.eat_ident("enum") {
+                Some(RecordKind::Enum)
+            } else {
+                None
+            };
+            let path = self.path()?;
+            expand_alias(kind, path)
+        };
+
+        loop {
+            if self.eat_ident("const") {
+                is_const = true;
+                continue;
+            }
+            if is_const {
+                ty = CxxType::Const(Box::new(ty));
+                is_const = false;
+            }
+            if self.eat_punct("*") {
+                ty = CxxType::Pointer(Box::new(ty));
+            } else if self.eat_punct("&") {
+                ty = CxxType::Reference(Box::new(ty));
+            } else {
+                return Ok(ty);
+            }
+        }
+    }
+
+    fn builtin(&mut self) -> Result<Option<Builtin>> {
+        const WORDS: &[&str] = &[
+            "void", "bool", "char", "short", "int", "long", "float", "double", "signed", "unsigned",
+        ];
+
+        if let Some(alias) = self.peek_ident().and_then(builtin_alias) {
+            self.position += 1;
+            return Ok(Some(alias));
+        }
+        // `std::size_t` and friends
+        if self.peek_ident() == Some("std")
+            && self.tokens.get(self.position + 1) == Some(&Token::Punct("::"))
+            && let Some(Token::Ident(ident)) = self.tokens.get(self.position + 2)
+            && let Some(alias) = builtin_alias(ident)
+        {
+            self.position += 3;
+            return Ok(Some(alias));
+        }
+
+        let mut words = Vec::new();
+        while let Some(word) = self.peek_ident().filter(|word| WORDS.contains(word)) {
+            words.push(word.to_string());
+            self.position += 1;
+        }
+        if words.is_empty() {
+            return Ok(None);
+        }
+
+        let builtin = match words.join(" ").as_str() {
+            "void" => Builtin::Void,
+            "bool" => Builtin::Bool,
+            "char" => Builtin::Char,
+            "signed char" => Builtin::SignedChar,
+            "unsigned char" => Builtin::UnsignedChar,
+            "short" | "short int" | "signed short" => Builtin::Short,
+            "unsigned short" | "unsigned short int" => Builtin::UnsignedShort,
+            "int" | "signed" | "signed int" => Builtin::Int,
+            "unsigned" | "unsigned int" => Builtin::UnsignedInt,
+            "long" | "long int" | "signed long" => Builtin::Long,
+            "unsigned long" | "unsigned long int" => Builtin::UnsignedLong,
+            "long long" | "long long int" | "signed long long" => Builtin::LongLong,
+            "unsigned long long" | "unsigned long long int" => Builtin::UnsignedLongLong,
+            "float" => Builtin::Float,
+            "double" => Builtin::Double,
+            other => bail!("unsupported type `{other}`"),
+        };
+        Ok(Some(builtin))
+    }
+}
+
+fn builtin_alias(ident: &str) -> Option<Builtin> {
+    Some(match ident {
+        "int8_t" => Builtin::SignedChar,
+        "uint8_t" => Builtin::UnsignedChar,
+        "int16_t" => Builtin::Short,
+        "uint16_t" => Builtin::UnsignedShort,
+        "int32_t" => Builtin::Int,
+        "uint32_t" => Builtin::UnsignedInt,
+        "int64_t" => Builtin::Int64,
+        "uint64_t" => Builtin::UInt64,
+        "size_t" | "uintptr_t" => Builtin::Size,
+        "ptrdiff_t" | "intptr_t" | "ssize_t" => Builtin::PtrDiff,
+        _ => return None,
+    })
+}
+
+fn named(kind: RecordKind, path: &[&str], args: Vec<TemplateArg>) -> CxxType {
+    let (last, scope) = path.split_last().unwrap();
+    let mut path: Vec<NameComponent> = scope
+        .iter()
+        .map(|name| NameComponent::new(name, None))
+        .collect();
+    path.push(NameComponent::new(last, Some(args)));
+    CxxType::Named { kind, path }
+}
+
+fn char_traits(ty: &CxxType) -> TemplateArg {
+    TemplateArg::Type(named(
+        RecordKind::Struct,
+        &["std", "char_traits"],
+        vec![TemplateArg::Type(ty.clone())],
+    ))
+}
+
+fn allocator(arg: TemplateArg) -> TemplateArg {
+    TemplateArg::Type(named(RecordKind::Class, &["std", "allocator"], vec![arg]))
+}
+
+fn std_struct(name: &str, arg: TemplateArg) -> TemplateArg {
+    TemplateArg::Type(named(RecordKind::Struct, &["std", name], vec![arg]))
+}
+
+fn pair_allocator(key: &TemplateArg, value: &TemplateArg) -> TemplateArg {
+    let TemplateArg::Type(key) = key else {
+        return allocator(value.clone());
+    };
+    allocator(TemplateArg::Type(named(
+        RecordKind::Struct,
+        &["std", "pair"],
+        vec![
+            TemplateArg::Type(CxxType::Const(Box::new(key.clone()))),
+            value.clone(),
+        ],
+    )))
+}
+
+// Fills in the aliases and default template arguments both ABIs mangle in full
+fn expand_alias(kind: Option<RecordKind>, mut path: Vec<NameComponent>) -> CxxType {
+    let names: Vec<String> = path
+        .iter()
+        .map(|component| component.name.clone())
+        .collect();
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    let char_ty = CxxType::Builtin(Builtin::Char);
+
+    match names.as_slice() {
+        ["std", "string"] => {
+            return named(
+                RecordKind::Class,
+                &["std", "basic_string"],
+                vec![
+                    TemplateArg::Type(char_ty.clone()),
+                    char_traits(&char_ty),
+                    allocator(TemplateArg::Type(char_ty)),
+                ],
+            );
+        }
+        ["std", "string_view"] => {
+            return named(
+                RecordKind::Class,
+                &["std", "basic_string_view"],
+                vec![TemplateArg::Type(char_ty.clone()), char_traits(&char_ty)],
+            );
+        }
+        ["geode", "ZStringView"] => {
+            return named(
+                RecordKind::Class,
+                &["geode", "BasicZStringView"],
+                vec![TemplateArg::Type(char_ty)],
+            );
+        }
+        _ => {}
+    }
+
+    let last = path.last_mut().unwrap();
+    if let Some(args) = &mut last.template_args
+        && let Some(first) = args.first().cloned()
+    {
+        match (names.as_slice(), args.len()) {
+            (["std", "basic_string"], 1) => {
+                if let TemplateArg::Type(ty) = &first {
+                    args.push(char_traits(ty));
+                }
+                args.push(allocator(first));
+            }
+            (["std", "basic_string_view"], 1) => {
+                if let TemplateArg::Type(ty) = &first {
+                    args.push(char_traits(ty));
+                }
+            }
+            (["std", "vector" | "deque" | "list"], 1) => args.push(allocator(first)),
+            (["std", "span"], 1) => args.push(TemplateArg::Integer(-1)),
+            (["std", "set"], 1) => {
+                args.push(std_struct("less", first.clone()));
+                args.push(allocator(first));
+            }
+            (["std", "unordered_set"], 1) => {
+                args.push(std_struct("hash", first.clone()));
+                args.push(std_struct("equal_to", first.clone()));
+                args.push(allocator(first));
+            }
+            (["std", "map"], 2) => {
+                let allocator = pair_allocator(&first, &args[1]);
+                args.push(std_struct("less", first));
+                args.push(allocator);
+            }
+            (["std", "unordered_map"], 2) => {
+                let allocator = pair_allocator(&first, &args[1]);
+                args.push(std_struct("hash", first.clone()));
+                args.push(std_struct("equal_to", first));
+                args.push(allocator);
+            }
+            (["geode", "Result"], 1) => {
+                args.push(TemplateArg::Type(expand_alias(
+                    None,
+                    vec![
+                        NameComponent::new("std", None),
+                        NameComponent::new("string", None),
+                    ],
+                )));
+            }
+            _ => {}
+        }
+    }
+
+    let kind = kind.unwrap_or(match names.as_slice() {
+        ["std", "char_traits" | "pair" | "less" | "hash" | "equal_to"] => RecordKind::Struct,
+        _ => RecordKind::Class,
+    });
+    CxxType::Named { kind, path }
+}
+
+pub fn parse_declaration(source: &str) -> Result<Declaration> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        position: 0,
+    };
+
+    let mut kind = FunctionKind::Method;
+    let mut access = AccessModifier::Public;
+    loop {
+        if parser.eat_ident("static") {
+            kind = FunctionKind::Static;
+        } else if parser.eat_ident("virtual") {
+            kind = FunctionKind::Virtual;
+        } else if parser.eat_ident("public") {
+            access = AccessModifier::Public;
+        } else if parser.eat_ident("protected") {
+            access = AccessModifier::Protected;
+        } else if parser.eat_ident("private") {
+            access = AccessModifier::Private;
+        } else {
+            break;
+        }
+    }
+
+    let mut scope = parser.path()?;
+    let function = scope.pop().unwrap();
+    if function.template_args.is_some() {
+        bail!("function templates are not supported");
+    }
+    let mut name = function.name;
+
+    if scope.is_empty() {
+        kind = FunctionKind::Free;
+    } else if let Some(class_name) = name.strip_prefix('~') {
+        if scope.last().is_some_and(|class| class.name != class_name) {
+            bail!("destructor `{name}` does not match its class");
+        }
+        kind = FunctionKind::Destructor;
+        name = class_name.to_string();
+    } else if scope.last().is_some_and(|class| class.name == name) {
+        kind = FunctionKind::Constructor;
+    }
+
+    parser.expect_punct("(")?;
+    let mut args = Vec::new();
+    if !parser.eat_punct(")") {
+        loop {
+            args.push(parser.ty()?);
+            // parameter names are allowed and ignored
+            if parser.peek_ident().is_some_and(|ident| ident != "const") {
+                parser.position += 1;
+            }
+            if parser.eat_punct(")") {
+                break;
+            }
+            parser.expect_punct(",")?;
+        }
+    }
+    if args == [CxxType::Builtin(Builtin::Void)] {
+        args.clear();
+    }
+
+    let is_const = parser.eat_ident("const");
+    let ret = if parser.eat_punct("->") {
+        parser.ty()?
+    } else {
+        CxxType::Builtin(Builtin::Void)
+    };
+    if parser.peek().is_some() {
+        bail!("unexpected {} after the declaration", parser.describe());
+    }
+
+    if is_const && !matches!(kind, FunctionKind::Method | FunctionKind::Virtual) {
+        bail!("only member functions can be const");
+    }
+    if matches!(kind, FunctionKind::Constructor | FunctionKind::Destructor)
+        && ret != CxxType::Builtin(Builtin::Void)
+    {
+        bail!("constructors and destructors have no return type");
+    }
+
+    Ok(Declaration {
+        scope,
+        name,
+        kind,
+        access,
+        is_const,
+        args,
+        ret,
+    })
+}
@@ -10,19 +10,15 @@ use crate::types::{RustType, cpp_to_rust_type};
 const INLINE: isize = -2;
 const UNSPECIFIED: isize = -1;
 
-pub fn generate_free_functions(
-    functions: &[Function],
-    platform: Platform,
-    generate_docs: bool,
-) -> String {
+pub fn generate_free_functions(functions: &[Function], generate_docs: bool) -> String {
     let mut output = String::new();
     output.push_str(
-        "#![allow(unused_imports, non_snake_case, dead_code, unsafe_op_in_unsafe_fn, clippy::missing_safety_doc, clippy::too_many_arguments)]\n\n",
+        "#![allow(unused_imports, non_snake_case, dead_code, unsafe_op_in_unsafe_fn, clippy::missing_safety_doc, clippy::too_many_arguments, unreachable_code)]\n\n",
     );
-    output.push_str("use std::ffi::c_void;\nuse crate::base;\n\n");
+    output.push_str("use std::ffi::c_void;\nuse crate::base;\nuse crate::types::*;\n\n");
 
     for func in functions {
-        let generated = generate_free_function(func, platform, generate_docs);
+        let generated = generate_free_function(func, generate_docs);
         output.push_str(&generated);
         output.push('\n');
     }
@@ -30,14 +26,19 @@ pub fn generate_free_functions(
     output
 }
 
-fn generate_free_function(func: &Function, platform: Platform, generate_docs: bool) -> String {
+fn generate_free_function(func: &Function, generate_docs: bool) -> String {
     let mut output = String::new();
 
-    if generate_docs && !func.prototype.attributes.docs.is_empty() {
-        output.push_str(&format!("/// {}\n", func.prototype.attributes.docs));
+    let name = sanitize_function_name(&func.prototype.name);
+    let linked = func.prototype.attributes.links;
+    let resolvable = Platform::all().iter().copied().any(|platform| {
+        let addr = get_platform_address(&func.binds, platform);
+        addr > 0 || (addr == UNSPECIFIED && is_platform_linked(linked, platform))
+    });
+    if !resolvable {
+        return format!("// {} - inline or unspecified\n", name);
     }
 
-    let name = sanitize_function_name(&func.prototype.name);
     let ret_type = cpp_to_rust_type(&func.prototype.ret.name);
     let args: Vec<(String, String)> = func
         .prototype
@@ -49,13 +50,6 @@ fn generate_free_function(func: &Function, platform: Platform, generate_docs: bo
         })
         .collect();
 
-    let addr = get_platform_address(&func.binds, platform);
-    let linked = func.prototype.attributes.links;
-
-    if addr == INLINE || (addr == UNSPECIFIED && !is_platform_linked(linked, platform)) {
-        return format!("// {} - inline or unspecified\n", name);
-    }
-
     let fn_type_args: Vec<String> = args.iter().map(|(_, ty)| ty.clone()).collect();
     let fn_type = format!(
         "extern \"C\" fn({}) -> {}",
@@ -63,6 +57,22 @@ fn generate_free_function(func: &Function, platform: Platform, generate_docs: bo
         ret_type.to_rust_str()
     );
 
+    output.push_str(&generate_free_function_addresses(&name, func));
+    output.push('\n');
+
+    // the detour signature #[hook_fn] checks hooks against
+    output.push_str(&format!(
+        "pub const {}_FN: crate::loader::FnMarker<{}> = crate::fn_marker!(fn({}) -> {});\n\n",
+        name.to_uppercase(),
+        fn_type,
+        fn_type_args.join(", "),
+        ret_type.to_rust_str()
+    ));
+
+    if generate_docs && !func.prototype.attributes.docs.is_empty() {
+        output.push_str(&crate::doc_comment(&func.prototype.attributes.docs));
+    }
+
     let call_args: Vec<String> = args.iter().map(|(name, _)| name.clone()).collect();
 
     let args_signature = args
@@ -81,10 +91,10 @@ fn generate_free_function(func: &Function, platform: Platform, generate_docs: bo
     ));
 
     output.push_str(&format!(
-        "    let addr = {resolver};\n    if addr == 0 {{\n        return Err(crate::base::SymbolResolveError::new(\"\", \"{name}\"));\n    }}\n    unsafe {{\n        let func: {} = std::mem::transmute(addr);\n        Ok(func({}))\n    }}\n",
+        "    let addr = {}_ADDR();\n    if addr == 0 {{\n        return Err(crate::diagnostics::unresolved(crate::base::SymbolResolveError::new(\"\", \"{name}\")));\n    }}\n    unsafe {{\n        let func: {} = std::mem::transmute(addr);\n        Ok(func({}))\n    }}\n",
+        name.to_uppercase(),
         fn_type,
         call_args_str,
-        resolver = generate_free_function_address_resolver(func, platform)
     ));
     output.push_str("}\n\n");
 
@@ -108,15 +118,11 @@ pub fn generate_member_function(
     full_class_name: &str,
     class_name: &str,
     generate_docs: bool,
-    overload_suffix: Option<&str>,
+    overload_name: Option<&str>,
     is_impl: bool,
 ) -> String {
     let mut output = String::new();
 
-    if generate_docs && !func.prototype.attributes.docs.is_empty() {
-        output.push_str(&format!("/// {}\n", func.prototype.attributes.docs));
-    }
-
     let name = sanitize_function_name(&func.prototype.name);
     let ret_type = member_return_type(func);
 
@@ -126,18 +132,28 @@ pub fn generate_member_function(
     let mut ref_args: Vec<(String, String)> = Vec::new();
 
     if !is_static {
-        fn_type_args.push(("this".to_string(), format!("*mut {}", class_name)));
+        let (this_ptr, this_ref) = if func.prototype.is_const {
+            ("*const", "&")
+        } else {
+            ("*mut", "&mut")
+        };
+        fn_type_args.push(("this".to_string(), format!("{} {}", this_ptr, class_name)));
         if is_impl {
-            ref_args.push(("self".to_string(), format!("&mut {}", class_name)));
+            ref_args.push(("self".to_string(), format!("{} {}", this_ref, class_name)));
         } else {
-            ref_args.push(("this".to_string(), format!("&mut {}", class_name)));
+            ref_args.push(("this".to_string(), format!("{} {}", this_ref, class_name)));
         }
     }
 
-    for arg in &func.prototype.args {
+    let nullable = &func.prototype.attributes.nullable;
+    for (index, arg) in func.prototype.args.iter().enumerate() {
         let ty = cpp_to_rust_type(&arg.ty.name);
         let arg_name = sanitize_arg_name(&arg.name);
-        let (ref_ty, fn_type_ty) = to_ref_types(&ty);
+        let (ref_ty, fn_type_ty) = if is_nullable_arg(nullable, &arg.name, index) {
+            to_nullable_ref_types(&ty)
+        } else {
+            to_ref_types(&ty)
+        };
         fn_type_args.push((arg_name.clone(), fn_type_ty));
         ref_args.push((arg_name, ref_ty));
     }
@@ -146,13 +162,12 @@ pub fn generate_member_function(
         return format!("// {}::{} - inline or unspecified\n", class_name, name);
     }
 
-    let func_name = if func.prototype.fn_type == FunctionType::Constructor {
-        let suffix = overload_suffix.unwrap_or("");
-        format!("{}_ctor{}", to_snake_case(class_name), suffix)
+    let func_name = if let Some(overload_name) = overload_name {
+        overload_name.to_string()
+    } else if func.prototype.fn_type == FunctionType::Constructor {
+        format!("{}_ctor", to_snake_case(class_name))
     } else if func.prototype.fn_type == FunctionType::Destructor {
         format!("{}_dtor", to_snake_case(class_name))
-    } else if let Some(suffix) = overload_suffix {
-        format!("{}{}", name, suffix)
     } else {
         name.clone()
     };
@@ -175,9 +190,37 @@ pub fn generate_member_function(
         ret_type.to_rust_str()
     );
 
+    // the detour signature #[modify] checks hooks against, detours always take `*mut this`
+    let mut detour_args = fn_type_args_str.clone();
+    if !is_static {
+        detour_args[0] = format!("*mut {}", class_name);
+    }
+    output.push_str(&format!(
+        "pub const {name}_FN: crate::loader::FnMarker<extern \"C\" fn({args}) -> {ret}> = crate::fn_marker!(fn({args}) -> {ret});\n\n",
+        name = func_name.to_uppercase(),
+        args = detour_args.join(", "),
+        ret = ret_type.to_rust_str()
+    ));
+
     let mut call_args: Vec<String> = Vec::new();
     for (n, ref_ty) in &ref_args {
-        if ref_ty.starts_with("&mut ") {
+        if let Some(inner) = ref_ty
+            .strip_prefix("Option<&mut ")
+            .and_then(|s| s.strip_suffix('>'))
+        {
+            call_args.push(format!(
+                "{}.map_or(std::ptr::null_mut(), |p| p as *mut {})",
+                n, inner
+            ));
+        } else if let Some(inner) = ref_ty
+            .strip_prefix("Option<&")
+            .and_then(|s| s.strip_suffix('>'))
+        {
+            call_args.push(format!(
+                "{}.map_or(std::ptr::null(), |p| p as *const {})",
+                n, inner
+            ));
+        } else if ref_ty.starts_with("&mut ") {
             call_args.push(format!("{} as *mut _", n));
         } else if ref_ty.starts_with("&") {
             call_args.push(format!("{} as *const _", n));
@@ -190,7 +233,7 @@ pub fn generate_member_function(
         .iter()
         .map(|(n, t)| {
             if n == "self" {
-                "&mut self".to_string()
+                receiver_signature(t)
             } else {
                 format!("{}: {}", n, t)
             }
@@ -199,7 +242,8 @@ pub fn generate_member_function(
         .join(", ");
     let ret_type_str = ret_type.to_rust_str();
     let (wrapper_signature, wrapper_setup, wrapper_args) = wrapper_signature_and_args(&ref_args);
-    let wrapper_ret_type_str = public_return_type(&ret_type);
+    let nullable_return = nullable.iter().any(|target| target == "return");
+    let wrapper_ret_type_str = public_return_type(&ret_type, nullable_return);
     let try_func_name = format!("try_resolve_{func_name}");
     let wrapper_call = if !is_static {
         format!("self.{try_func_name}({wrapper_args})")
@@ -213,7 +257,7 @@ pub fn generate_member_function(
     ));
 
     output.push_str(&format!(
-        "    let addr = {prefix}{addr}();\n    if addr == 0 {{\n        return Err(crate::base::SymbolResolveError::new(\"{class_name}\", \"{func_name}\"));\n    }}\n",
+        "    let addr = {prefix}{addr}();\n    if addr == 0 {{\n        return Err(crate::diagnostics::unresolved(crate::base::SymbolResolveError::new(\"{class_name}\", \"{func_name}\")));\n    }}\n",
         prefix = if is_impl { "Self::" } else { "" },
         addr = addr_const_name,
         class_name = class_name,
@@ -229,6 +273,9 @@ pub fn generate_member_function(
     ));
     output.push_str("}\n\n");
 
+    if generate_docs && !func.prototype.attributes.docs.is_empty() {
+        output.push_str(&crate::doc_comment(&func.prototype.attributes.docs));
+    }
     output.push_str(&format!(
         "#[allow(unused_variables)]\npub fn {}({}) -> {} {{\n",
         func_name, wrapper_signature, wrapper_ret_type_str
@@ -241,13 +288,62 @@ pub fn generate_member_function(
     );
     output.push_str(&format!(
         "    {}\n",
-        wrap_public_return(&ret_type, &resolved)
+        wrap_public_return(&ret_type, nullable_return, &resolved)
     ));
     output.push_str("}\n\n");
 
+    if let Some((suffix, converted_ty, conversion)) = owned_return_conversion(&ret_type) {
+        let forward_args = ref_args
+            .iter()
+            .filter(|(n, _)| n != "self")
+            .map(|(n, _)| n.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let forward_call = if !is_static {
+            format!("self.{func_name}({forward_args})")
+        } else {
+            format!("Self::{func_name}({forward_args})")
+        };
+        output.push_str(&format!(
+            "#[allow(unused_variables)]\npub fn {func_name}_{suffix}({wrapper_signature}) -> {converted_ty} {{\n    {}\n}}\n\n",
+            conversion.replace("{}", &forward_call)
+        ));
+    }
+
     output
 }
 
+// (name suffix, owned type, conversion with `{}` standing in for the raw call)
+pub fn owned_return_conversion(ret_type: &RustType) -> Option<(&'static str, String, String)> {
+    let (inner, by_ref) = match ret_type {
+        RustType::Reference(inner, _) => (inner.as_ref(), true),
+        other => (other, false),
+    };
+    let value = if by_ref { "unsafe { &*{} }" } else { "{}" };
+
+    match inner {
+        RustType::KnownClass(name) if name == "GdString" => Some((
+            "string",
+            "String".to_string(),
+            format!("{value}.to_string()"),
+        )),
+        RustType::Vector(elem) => match elem.as_ref() {
+            RustType::KnownClass(name) if name == "GdString" => Some((
+                "vec",
+                "Vec<String>".to_string(),
+                format!("{value}.iter().map(|s| s.to_string()).collect()"),
+            )),
+            RustType::Primitive(_) | RustType::Pointer(..) => Some((
+                "vec",
+                format!("Vec<{}>", elem.to_rust_str()),
+                format!("{value}.to_vec()"),
+            )),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn wrapper_signature_and_args(ref_args: &[(String, String)]) -> (String, String, String) {
     let mut signature = Vec::new();
     let mut setup = String::new();
@@ -255,7 +351,7 @@ fn wrapper_signature_and_args(ref_args: &[(String, String)]) -> (String, String,
 
     for (name, ty) in ref_args {
         if name == "self" {
-            signature.push("&mut self".to_string());
+            signature.push(receiver_signature(ty));
             continue;
         }
 
@@ -266,6 +362,37 @@ fn wrapper_signature_and_args(ref_args: &[(String, String)]) -> (String, String,
                 "    let {local} = std::ffi::CString::new({name}.as_ref()).expect(\"string contains interior nul byte\");\n"
             ));
             args.push(format!("{local}.as_ptr().cast()"));
+        } else if ty == "GdString" || ty == "&GdString" {
+            signature.push(format!("{name}: impl AsRef<str>"));
+            let local = format!("__{name}_str");
+            setup.push_str(&format!(
+                "    let {local} = GdString::from({name}.as_ref());\n"
+            ));
+            if ty.starts_with('&') {
+                args.push(format!("&{local}"));
+            } else {
+                args.push(local);
+            }
+        } else if let Some(elem) = ty
+            .trim_start_matches('&')
+            .strip_prefix("GdVector<")
+            .and_then(|s| s.strip_suffix('>'))
+        {
+            let (item_ty, item) = if elem == "GdString" {
+                ("impl AsRef<str>", "GdString::from(item.as_ref())")
+            } else {
+                (elem, "item")
+            };
+            signature.push(format!("{name}: impl IntoIterator<Item = {item_ty}>"));
+            let local = format!("__{name}_vec");
+            setup.push_str(&format!(
+                "    let mut {local} = GdVector::new();\n    for item in {name} {{\n        {local}.push_back({item});\n    }}\n"
+            ));
+            if ty.starts_with('&') {
+                args.push(format!("&{local}"));
+            } else {
+                args.push(local);
+            }
         } else {
             signature.push(format!("{name}: {ty}"));
             args.push(name.clone());
@@ -275,7 +402,15 @@ fn wrapper_signature_and_args(ref_args: &[(String, String)]) -> (String, String,
     (signature.join(", "), setup, args.join(", "))
 }
 
-fn public_return_type(ret_type: &RustType) -> String {
+fn receiver_signature(self_ty: &str) -> String {
+    if self_ty.starts_with("&mut ") {
+        "&mut self".to_string()
+    } else {
+        "&self".to_string()
+    }
+}
+
+fn public_return_type(ret_type: &RustType, nullable: bool) -> String {
     match ret_type {
         RustType::Pointer(inner, false)
             if matches!(
@@ -283,13 +418,17 @@ fn public_return_type(ret_type: &RustType) -> String {
                 RustType::KnownClass(_) | RustType::CocosType(_)
             ) =>
         {
-            format!("crate::inherit::Obj<{}>", inner.to_rust_str())
+            if nullable {
+                format!("Option<crate::inherit::Obj<{}>>", inner.to_rust_str())
+            } else {
+                format!("crate::inherit::Obj<{}>", inner.to_rust_str())
+            }
         }
         _ => ret_type.to_rust_str(),
     }
 }
 
-fn wrap_public_return(ret_type: &RustType, value: &str) -> String {
+fn wrap_public_return(ret_type: &RustType, nullable: bool, value: &str) -> String {
     match ret_type {
         RustType::Pointer(inner, false)
             if matches!(
@@ -297,7 +436,11 @@ fn wrap_public_return(ret_type: &RustType, value: &str) -> String {
                 RustType::KnownClass(_) | RustType::CocosType(_)
             ) =>
         {
-            format!("crate::inherit::Obj::from_raw({value})")
+            if nullable {
+                format!("std::ptr::NonNull::new({value}).map(crate::inherit::Obj::from_non_null)")
+            } else {
+                format!("crate::inherit::Obj::from_raw({value})")
+            }
         }
         _ => value.to_string(),
     }
@@ -321,10 +464,49 @@ fn to_ref_types(ty: &crate::types::RustType) -> (String, String) {
                 }
             }
         }
+        RustType::Reference(inner, is_const) => {
+            let inner_str = inner.to_rust_str();
+            if *is_const {
+                (format!("&{}", inner_str), format!("*const {}", inner_str))
+            } else {
+                (format!("&mut {}", inner_str), format!("*mut {}", inner_str))
+            }
+        }
         _ => (ty.to_rust_str(), ty.to_rust_str()),
     }
 }
 
+fn is_nullable_arg(nullable: &[String], name: &str, index: usize) -> bool {
+    nullable
+        .iter()
+        .any(|target| target == name || target.parse::<usize>() == Ok(index))
+}
+
+fn to_nullable_ref_types(ty: &RustType) -> (String, String) {
+    match ty {
+        RustType::Pointer(inner, is_const)
+            if matches!(
+                inner.as_ref(),
+                RustType::KnownClass(_) | RustType::CocosType(_)
+            ) =>
+        {
+            let inner_str = inner.to_rust_str();
+            if *is_const {
+                (
+                    format!("Option<&{}>", inner_str),
+                    format!("*const {}", inner_str),
+                )
+            } else {
+                (
+                    format!("Option<&mut {}>", inner_str),
+                    format!("*mut {}", inner_str),
+                )
+            }
+        }
+        _ => to_ref_types(ty),
+    }
+}
+
 pub fn generate_platform_addresses_const(
     func_name: &str,
     binds: &PlatformNumber,
@@ -386,12 +568,25 @@ pub fn generate_platform_addresses_const(
         class_name,
         func,
     ));
+    output.push_str(&generate_host_branch(full_class_name, func));
 
     output.push_str("    0\n}\n");
 
     output
 }
 
+// Hosts have no game to bind to, the Itanium symbols let a `SymbolProvider` hand out fakes
+fn generate_host_branch(full_class_name: &str, func: &FunctionBindField) -> String {
+    if !supports_symbol_signature(func) {
+        return String::new();
+    }
+    format!(
+        "    #[cfg({})]{}",
+        Platform::host_cfg_condition(),
+        generate_android_symbol_resolver(full_class_name, func)
+    )
+}
+
 fn combined_links(class_links: BromaPlatform, function_links: BromaPlatform) -> BromaPlatform {
     class_links | function_links
 }
@@ -407,13 +602,25 @@ fn get_platform_address(binds: &PlatformNumber, platform: Platform) -> isize {
     }
 }
 
-fn generate_free_function_address_resolver(func: &Function, platform: Platform) -> String {
-    let addr = get_platform_address(&func.binds, platform);
-    if addr > 0 {
-        format!("crate::base::get() + 0x{addr:x}")
-    } else {
-        "0".to_string()
+// Free functions are only bound by address, the ones without one on a platform can't be
+// resolved or hooked there
+fn generate_free_function_addresses(func_name: &str, func: &Function) -> String {
+    let mut output = String::new();
+    output.push_str(&format!(
+        "pub fn {}_ADDR() -> usize {{\n",
+        func_name.to_uppercase()
+    ));
+    for platform in Platform::all().iter().copied() {
+        let addr = get_platform_address(&func.binds, platform);
+        output.push_str(&format!("    #[cfg({})]", platform.cfg_condition()));
+        if addr > 0 {
+            output.push_str(&format!(" {{ return crate::base::get() + 0x{addr:x}; }}\n"));
+        } else {
+            output.push_str(" { return 0; }\n");
+        }
     }
+    output.push_str("    0\n}\n");
+    output
 }
 
 fn generate_platform_branch(
@@ -473,17 +680,43 @@ fn generate_platform_branch(
     output
 }
 
-fn should_generate_member_function(
+pub fn should_generate_member_function(
+    class_links: BromaPlatform,
+    full_class_name: &str,
+    func: &FunctionBindField,
+) -> bool {
+    Platform::all()
+        .iter()
+        .any(|&platform| is_bound(platform, class_links, full_class_name, func))
+}
+
+// the platforms whose `_ADDR` has an address or a symbol to look up, as a cfg condition.
+// Elsewhere it's 0 without trying, which isn't a failure to resolve.
+pub fn bound_cfg_condition(
+    class_links: BromaPlatform,
+    full_class_name: &str,
+    func: &FunctionBindField,
+) -> String {
+    let mut conditions: Vec<&str> = Platform::all()
+        .iter()
+        .filter(|&&platform| is_bound(platform, class_links, full_class_name, func))
+        .map(|platform| platform.cfg_condition())
+        .collect();
+    if supports_symbol_signature(func) {
+        conditions.push(Platform::host_cfg_condition());
+    }
+    format!("any({})", conditions.join(", "))
+}
+
+fn is_bound(
+    platform: Platform,
     class_links: BromaPlatform,
     full_class_name: &str,
     func: &FunctionBindField,
 ) -> bool {
     let links = combined_links(class_links, func.prototype.attributes.links);
-    Platform::all().iter().copied().any(|platform| {
-        let addr = get_platform_address(&func.binds, platform);
-        addr > 0
-            || (addr == UNSPECIFIED && can_resolve_symbol(platform, links, full_class_name, func))
-    })
+    let addr = get_platform_address(&func.binds, platform);
+    addr > 0 || (addr == UNSPECIFIED && can_resolve_symbol(platform, links, full_class_name, func))
 }
 
 fn absolute_address_expr(platform: Platform, class_name: &str, addr: usize) -> String {
@@ -512,7 +745,7 @@ fn is_in_extensions_dll(class_name: &str) -> bool {
     class_name.contains("cocos2d::extension")
 }
 
-fn is_cocos_class(class_name: &str) -> bool {
+pub(crate) fn is_cocos_class(class_name: &str) -> bool {
     class_name.contains("cocos2d")
         || class_name.contains("pugi::")
         || matches!(
@@ -594,7 +827,7 @@ fn supports_symbol_signature(func: &FunctionBindField) -> bool {
             .all(|arg| supports_symbol_arg_type(&cpp_to_rust_type(&arg.ty.name)))
 }
 
-fn member_return_type(func: &FunctionBindField) -> RustType {
+pub fn member_return_type(func: &FunctionBindField) -> RustType {
     match func.prototype.fn_type {
         FunctionType::Constructor | FunctionType::Destructor => RustType::Primitive("()".into()),
         FunctionType::Normal => cpp_to_rust_type(&func.prototype.ret.name),
@@ -732,7 +965,7 @@ fn supports_symbol_pointee_type(ty: &RustType) -> bool {
     )
 }
 
-fn sanitize_function_name(name: &str) -> String {
+pub fn sanitize_function_name(name: &str) -> String {
     if name == "new" {
         return "create".to_string();
     }
@@ -742,7 +975,7 @@ fn sanitize_function_name(name: &str) -> String {
     sanitize_ident(&to_snake_case(name))
 }
 
-fn sanitize_arg_name(name: &str) -> String {
+pub fn sanitize_arg_name(name: &str) -> String {
     if name.is_empty() {
         return "_arg".to_string();
     }
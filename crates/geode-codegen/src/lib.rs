@@ -1,9 +1,14 @@
 pub mod android_symbol;
 pub mod class;
+pub mod declaration;
 pub mod function;
 pub mod member;
+pub mod overload;
 pub mod platform;
+pub mod protocol;
+pub mod reflection;
 pub mod types;
+pub mod vtable;
 pub mod windows_symbol;
 
 use std::collections::HashMap;
@@ -16,10 +21,12 @@ use broma_rs::Root;
 pub struct Config {
     pub broma_paths: Vec<PathBuf>,
     pub output_dir: PathBuf,
-    pub platform: Option<platform::Platform>,
     pub generate_docs: bool,
     pub separate_files: bool,
     pub use_cocos_bindgen: bool,
+    pub overload_table: Option<PathBuf>,
+    // every virtual of the cocos classes in header order, what their vtables are laid out from
+    pub cocos_vtables: Option<PathBuf>,
 }
 
 pub fn generate(config: Config) -> Result<()> {
@@ -30,6 +37,10 @@ pub fn generate(config: Config) -> Result<()> {
     }
 
     let merged = merge_roots(&roots);
+    let vtables = match &config.cocos_vtables {
+        Some(path) => broma_rs::parse_file(path)?,
+        None => Root::default(),
+    };
 
     let class_names: Vec<String> = merged
         .classes
@@ -38,9 +49,14 @@ pub fn generate(config: Config) -> Result<()> {
         .collect();
     types::register_classes(&class_names);
 
-    std::fs::create_dir_all(&config.output_dir)?;
+    let overload_table = config
+        .overload_table
+        .as_deref()
+        .map(overload::OverloadTable::load)
+        .transpose()?;
+    let function_names = overload::assign_function_names(&merged.classes, overload_table.as_ref())?;
 
-    let platform = config.platform.unwrap_or_else(detect_platform);
+    std::fs::create_dir_all(&config.output_dir)?;
 
     let classes_dir = config.output_dir.join("classes");
     let functions_dir = config.output_dir.join("functions");
@@ -62,7 +78,15 @@ pub fn generate(config: Config) -> Result<()> {
                 base_module_name.clone()
             };
 
-            let output = class::generate_class(class, &merged, config.generate_docs, true, true);
+            let output = class::generate_class(
+                class,
+                &merged,
+                &vtables,
+                &function_names,
+                config.generate_docs,
+                true,
+                true,
+            );
             let file_path = classes_dir.join(format!("{module_name}.rs"));
             std::fs::write(&file_path, output)?;
             class_modules.push((module_name, class::serialize_name(&class.name).to_string()));
@@ -75,7 +99,15 @@ pub fn generate(config: Config) -> Result<()> {
         let mut first = true;
 
         for class in &merged.classes {
-            let output = class::generate_class(class, &merged, config.generate_docs, first, false);
+            let output = class::generate_class(
+                class,
+                &merged,
+                &vtables,
+                &function_names,
+                config.generate_docs,
+                first,
+                false,
+            );
             classes_output.push_str(&output);
             classes_output.push('\n');
             first = false;
@@ -85,13 +117,16 @@ pub fn generate(config: Config) -> Result<()> {
     }
 
     let functions_output =
-        function::generate_free_functions(&merged.functions, platform, config.generate_docs);
+        function::generate_free_functions(&merged.functions, config.generate_docs);
     std::fs::write(functions_dir.join("global.rs"), functions_output)?;
     std::fs::write(
         functions_dir.join("mod.rs"),
         "#![allow(unused_imports)]\nmod global;\npub use global::*;\n",
     )?;
 
+    let reflection_output = reflection::generate_reflection(&merged.classes, &function_names);
+    std::fs::write(config.output_dir.join("reflection.rs"), reflection_output)?;
+
     let types_output = types::generate_types_mod(config.use_cocos_bindgen);
     std::fs::write(config.output_dir.join("types.rs"), types_output)?;
 
@@ -119,26 +154,6 @@ fn merge_roots(roots: &[Root]) -> Root {
     merged
 }
 
-fn detect_platform() -> platform::Platform {
-    cfg_if::cfg_if! {
-        if #[cfg(target_os = "windows")] {
-            platform::Platform::Windows
-        } else if #[cfg(all(target_os = "macos", target_arch = "x86_64"))] {
-            platform::Platform::MacIntel
-        } else if #[cfg(all(target_os = "macos", target_arch = "aarch64"))] {
-            platform::Platform::MacArm
-        } else if #[cfg(target_os = "ios")] {
-            platform::Platform::IOS
-        } else if #[cfg(all(target_os = "android", target_arch = "arm"))] {
-            platform::Platform::Android32
-        } else if #[cfg(all(target_os = "android", target_arch = "aarch64"))] {
-            platform::Platform::Android64
-        } else {
-            platform::Platform::Windows
-        }
-    }
-}
-
 pub fn to_snake_case(s: &str) -> String {
     let mut result = String::with_capacity(s.len());
     let mut chars = s.chars().peekable();
@@ -170,6 +185,10 @@ pub fn to_snake_case(s: &str) -> String {
     result
 }
 
+pub fn doc_comment(docs: &str) -> String {
+    docs.lines().map(|line| format!("/// {line}\n")).collect()
+}
+
 fn generate_classes_mod(modules: &[(String, String)]) -> String {
     let mut output = String::new();
     output.push_str("#![allow(unused_imports)]\n\n");
@@ -189,8 +208,9 @@ fn generate_classes_mod(modules: &[(String, String)]) -> String {
 
 fn generate_root_mod(use_cocos_bindgen: bool) -> String {
     if use_cocos_bindgen {
-        "pub mod cocos;\npub mod types;\npub mod classes;\npub mod functions;\n".to_string()
+        "pub mod cocos;\npub mod types;\npub mod classes;\npub mod functions;\npub mod reflection;\n"
+            .to_string()
     } else {
-        "pub mod types;\npub mod classes;\npub mod functions;\n".to_string()
+        "pub mod types;\npub mod classes;\npub mod functions;\npub mod reflection;\n".to_string()
     }
 }
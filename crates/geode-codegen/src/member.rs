@@ -9,25 +9,22 @@ pub fn generate_member_field(field: &MemberField) -> String {
     let type_str = rust_type.to_rust_str();
     let name = sanitize_member_name(&field.name);
 
+    match member_field_cfg(field) {
+        Some(cfg) => format!("    #[cfg({})]\n    pub {}: {},", cfg, name, type_str),
+        None => format!("    pub {}: {},", name, type_str),
+    }
+}
+
+pub fn member_field_cfg(field: &MemberField) -> Option<String> {
     let platforms = broma_platform_to_codegen(field.platform);
 
     if platforms.is_empty() || field.platform == BromaPlatform::All {
-        format!("    pub {}: {},", name, type_str)
+        None
     } else if platforms.len() == 1 {
-        format!(
-            "    #[cfg({})]\n    pub {}: {},",
-            platforms[0].cfg_condition(),
-            name,
-            type_str
-        )
+        Some(platforms[0].cfg_condition().to_string())
     } else {
         let conditions: Vec<&str> = platforms.iter().map(|p| p.cfg_condition()).collect();
-        format!(
-            "    #[cfg(any({}))]\n    pub {}: {},",
-            conditions.join(", "),
-            name,
-            type_str
-        )
+        Some(format!("any({})", conditions.join(", ")))
     }
 }
 
@@ -0,0 +1,342 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use broma_rs::{Class, FunctionBindField, FunctionType};
+
+use crate::class::serialize_name;
+use crate::function::{
+    member_return_type, owned_return_conversion, sanitize_function_name,
+    should_generate_member_function,
+};
+use crate::to_snake_case;
+
+pub type FunctionNames = HashMap<(String, usize), String>;
+
+/// Each non-comment line has the form `Class::function(arg, types) [const] = rust_name`.
+#[derive(Debug, Clone, Default)]
+pub struct OverloadTable {
+    entries: HashMap<String, String>,
+}
+
+impl OverloadTable {
+    pub fn load(path: &Path) -> Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn parse(source: &str) -> Result<Self> {
+        let mut entries = HashMap::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((signature, name)) = line.rsplit_once('=') else {
+                bail!(
+                    "overload table line {}: expected `signature = name`",
+                    index + 1
+                );
+            };
+            let (signature, name) = (signature.trim(), name.trim());
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                bail!("overload table line {}: invalid name `{name}`", index + 1);
+            }
+            if entries
+                .insert(signature.to_string(), name.to_string())
+                .is_some()
+            {
+                bail!(
+                    "overload table line {}: duplicate entry for `{signature}`",
+                    index + 1
+                );
+            }
+        }
+
+        Ok(Self { entries })
+    }
+
+    pub fn get(&self, signature: &str) -> Option<&str> {
+        self.entries.get(signature).map(String::as_str)
+    }
+}
+
+// Overloads are named from their parameter types. With a table, every overload must be
+// listed so reordering or adding declarations can never silently rename public API.
+// Overrides of a virtual take the name of the function they override, so calling it through
+// any class in the hierarchy reaches the same C++ function.
+pub fn assign_function_names(
+    classes: &[Class],
+    table: Option<&OverloadTable>,
+) -> Result<FunctionNames> {
+    let by_name: HashMap<&str, &Class> = classes
+        .iter()
+        .map(|class| (class.name.as_str(), class))
+        .collect();
+    let mut names = FunctionNames::new();
+    let mut from_table = HashSet::new();
+    let mut missing = HashSet::new();
+    let mut used_entries = HashSet::new();
+    let mut generated: Vec<(&Class, Vec<(usize, &FunctionBindField)>)> = Vec::new();
+
+    for class in classes {
+        let class_name = serialize_name(&class.name);
+        let functions = generated_functions(class);
+
+        let mut base_counts: HashMap<String, usize> = HashMap::new();
+        for (_, func) in &functions {
+            *base_counts.entry(base_name(class_name, func)).or_insert(0) += 1;
+        }
+
+        for (field_id, func) in &functions {
+            let base = base_name(class_name, func);
+            let signature = overload_signature(&class.name, func);
+            let key = (class.name.clone(), *field_id);
+
+            let name = if base_counts[&base] == 1 {
+                base
+            } else if let Some(table) = table {
+                match table.get(&signature) {
+                    Some(name) => {
+                        used_entries.insert(signature);
+                        from_table.insert(key.clone());
+                        name.to_string()
+                    }
+                    None => {
+                        missing.insert(key.clone());
+                        derive_overload_name(&base, func)
+                    }
+                }
+            } else {
+                derive_overload_name(&base, func)
+            };
+            names.insert(key, name);
+        }
+        generated.push((class, functions));
+    }
+
+    // roots are never renamed here, so the order overrides are visited in doesn't matter
+    let mut mismatches = Vec::new();
+    for (class, functions) in &generated {
+        for (field_id, func) in functions {
+            let mut root = None;
+            let mut current = (*class, *func);
+            while let Some((base_class, base_id, base_func)) =
+                overridden(&by_name, &names, current.0, current.1)
+            {
+                root = Some((base_class, base_id));
+                current = (base_class, base_func);
+            }
+            let Some((root_class, root_id)) = root else {
+                continue;
+            };
+
+            let key = (class.name.clone(), *field_id);
+            let inherited = names[&(root_class.name.clone(), root_id)].clone();
+            if from_table.contains(&key) && names[&key] != inherited {
+                mismatches.push(format!(
+                    "{} = {} overrides {}::{}, which is named {inherited}",
+                    overload_signature(&class.name, func),
+                    names[&key],
+                    root_class.name,
+                    func.prototype.name
+                ));
+            }
+            names.insert(key, inherited);
+        }
+    }
+
+    let mut collisions = Vec::new();
+    for (class, functions) in &generated {
+        let class_name = serialize_name(&class.name);
+        let mut taken: HashMap<String, String> = HashMap::new();
+        for (field_id, func) in functions {
+            let name = &names[&(class.name.clone(), *field_id)];
+            let signature = overload_signature(&class.name, func);
+            let mut generated_names = vec![name.clone()];
+            if let Some((suffix, _, _)) = owned_return_conversion(&member_return_type(func)) {
+                generated_names.push(format!("{name}_{suffix}"));
+            }
+            for generated_name in generated_names {
+                if let Some(other) = taken.insert(generated_name.clone(), signature.clone()) {
+                    collisions.push(format!(
+                        "{class_name}::{generated_name} is generated for both `{other}` and `{signature}`"
+                    ));
+                }
+            }
+        }
+    }
+
+    let mut errors = Vec::new();
+    if !missing.is_empty() {
+        let missing: Vec<String> = generated
+            .iter()
+            .flat_map(|(class, functions)| {
+                functions
+                    .iter()
+                    .map(move |(field_id, func)| (*class, *field_id, *func))
+            })
+            .filter(|(class, field_id, _)| missing.contains(&(class.name.clone(), *field_id)))
+            .map(|(class, field_id, func)| {
+                format!(
+                    "{} = {}",
+                    overload_signature(&class.name, func),
+                    names[&(class.name.clone(), field_id)]
+                )
+            })
+            .collect();
+        errors.push(format!(
+            "overloaded functions missing from the overload table:\n{}",
+            missing.join("\n")
+        ));
+    }
+    if !mismatches.is_empty() {
+        errors.push(format!(
+            "overload table entries that rename an override, give them the overridden function's name:\n{}",
+            mismatches.join("\n")
+        ));
+    }
+    if !collisions.is_empty() {
+        errors.push(format!(
+            "generated function names collide, add overload table entries to rename them:\n{}",
+            collisions.join("\n")
+        ));
+    }
+    if let Some(table) = table {
+        let mut stale: Vec<&str> = table
+            .entries
+            .keys()
+            .filter(|signature| !used_entries.contains(*signature))
+            .map(String::as_str)
+            .collect();
+        if !stale.is_empty() {
+            stale.sort_unstable();
+            errors.push(format!(
+                "overload table entries that match no overloaded function:\n{}",
+                stale.join("\n")
+            ));
+        }
+    }
+    if !errors.is_empty() {
+        bail!("{}", errors.join("\n\n"));
+    }
+
+    Ok(names)
+}
+
+fn generated_functions(class: &Class) -> Vec<(usize, &FunctionBindField)> {
+    class
+        .fields
+        .iter()
+        .filter_map(|field| field.as_function_bind().map(|func| (field.field_id, func)))
+        .filter(|(_, func)| {
+            should_generate_member_function(class.attributes.links, &class.name, func)
+        })
+        .collect()
+}
+
+// the nearest generated virtual in a base class that `func` overrides
+fn overridden<'a>(
+    by_name: &HashMap<&str, &'a Class>,
+    names: &FunctionNames,
+    class: &Class,
+    func: &FunctionBindField,
+) -> Option<(&'a Class, usize, &'a FunctionBindField)> {
+    for base in &class.superclasses {
+        let Some(base_class) = by_name.get(base.as_str()).copied() else {
+            continue;
+        };
+        let found = base_class.fields.iter().find_map(|field| {
+            let base_func = field.as_function_bind()?;
+            (base_func.prototype.is_virtual
+                && base_func.prototype.signature_matches(&func.prototype)
+                && names.contains_key(&(base_class.name.clone(), field.field_id)))
+            .then_some((base_class, field.field_id, base_func))
+        });
+        if let Some(found) = found.or_else(|| overridden(by_name, names, base_class, func)) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+pub fn overload_signature(full_class_name: &str, func: &FunctionBindField) -> String {
+    let args = func
+        .prototype
+        .args
+        .iter()
+        .map(|arg| arg.ty.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let constness = if func.prototype.is_const {
+        " const"
+    } else {
+        ""
+    };
+    format!(
+        "{full_class_name}::{}({args}){constness}",
+        func.prototype.name
+    )
+}
+
+fn base_name(class_name: &str, func: &FunctionBindField) -> String {
+    match func.prototype.fn_type {
+        FunctionType::Constructor => format!("{}_ctor", to_snake_case(class_name)),
+        FunctionType::Destructor => format!("{}_dtor", to_snake_case(class_name)),
+        FunctionType::Normal => sanitize_function_name(&func.prototype.name),
+    }
+}
+
+pub fn derive_overload_name(base: &str, func: &FunctionBindField) -> String {
+    if func.prototype.args.is_empty() {
+        return base.to_string();
+    }
+
+    let words = func
+        .prototype
+        .args
+        .iter()
+        .map(|arg| type_word(&arg.ty.name))
+        .collect::<Vec<_>>()
+        .join("_");
+    format!("{base}_with_{words}")
+}
+
+fn type_word(cpp_type: &str) -> String {
+    let is_pointer = cpp_type.contains('*');
+    let stripped = cpp_type
+        .replace(['&', '*'], " ")
+        .split_whitespace()
+        .filter(|word| !matches!(*word, "const" | "struct"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let without_template = stripped.split('<').next().unwrap_or_default();
+
+    match without_template {
+        "char" if is_pointer => return "string".to_string(),
+        "void" if is_pointer => return "ptr".to_string(),
+        "gd::string" | "std::string" | "std::string_view" => return "string".to_string(),
+        _ => {}
+    }
+
+    let last = without_template
+        .rsplit("::")
+        .next()
+        .unwrap_or(without_template)
+        .replace("unsigned ", "u")
+        .replace(' ', "_");
+    let snake = to_snake_case(&last);
+    let snake = snake.strip_prefix("cc_").unwrap_or(&snake);
+
+    // keep `ccColor4B` as `color4b` rather than `color4_b`
+    let mut word = String::with_capacity(snake.len());
+    for segment in snake.split('_') {
+        let joins_digit = segment.len() == 1 && word.ends_with(|c: char| c.is_ascii_digit());
+        if !word.is_empty() && !joins_digit {
+            word.push('_');
+        }
+        word.push_str(segment);
+    }
+    word
+}
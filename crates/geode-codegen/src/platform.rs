@@ -33,6 +33,11 @@ impl Platform {
         }
     }
 
+    // anything the game doesn't run on, i.e. `cargo test` on a desktop Linux
+    pub fn host_cfg_condition() -> &'static str {
+        "not(any(target_os = \"windows\", target_os = \"macos\", target_os = \"ios\", target_os = \"android\"))"
+    }
+
     pub fn is_macos(self) -> bool {
         matches!(self, Platform::MacIntel | Platform::MacArm)
     }
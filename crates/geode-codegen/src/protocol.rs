@@ -0,0 +1,184 @@
+use broma_rs::{Class, FunctionBindField, FunctionType};
+
+use crate::class::serialize_name;
+use crate::function::{sanitize_arg_name, sanitize_function_name};
+use crate::overload::derive_overload_name;
+use crate::types::{RustType, cpp_to_rust_type};
+
+// Protocols are base-less classes made only of virtual functions, such as
+// `FLAlertLayerProtocol` or `TextInputDelegate`. In a subclass they are a single vtable
+// pointer, so Rust classes can implement them by building that vtable themselves. Records
+// passed or returned by value go through hidden pointers whose placement differs per ABI,
+// protocols with those are left out.
+pub fn is_protocol(class: &Class) -> bool {
+    class.superclasses.is_empty()
+        && !class.fields.is_empty()
+        && class.fields.iter().all(|field| {
+            field.as_function_bind().is_some_and(|func| {
+                func.prototype.is_virtual
+                    && !func.prototype.is_static
+                    && func.prototype.fn_type == FunctionType::Normal
+                    && is_register_type(&func.prototype.ret.name)
+                    && func
+                        .prototype
+                        .args
+                        .iter()
+                        .all(|arg| is_register_type(&arg.ty.name))
+            })
+        })
+}
+
+fn is_register_type(cpp_type: &str) -> bool {
+    matches!(
+        cpp_to_rust_type(cpp_type),
+        RustType::Primitive(_) | RustType::Pointer(..) | RustType::Reference(..)
+    )
+}
+
+pub fn generate_protocol(class: &Class) -> String {
+    let class_name = serialize_name(&class.name);
+    let functions: Vec<&FunctionBindField> = class
+        .fields
+        .iter()
+        .filter_map(|field| field.as_function_bind())
+        .collect();
+
+    let method_names: Vec<String> = functions
+        .iter()
+        .map(|func| {
+            let base = sanitize_function_name(&func.prototype.name);
+            let overloaded = functions
+                .iter()
+                .filter(|other| other.prototype.name == func.prototype.name)
+                .count()
+                > 1;
+            if overloaded {
+                derive_overload_name(&base, func)
+            } else {
+                base
+            }
+        })
+        .collect();
+
+    let mut trait_methods = String::new();
+    let mut thunks = String::new();
+    let mut entries = Vec::new();
+    for (func, method) in functions.iter().zip(&method_names) {
+        let receiver = if func.prototype.is_const {
+            "&self"
+        } else {
+            "&mut self"
+        };
+        let ret_type = cpp_to_rust_type(&func.prototype.ret.name);
+        let ret = match ret_type.to_rust_str().as_str() {
+            "()" => String::new(),
+            ty => format!(" -> {ty}"),
+        };
+        let args: Vec<(String, String)> = func
+            .prototype
+            .args
+            .iter()
+            .enumerate()
+            .map(|(index, arg)| {
+                let name = if arg.name.is_empty() {
+                    format!("arg{index}")
+                } else {
+                    sanitize_arg_name(&arg.name)
+                };
+                (name, cpp_to_rust_type(&arg.ty.name).to_rust_str())
+            })
+            .collect();
+        let params = args
+            .iter()
+            .map(|(name, ty)| format!(", {name}: {ty}"))
+            .collect::<String>();
+        let arg_names = args
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        // void functions default to doing nothing, like the inline bodies they come from
+        let body = if ret.is_empty() { " {}" } else { ";" };
+        trait_methods.push_str(&format!("    fn {method}({receiver}{params}){ret}{body}\n"));
+
+        let this_ref = if func.prototype.is_const {
+            "&*this.byte_sub(OFFSET).cast::<T>()"
+        } else {
+            "&mut *this.byte_sub(OFFSET).cast::<T>()"
+        };
+        // a panic can't unwind into C++, so every thunk catches it and returns a default:
+        // zero for primitives, null for pointers. A C++ reference has no such value, those
+        // abort after the panic is reported.
+        let call = |value: String| {
+            format!(
+                "|| {{\n                let this = unsafe {{ {this_ref} }};\n                {value}\n            }}"
+            )
+        };
+        let value = format!("this.{method}({arg_names})");
+        let hook_name = format!("{class_name}::{}", func.prototype.name);
+        let body = match ret_type {
+            RustType::Primitive(_) => format!(
+                "            crate::modify::run_hook(\"{hook_name}\", {})\n",
+                call(value)
+            ),
+            RustType::Pointer(_, is_const) => format!(
+                "            crate::modify::run_hook(\"{hook_name}\", {})\n            .unwrap_or(std::ptr::{}())\n",
+                call(format!("Some({value})")),
+                if is_const { "null" } else { "null_mut" }
+            ),
+            _ => format!(
+                "            crate::modify::run_hook_or_abort(\"{hook_name}\", {})\n",
+                call(value)
+            ),
+        };
+        thunks.push_str(&format!(
+            "        extern \"C\" fn {method}<T: {class_name}Impl, const OFFSET: usize>(this: *mut c_void{params}){ret} {{\n{body}        }}\n"
+        ));
+        entries.push(format!("{method}::<T, OFFSET> as *mut c_void"));
+    }
+
+    let entries_expr = |entries: &[String]| format!("vec![{}]", entries.join(", "));
+    let itanium = entries_expr(&entries);
+    let msvc = entries_expr(&msvc_order(&functions, &entries));
+
+    let mut output = String::new();
+    output.push_str("#[allow(unused_variables)]\n");
+    output.push_str(&format!(
+        "pub trait {class_name}Impl {{\n{trait_methods}}}\n\n"
+    ));
+    output.push_str(&format!("impl {class_name} {{\n"));
+    output.push_str("    // `OFFSET` is where the protocol's vtable pointer sits in `T`, the thunks subtract it\n");
+    output.push_str("    // to get back to the object\n");
+    output.push_str(&format!(
+        "    pub fn vtable_for<T: {class_name}Impl, const OFFSET: usize>() -> Vec<*mut c_void> {{\n"
+    ));
+    output.push_str(&thunks);
+    if itanium == msvc {
+        output.push_str(&format!("\n        {itanium}\n"));
+    } else {
+        // MSVC groups overloads at the first declaration's slot, in reverse order
+        output.push_str(&format!(
+            "\n        if cfg!(target_os = \"windows\") {{\n            {msvc}\n        }} else {{\n            {itanium}\n        }}\n"
+        ));
+    }
+    output.push_str("    }\n}\n\n");
+    output
+}
+
+fn msvc_order(functions: &[&FunctionBindField], entries: &[String]) -> Vec<String> {
+    let mut ordered = Vec::with_capacity(entries.len());
+    let mut placed = vec![false; entries.len()];
+    for (index, func) in functions.iter().enumerate() {
+        if placed[index] {
+            continue;
+        }
+        for (overload, other) in functions.iter().enumerate().rev() {
+            if other.prototype.name == func.prototype.name {
+                ordered.push(entries[overload].clone());
+                placed[overload] = true;
+            }
+        }
+    }
+    ordered
+}
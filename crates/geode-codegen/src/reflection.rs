@@ -0,0 +1,89 @@
+use broma_rs::{Class, FieldInner, FunctionType};
+
+use crate::class::serialize_name;
+use crate::function::bound_cfg_condition;
+use crate::member::{member_field_cfg, sanitize_member_name};
+use crate::overload::{FunctionNames, overload_signature};
+use crate::types::cpp_to_rust_type;
+
+pub fn generate_reflection(classes: &[Class], names: &FunctionNames) -> String {
+    let mut output = String::new();
+    output.push_str("#![allow(unused_imports, clippy::all)]\n\n");
+    output.push_str("use crate::reflect::{ClassInfo, FieldInfo, FunctionInfo};\n\n");
+    output.push_str("pub static CLASSES: &[ClassInfo] = &[\n");
+
+    for class in classes {
+        output.push_str(&generate_class_info(class, names));
+    }
+
+    output.push_str("];\n");
+    output
+}
+
+fn generate_class_info(class: &Class, names: &FunctionNames) -> String {
+    let class_name = serialize_name(&class.name);
+    let mut output = String::new();
+
+    output.push_str("    ClassInfo {\n");
+    output.push_str(&format!("        name: {:?},\n", class.name));
+    output.push_str(&format!(
+        "        bases: &[{}],\n",
+        class
+            .superclasses
+            .iter()
+            .map(|base| format!("{base:?}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+    output.push_str(&format!(
+        "        size: std::mem::size_of::<crate::classes::{class_name}>(),\n"
+    ));
+
+    output.push_str("        fields: &[\n");
+    for field in &class.fields {
+        let FieldInner::Member(member) = &field.inner else {
+            continue;
+        };
+        if let Some(cfg) = member_field_cfg(member) {
+            output.push_str(&format!("            #[cfg({cfg})]\n"));
+        }
+        output.push_str(&format!(
+            "            FieldInfo {{ name: {:?}, cpp_type: {:?}, rust_type: {:?}, offset: std::mem::offset_of!(crate::classes::{class_name}, {}) }},\n",
+            member.name,
+            member.ty.name,
+            cpp_to_rust_type(&member.ty.name).to_rust_str(),
+            sanitize_member_name(&member.name),
+        ));
+    }
+    output.push_str("        ],\n");
+
+    output.push_str("        functions: &[\n");
+    for field in &class.fields {
+        let FieldInner::FunctionBind(func) = &field.inner else {
+            continue;
+        };
+        let Some(rust_name) = names.get(&(class.name.clone(), field.field_id)) else {
+            continue;
+        };
+
+        let prefix = match func.prototype.fn_type {
+            FunctionType::Normal if func.prototype.is_static => {
+                format!("static {} ", func.prototype.ret.name)
+            }
+            FunctionType::Normal => format!("{} ", func.prototype.ret.name),
+            FunctionType::Constructor | FunctionType::Destructor => String::new(),
+        };
+        output.push_str(&format!(
+            "            FunctionInfo {{ name: {:?}, rust_name: {:?}, signature: {:?}, resolver: crate::classes::{class_name}::{}_ADDR, bound: cfg!({}) }},\n",
+            func.prototype.name,
+            rust_name,
+            format!("{prefix}{}", overload_signature(&class.name, func)),
+            rust_name.to_uppercase(),
+            bound_cfg_condition(class.attributes.links, &class.name, func),
+        ));
+    }
+    output.push_str("        ],\n");
+
+    output.push_str("    },\n");
+    output
+}
@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+
+use broma_rs::{Class, FieldInner, FunctionType, Platform as BromaPlatform, Root};
+
+use crate::function::is_cocos_class;
+use crate::platform::Platform;
+
+// Primary vtable of a class, laid out from Broma's declaration order the way each ABI does it.
+// Cocos2d.bro lists addresses and leaves out inline virtuals and whole interfaces (CCCopying, the
+// input delegates), so cocos classes are laid out from the vtables Broma instead, which lists
+// every virtual in header order. Classes built on one it doesn't describe have no known layout.
+pub struct VtableLayout {
+    // function pointers after the address point
+    pub slots: usize,
+    // slot of every virtual the class declares itself, by field id, destructors excluded
+    pub own: HashMap<usize, Slot>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Slot {
+    Primary(usize),
+    // overrides a virtual of this secondary base, which C++ calls through the base's own vtable
+    Secondary(String),
+}
+
+#[derive(Clone)]
+struct Entry {
+    name: String,
+    args: Vec<String>,
+    is_const: bool,
+    destructor: bool,
+}
+
+impl Entry {
+    fn is_overridden_by(&self, other: &Entry) -> bool {
+        if self.destructor || other.destructor {
+            return self.destructor && other.destructor;
+        }
+        self.name == other.name && self.args == other.args && self.is_const == other.is_const
+    }
+}
+
+// None when the class or one of its bases isn't fully described by Broma
+pub fn primary_vtable(
+    class: &Class,
+    root: &Root,
+    vtables: &Root,
+    platform: Platform,
+) -> Option<VtableLayout> {
+    let classes = Classes { root, vtables };
+    let described = classes.find(&class.name)?;
+    let (entries, secondary) = layout(described, &classes, platform, 0)?;
+
+    // the class's bound virtuals, matched up with the described ones for cocos classes
+    let mut own = HashMap::new();
+    for (field_id, entry) in declared_virtuals(class, platform) {
+        if entry.destructor {
+            continue;
+        }
+        let slot = match secondary
+            .iter()
+            .find(|(base, _)| base.is_overridden_by(&entry))
+        {
+            Some((_, base)) => Slot::Secondary(base.clone()),
+            None => match entries
+                .iter()
+                .position(|base| base.is_overridden_by(&entry))
+            {
+                Some(slot) => Slot::Primary(slot),
+                None => continue,
+            },
+        };
+        own.insert(field_id, slot);
+    }
+    Some(VtableLayout {
+        slots: entries.len(),
+        own,
+    })
+}
+
+struct Classes<'a> {
+    root: &'a Root,
+    vtables: &'a Root,
+}
+
+impl Classes<'_> {
+    fn find(&self, name: &str) -> Option<&Class> {
+        if is_cocos_class(name) {
+            self.vtables.find_class(name)
+        } else {
+            self.root.find_class(name)
+        }
+    }
+}
+
+// a virtual reached through a secondary base, along with that base
+type SecondaryEntry = (Entry, String);
+
+// the primary vtable's entries and every virtual reached through a secondary base
+fn layout(
+    class: &Class,
+    classes: &Classes,
+    platform: Platform,
+    depth: usize,
+) -> Option<(Vec<Entry>, Vec<SecondaryEntry>)> {
+    // broma doesn't rule out cycles
+    if depth > 64 {
+        return None;
+    }
+
+    let bases = class
+        .superclasses
+        .iter()
+        .map(|base| classes.find(base))
+        .collect::<Option<Vec<_>>>()?;
+    let primary = bases
+        .iter()
+        .position(|base| is_dynamic(base, classes, platform, depth + 1));
+
+    let (mut entries, mut secondary) = match primary {
+        Some(index) => layout(bases[index], classes, platform, depth + 1)?,
+        None => (Vec::new(), Vec::new()),
+    };
+    for (index, base) in bases.iter().enumerate() {
+        if Some(index) != primary {
+            let mut virtuals = Vec::new();
+            all_virtuals(base, classes, platform, depth + 1, &mut virtuals);
+            secondary.extend(virtuals.into_iter().map(|entry| (entry, base.name.clone())));
+        }
+    }
+
+    let mut new = Vec::new();
+    for (_, entry) in declared_virtuals(class, platform) {
+        if entries.iter().any(|base| base.is_overridden_by(&entry)) {
+            continue;
+        }
+        // MSVC keeps overriders of a secondary base in that base's vftable only, the Itanium
+        // ABI gives them a primary slot as well
+        if platform == Platform::Windows
+            && secondary
+                .iter()
+                .any(|(base, _)| base.is_overridden_by(&entry))
+        {
+            continue;
+        }
+        new.push(entry);
+    }
+
+    // MSVC groups overloads at the first one's position, in reverse declaration order
+    if platform == Platform::Windows {
+        let mut grouped = Vec::with_capacity(new.len());
+        while let Some(first) = new.first() {
+            let name = first.name.clone();
+            let (mut group, rest): (Vec<_>, Vec<_>) =
+                new.into_iter().partition(|entry| entry.name == name);
+            group.reverse();
+            grouped.extend(group);
+            new = rest;
+        }
+        new = grouped;
+    }
+
+    for entry in new {
+        // complete and deleting destructor, MSVC has a single scalar deleting one
+        if entry.destructor && platform != Platform::Windows {
+            entries.push(entry.clone());
+        }
+        entries.push(entry);
+    }
+    Some((entries, secondary))
+}
+
+fn is_dynamic(class: &Class, classes: &Classes, platform: Platform, depth: usize) -> bool {
+    if depth > 64 {
+        return false;
+    }
+    !declared_virtuals(class, platform).is_empty()
+        || class.superclasses.iter().any(|base| {
+            classes
+                .find(base)
+                .is_some_and(|base| is_dynamic(base, classes, platform, depth + 1))
+        })
+}
+
+fn all_virtuals(
+    class: &Class,
+    classes: &Classes,
+    platform: Platform,
+    depth: usize,
+    out: &mut Vec<Entry>,
+) {
+    if depth > 64 {
+        return;
+    }
+    out.extend(
+        declared_virtuals(class, platform)
+            .into_iter()
+            .map(|(_, entry)| entry),
+    );
+    for base in &class.superclasses {
+        if let Some(base) = classes.find(base) {
+            all_virtuals(base, classes, platform, depth + 1, out);
+        }
+    }
+}
+
+fn declared_virtuals(class: &Class, platform: Platform) -> Vec<(usize, Entry)> {
+    class
+        .fields
+        .iter()
+        .filter_map(|field| match &field.inner {
+            FieldInner::FunctionBind(func)
+                if func.prototype.is_virtual
+                    && !is_missing(func.prototype.attributes.missing, platform) =>
+            {
+                Some((
+                    field.field_id,
+                    Entry {
+                        name: func.prototype.name.clone(),
+                        args: func
+                            .prototype
+                            .args
+                            .iter()
+                            .map(|arg| normalize_type(&arg.ty.name))
+                            .collect(),
+                        is_const: func.prototype.is_const,
+                        destructor: func.prototype.fn_type == FunctionType::Destructor,
+                    },
+                ))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+// GeometryDash.bro writes `cocos2d::CCTouch *` where Cocos2d.bro writes `cocos2d::CCTouch*`, and
+// both have the odd `const char*`
+fn normalize_type(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    for part in name.split_whitespace() {
+        if !normalized.is_empty() && !part.starts_with(['*', '&']) {
+            normalized.push(' ');
+        }
+        normalized.push_str(part);
+    }
+    match normalized.strip_prefix("const ") {
+        Some(rest) => {
+            let pointee = rest.find(['*', '&']).unwrap_or(rest.len());
+            format!("{} const{}", &rest[..pointee], &rest[pointee..])
+        }
+        None => normalized,
+    }
+}
+
+fn is_missing(missing: BromaPlatform, platform: Platform) -> bool {
+    let flag = match platform {
+        Platform::Windows => BromaPlatform::Windows,
+        Platform::MacIntel => BromaPlatform::MacIntel,
+        Platform::MacArm => BromaPlatform::MacArm,
+        Platform::IOS => BromaPlatform::IOS,
+        Platform::Android32 => BromaPlatform::Android32,
+        Platform::Android64 => BromaPlatform::Android64,
+    };
+    missing.intersects(flag)
+}
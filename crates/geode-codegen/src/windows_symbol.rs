@@ -1,5 +1,9 @@
 use broma_rs::{AccessModifier, FunctionBindField, FunctionType};
 
+use crate::declaration::{
+    Builtin, CxxType, Declaration, FunctionKind, NameComponent, RecordKind, TemplateArg,
+};
+
 fn mangle_ident(s: &str) -> String {
     if s.contains("::") {
         let mut result = String::new();
@@ -265,3 +269,197 @@ pub fn generate_windows_symbol(class_name: &str, func: &FunctionBindField) -> Op
     symbol.push_str("@Z");
     Some(symbol)
 }
+
+// MSVC mangling for `declaration::Declaration`. Names and template instantiations are
+// back-referenced through `names`, function arguments through `args`, and each template
+// instantiation starts over with its own tables.
+#[derive(Default)]
+struct DeclarationMangler {
+    names: Vec<String>,
+    args: Vec<CxxType>,
+}
+
+impl DeclarationMangler {
+    fn source_name(&mut self, name: &str) -> String {
+        if let Some(index) = self.names.iter().position(|seen| seen == name) {
+            return index.to_string();
+        }
+        if self.names.len() < 10 {
+            self.names.push(name.to_string());
+        }
+        format!("{name}@")
+    }
+
+    fn component(&mut self, component: &NameComponent) -> String {
+        let Some(args) = &component.template_args else {
+            return self.source_name(&component.name);
+        };
+        let mut template = DeclarationMangler::default();
+        let mut instantiation = format!("?${}", template.source_name(&component.name));
+        for arg in args {
+            instantiation.push_str(&template.template_arg(arg));
+        }
+        self.source_name(&instantiation)
+    }
+
+    fn qualified_name(&mut self, path: &[NameComponent]) -> String {
+        let mut mangled: String = path
+            .iter()
+            .rev()
+            .map(|component| self.component(component))
+            .collect();
+        mangled.push('@');
+        mangled
+    }
+
+    fn template_arg(&mut self, arg: &TemplateArg) -> String {
+        match arg {
+            TemplateArg::Type(CxxType::Const(inner)) => format!("$$CB{}", self.ty(inner)),
+            TemplateArg::Type(ty) => self.ty(ty),
+            TemplateArg::Integer(value) => format!("$0{}", encode_number(*value as i64)),
+        }
+    }
+
+    fn ty(&mut self, ty: &CxxType) -> String {
+        match ty {
+            CxxType::Builtin(builtin) => builtin_code(*builtin).to_string(),
+            CxxType::Named { kind, path } => {
+                let marker = match kind {
+                    RecordKind::Class => "V",
+                    RecordKind::Struct => "U",
+                    RecordKind::Enum => "W4",
+                };
+                format!("{marker}{}", self.qualified_name(path))
+            }
+            CxxType::Const(inner) => self.ty(inner),
+            CxxType::Pointer(inner) => format!("PE{}", self.pointee(inner)),
+            CxxType::Reference(inner) => format!("AE{}", self.pointee(inner)),
+        }
+    }
+
+    fn pointee(&mut self, ty: &CxxType) -> String {
+        let cv = if matches!(ty, CxxType::Const(_)) {
+            'B'
+        } else {
+            'A'
+        };
+        format!("{cv}{}", self.ty(ty.strip_const()))
+    }
+
+    fn return_type(&mut self, ty: &CxxType) -> String {
+        let is_const = matches!(ty, CxxType::Const(_));
+        match ty.strip_const() {
+            inner @ (CxxType::Named { .. } | CxxType::Builtin(_))
+                if is_const || matches!(inner, CxxType::Named { .. }) =>
+            {
+                let cv = if is_const { 'B' } else { 'A' };
+                format!("?{cv}{}", self.ty(inner))
+            }
+            inner => self.ty(inner),
+        }
+    }
+
+    fn arg(&mut self, ty: &CxxType) -> String {
+        let ty = ty.strip_const();
+        if let Some(index) = self.args.iter().position(|seen| seen == ty) {
+            return index.to_string();
+        }
+        let mangled = self.ty(ty);
+        if mangled.len() > 1 && self.args.len() < 10 {
+            self.args.push(ty.clone());
+        }
+        mangled
+    }
+}
+
+fn builtin_code(builtin: Builtin) -> &'static str {
+    match builtin {
+        Builtin::Void => "X",
+        Builtin::Bool => "_N",
+        Builtin::Char => "D",
+        Builtin::SignedChar => "C",
+        Builtin::UnsignedChar => "E",
+        Builtin::Short => "F",
+        Builtin::UnsignedShort => "G",
+        Builtin::Int => "H",
+        Builtin::UnsignedInt => "I",
+        Builtin::Long => "J",
+        Builtin::UnsignedLong => "K",
+        Builtin::LongLong => "_J",
+        Builtin::UnsignedLongLong => "_K",
+        Builtin::Float => "M",
+        Builtin::Double => "N",
+        Builtin::Size | Builtin::PtrDiff | Builtin::Int64 | Builtin::UInt64 => {
+            unreachable!("platform dependent types are resolved before mangling")
+        }
+    }
+}
+
+fn encode_number(value: i64) -> String {
+    if value < 0 {
+        return format!("?{}", encode_number(value.unsigned_abs() as i64));
+    }
+    match value {
+        1..=10 => (value - 1).to_string(),
+        _ => {
+            let mut digits: String = format!("{value:X}")
+                .chars()
+                .map(|digit| (b'A' + digit.to_digit(16).unwrap() as u8) as char)
+                .collect();
+            digits.push('@');
+            digits
+        }
+    }
+}
+
+pub fn generate_declaration_symbol(decl: &Declaration) -> String {
+    let mut mangler = DeclarationMangler::default();
+
+    let mut symbol = "?".to_string();
+    match decl.kind {
+        FunctionKind::Constructor => symbol.push_str("?0"),
+        FunctionKind::Destructor => symbol.push_str("?1"),
+        _ => symbol.push_str(&mangler.source_name(&decl.name)),
+    }
+    symbol.push_str(&mangler.qualified_name(&decl.scope));
+
+    let is_virtual = decl.kind == FunctionKind::Virtual;
+    match decl.kind {
+        FunctionKind::Free => symbol.push('Y'),
+        FunctionKind::Static => symbol.push(match decl.access {
+            AccessModifier::Private => 'C',
+            AccessModifier::Protected => 'K',
+            AccessModifier::Public => 'S',
+        }),
+        _ => {
+            symbol.push(match (decl.access, is_virtual) {
+                (AccessModifier::Private, false) => 'A',
+                (AccessModifier::Protected, false) => 'I',
+                (AccessModifier::Public, false) => 'Q',
+                (AccessModifier::Private, true) => 'E',
+                (AccessModifier::Protected, true) => 'M',
+                (AccessModifier::Public, true) => 'U',
+            });
+            symbol.push('E');
+            symbol.push(if decl.is_const { 'B' } else { 'A' });
+        }
+    }
+    // __cdecl, the only convention on x64
+    symbol.push('A');
+
+    match decl.kind {
+        FunctionKind::Constructor | FunctionKind::Destructor => symbol.push('@'),
+        _ => symbol.push_str(&mangler.return_type(&decl.ret)),
+    }
+
+    if decl.args.is_empty() {
+        symbol.push('X');
+    } else {
+        for arg in &decl.args {
+            symbol.push_str(&mangler.arg(arg));
+        }
+        symbol.push('@');
+    }
+    symbol.push('Z');
+    symbol
+}
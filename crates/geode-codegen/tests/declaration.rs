@@ -0,0 +1,2520 @@
+use broma_rs::AccessModifier;
+use geode_codegen::declaration::{
+    Builtin, CxxType, Declaration, FunctionKind, RecordKind, TemplateArg, parse_declaration,
+};
+use geode_codegen::platform::Platform;
+
+// Declarations from `loader/raw.rs` with the symbols that were written out by hand for each
+// platform before `geode_bind!` derived them, with the number of arguments the binding takes.
+// Symbols are in `Platform::all()` order: windows, mac_intel, mac_arm, ios, android32, android64
+const LOADER_BINDINGS: &[(&str, usize, [&str; 6])] = &[
+    (
+        "static geode::Loader::get() -> geode::Loader*",
+        0,
+        [
+            "?get@Loader@geode@@SAPEAV12@XZ",
+            "_ZN5geode6Loader3getEv",
+            "_ZN5geode6Loader3getEv",
+            "_ZN5geode6Loader3getEv",
+            "_ZN5geode6Loader3getEv",
+            "_ZN5geode6Loader3getEv",
+        ],
+    ),
+    (
+        "protected geode::Loader::takeNextMod() -> geode::Mod*",
+        1,
+        [
+            "?takeNextMod@Loader@geode@@IEAAPEAVMod@2@XZ",
+            "_ZN5geode6Loader11takeNextModEv",
+            "_ZN5geode6Loader11takeNextModEv",
+            "_ZN5geode6Loader11takeNextModEv",
+            "_ZN5geode6Loader11takeNextModEv",
+            "_ZN5geode6Loader11takeNextModEv",
+        ],
+    ),
+    (
+        "geode::Loader::getLoadingState() -> enum geode::Loader::LoadingState",
+        1,
+        [
+            "?getLoadingState@Loader@geode@@QEAA?AW4LoadingState@12@XZ",
+            "_ZN5geode6Loader15getLoadingStateEv",
+            "_ZN5geode6Loader15getLoadingStateEv",
+            "_ZN5geode6Loader15getLoadingStateEv",
+            "_ZN5geode6Loader15getLoadingStateEv",
+            "_ZN5geode6Loader15getLoadingStateEv",
+        ],
+    ),
+    (
+        "geode::Loader::isPatchless() const -> bool",
+        1,
+        [
+            "?isPatchless@Loader@geode@@QEBA_NXZ",
+            "_ZNK5geode6Loader11isPatchlessEv",
+            "_ZNK5geode6Loader11isPatchlessEv",
+            "_ZNK5geode6Loader11isPatchlessEv",
+            "_ZNK5geode6Loader11isPatchlessEv",
+            "_ZNK5geode6Loader11isPatchlessEv",
+        ],
+    ),
+    (
+        "geode::Loader::isModInstalled(std::string_view) const -> bool",
+        2,
+        [
+            "?isModInstalled@Loader@geode@@QEBA_NV?$basic_string_view@DU?$char_traits@D@std@@@std@@@Z",
+            "_ZNK5geode6Loader14isModInstalledENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader14isModInstalledENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader14isModInstalledENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader14isModInstalledENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader14isModInstalledENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Loader::getInstalledMod(std::string_view) const -> geode::Mod*",
+        2,
+        [
+            "?getInstalledMod@Loader@geode@@QEBAPEAVMod@2@V?$basic_string_view@DU?$char_traits@D@std@@@std@@@Z",
+            "_ZNK5geode6Loader15getInstalledModENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader15getInstalledModENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader15getInstalledModENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader15getInstalledModENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader15getInstalledModENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Loader::isModLoaded(std::string_view) const -> bool",
+        2,
+        [
+            "?isModLoaded@Loader@geode@@QEBA_NV?$basic_string_view@DU?$char_traits@D@std@@@std@@@Z",
+            "_ZNK5geode6Loader11isModLoadedENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader11isModLoadedENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader11isModLoadedENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader11isModLoadedENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader11isModLoadedENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Loader::getLoadedMod(std::string_view) const -> geode::Mod*",
+        2,
+        [
+            "?getLoadedMod@Loader@geode@@QEBAPEAVMod@2@V?$basic_string_view@DU?$char_traits@D@std@@@std@@@Z",
+            "_ZNK5geode6Loader12getLoadedModENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader12getLoadedModENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader12getLoadedModENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader12getLoadedModENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader12getLoadedModENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Loader::getAllMods() -> std::vector<geode::Mod*>",
+        1,
+        [
+            "?getAllMods@Loader@geode@@QEAA?AV?$vector@PEAVMod@geode@@V?$allocator@PEAVMod@geode@@@std@@@std@@XZ",
+            "_ZN5geode6Loader10getAllModsEv",
+            "_ZN5geode6Loader10getAllModsEv",
+            "_ZN5geode6Loader10getAllModsEv",
+            "_ZN5geode6Loader10getAllModsEv",
+            "_ZN5geode6Loader10getAllModsEv",
+        ],
+    ),
+    (
+        "geode::Loader::getLaunchArgumentNames() const -> std::vector<std::string>",
+        1,
+        [
+            "?getLaunchArgumentNames@Loader@geode@@QEBA?AV?$vector@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@V?$allocator@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@@std@@XZ",
+            "_ZNK5geode6Loader22getLaunchArgumentNamesEv",
+            "_ZNK5geode6Loader22getLaunchArgumentNamesEv",
+            "_ZNK5geode6Loader22getLaunchArgumentNamesEv",
+            "_ZNK5geode6Loader22getLaunchArgumentNamesEv",
+            "_ZNK5geode6Loader22getLaunchArgumentNamesEv",
+        ],
+    ),
+    (
+        "geode::Loader::hasLaunchArgument(std::string_view) const -> bool",
+        2,
+        [
+            "?hasLaunchArgument@Loader@geode@@QEBA_NV?$basic_string_view@DU?$char_traits@D@std@@@std@@@Z",
+            "_ZNK5geode6Loader17hasLaunchArgumentENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader17hasLaunchArgumentENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader17hasLaunchArgumentENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader17hasLaunchArgumentENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader17hasLaunchArgumentENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Loader::getLaunchArgument(std::string_view) const -> std::optional<std::string>",
+        2,
+        [
+            "?getLaunchArgument@Loader@geode@@QEBA?AV?$optional@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@std@@V?$basic_string_view@DU?$char_traits@D@std@@@4@@Z",
+            "_ZNK5geode6Loader17getLaunchArgumentENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader17getLaunchArgumentENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader17getLaunchArgumentENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader17getLaunchArgumentENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader17getLaunchArgumentENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Loader::getGameVersion() -> std::string",
+        1,
+        [
+            "?getGameVersion@Loader@geode@@QEAA?AV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@XZ",
+            "_ZN5geode6Loader14getGameVersionEv",
+            "_ZN5geode6Loader14getGameVersionEv",
+            "_ZN5geode6Loader14getGameVersionEv",
+            "_ZN5geode6Loader14getGameVersionEv",
+            "_ZN5geode6Loader14getGameVersionEv",
+        ],
+    ),
+    (
+        "geode::Mod::getID() const -> geode::ZStringView",
+        1,
+        [
+            "?getID@Mod@geode@@QEBA?AV?$BasicZStringView@D@2@XZ",
+            "_ZNK5geode3Mod5getIDEv",
+            "_ZNK5geode3Mod5getIDEv",
+            "_ZNK5geode3Mod5getIDEv",
+            "_ZNK5geode3Mod5getIDEv",
+            "_ZNK5geode3Mod5getIDEv",
+        ],
+    ),
+    (
+        "geode::Mod::getName() const -> geode::ZStringView",
+        1,
+        [
+            "?getName@Mod@geode@@QEBA?AV?$BasicZStringView@D@2@XZ",
+            "_ZNK5geode3Mod7getNameEv",
+            "_ZNK5geode3Mod7getNameEv",
+            "_ZNK5geode3Mod7getNameEv",
+            "_ZNK5geode3Mod7getNameEv",
+            "_ZNK5geode3Mod7getNameEv",
+        ],
+    ),
+    (
+        "geode::Mod::isLoaded() const -> bool",
+        1,
+        [
+            "?isLoaded@Mod@geode@@QEBA_NXZ",
+            "_ZNK5geode3Mod8isLoadedEv",
+            "_ZNK5geode3Mod8isLoadedEv",
+            "_ZNK5geode3Mod8isLoadedEv",
+            "_ZNK5geode3Mod8isLoadedEv",
+            "_ZNK5geode3Mod8isLoadedEv",
+        ],
+    ),
+    (
+        "geode::Mod::isCurrentlyLoading() const -> bool",
+        1,
+        [
+            "?isCurrentlyLoading@Mod@geode@@QEBA_NXZ",
+            "_ZNK5geode3Mod18isCurrentlyLoadingEv",
+            "_ZNK5geode3Mod18isCurrentlyLoadingEv",
+            "_ZNK5geode3Mod18isCurrentlyLoadingEv",
+            "_ZNK5geode3Mod18isCurrentlyLoadingEv",
+            "_ZNK5geode3Mod18isCurrentlyLoadingEv",
+        ],
+    ),
+    (
+        "geode::Mod::hasSettings() const -> bool",
+        1,
+        [
+            "?hasSettings@Mod@geode@@QEBA_NXZ",
+            "_ZNK5geode3Mod11hasSettingsEv",
+            "_ZNK5geode3Mod11hasSettingsEv",
+            "_ZNK5geode3Mod11hasSettingsEv",
+            "_ZNK5geode3Mod11hasSettingsEv",
+            "_ZNK5geode3Mod11hasSettingsEv",
+        ],
+    ),
+    (
+        "geode::Mod::getSettingKeys() const -> std::vector<std::string>",
+        1,
+        [
+            "?getSettingKeys@Mod@geode@@QEBA?AV?$vector@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@V?$allocator@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@@std@@XZ",
+            "_ZNK5geode3Mod14getSettingKeysEv",
+            "_ZNK5geode3Mod14getSettingKeysEv",
+            "_ZNK5geode3Mod14getSettingKeysEv",
+            "_ZNK5geode3Mod14getSettingKeysEv",
+            "_ZNK5geode3Mod14getSettingKeysEv",
+        ],
+    ),
+    (
+        "geode::Mod::hasSetting(std::string_view) const -> bool",
+        2,
+        [
+            "?hasSetting@Mod@geode@@QEBA_NV?$basic_string_view@DU?$char_traits@D@std@@@std@@@Z",
+            "_ZNK5geode3Mod10hasSettingENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod10hasSettingENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod10hasSettingENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod10hasSettingENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod10hasSettingENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Mod::getSetting(std::string_view) const -> std::shared_ptr<geode::SettingV3>",
+        2,
+        [
+            "?getSetting@Mod@geode@@QEBA?AV?$shared_ptr@VSettingV3@geode@@@std@@V?$basic_string_view@DU?$char_traits@D@std@@@4@@Z",
+            "_ZNK5geode3Mod10getSettingENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod10getSettingENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod10getSettingENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod10getSettingENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod10getSettingENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Mod::getLaunchArgumentName(std::string_view) const -> std::string",
+        2,
+        [
+            "?getLaunchArgumentName@Mod@geode@@QEBA?AV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@V?$basic_string_view@DU?$char_traits@D@std@@@4@@Z",
+            "_ZNK5geode3Mod21getLaunchArgumentNameENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod21getLaunchArgumentNameENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod21getLaunchArgumentNameENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod21getLaunchArgumentNameENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod21getLaunchArgumentNameENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Mod::getLaunchArgumentNames() const -> std::vector<std::string>",
+        1,
+        [
+            "?getLaunchArgumentNames@Mod@geode@@QEBA?AV?$vector@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@V?$allocator@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@@std@@XZ",
+            "_ZNK5geode3Mod22getLaunchArgumentNamesEv",
+            "_ZNK5geode3Mod22getLaunchArgumentNamesEv",
+            "_ZNK5geode3Mod22getLaunchArgumentNamesEv",
+            "_ZNK5geode3Mod22getLaunchArgumentNamesEv",
+            "_ZNK5geode3Mod22getLaunchArgumentNamesEv",
+        ],
+    ),
+    (
+        "geode::Mod::hasLaunchArgument(std::string_view) const -> bool",
+        2,
+        [
+            "?hasLaunchArgument@Mod@geode@@QEBA_NV?$basic_string_view@DU?$char_traits@D@std@@@std@@@Z",
+            "_ZNK5geode3Mod17hasLaunchArgumentENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod17hasLaunchArgumentENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod17hasLaunchArgumentENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod17hasLaunchArgumentENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod17hasLaunchArgumentENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Mod::getLaunchArgument(std::string_view) const -> std::optional<std::string>",
+        2,
+        [
+            "?getLaunchArgument@Mod@geode@@QEBA?AV?$optional@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@std@@V?$basic_string_view@DU?$char_traits@D@std@@@4@@Z",
+            "_ZNK5geode3Mod17getLaunchArgumentENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod17getLaunchArgumentENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod17getLaunchArgumentENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod17getLaunchArgumentENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod17getLaunchArgumentENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Mod::getHooks() const -> std::vector<geode::Hook*>",
+        1,
+        [
+            "?getHooks@Mod@geode@@QEBA?AV?$vector@PEAVHook@geode@@V?$allocator@PEAVHook@geode@@@std@@@std@@XZ",
+            "_ZNK5geode3Mod8getHooksEv",
+            "_ZNK5geode3Mod8getHooksEv",
+            "_ZNK5geode3Mod8getHooksEv",
+            "_ZNK5geode3Mod8getHooksEv",
+            "_ZNK5geode3Mod8getHooksEv",
+        ],
+    ),
+    (
+        "geode::Mod::getPatches() const -> std::vector<geode::Patch*>",
+        1,
+        [
+            "?getPatches@Mod@geode@@QEBA?AV?$vector@PEAVPatch@geode@@V?$allocator@PEAVPatch@geode@@@std@@@std@@XZ",
+            "_ZNK5geode3Mod10getPatchesEv",
+            "_ZNK5geode3Mod10getPatchesEv",
+            "_ZNK5geode3Mod10getPatchesEv",
+            "_ZNK5geode3Mod10getPatchesEv",
+            "_ZNK5geode3Mod10getPatchesEv",
+        ],
+    ),
+    (
+        "geode::Mod::enable() -> geode::Result<void>",
+        1,
+        [
+            "?enable@Mod@geode@@QEAA?AV?$Result@XV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@XZ",
+            "_ZN5geode3Mod6enableEv",
+            "_ZN5geode3Mod6enableEv",
+            "_ZN5geode3Mod6enableEv",
+            "_ZN5geode3Mod6enableEv",
+            "_ZN5geode3Mod6enableEv",
+        ],
+    ),
+    (
+        "geode::Mod::disable() -> geode::Result<void>",
+        1,
+        [
+            "?disable@Mod@geode@@QEAA?AV?$Result@XV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@XZ",
+            "_ZN5geode3Mod7disableEv",
+            "_ZN5geode3Mod7disableEv",
+            "_ZN5geode3Mod7disableEv",
+            "_ZN5geode3Mod7disableEv",
+            "_ZN5geode3Mod7disableEv",
+        ],
+    ),
+    (
+        "static geode::ModSettingsManager::from(geode::Mod*) -> geode::ModSettingsManager*",
+        1,
+        [
+            "?from@ModSettingsManager@geode@@SAPEAV12@PEAVMod@2@@Z",
+            "_ZN5geode18ModSettingsManager4fromEPNS_3ModE",
+            "_ZN5geode18ModSettingsManager4fromEPNS_3ModE",
+            "_ZN5geode18ModSettingsManager4fromEPNS_3ModE",
+            "_ZN5geode18ModSettingsManager4fromEPNS_3ModE",
+            "_ZN5geode18ModSettingsManager4fromEPNS_3ModE",
+        ],
+    ),
+    (
+        "geode::ModSettingsManager::get(std::string_view) -> std::shared_ptr<geode::SettingV3>",
+        2,
+        [
+            "?get@ModSettingsManager@geode@@QEAA?AV?$shared_ptr@VSettingV3@geode@@@std@@V?$basic_string_view@DU?$char_traits@D@std@@@4@@Z",
+            "_ZN5geode18ModSettingsManager3getENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZN5geode18ModSettingsManager3getENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZN5geode18ModSettingsManager3getENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZN5geode18ModSettingsManager3getENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZN5geode18ModSettingsManager3getENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::ModSettingsManager::restartRequired() const -> bool",
+        1,
+        [
+            "?restartRequired@ModSettingsManager@geode@@QEBA_NXZ",
+            "_ZNK5geode18ModSettingsManager15restartRequiredEv",
+            "_ZNK5geode18ModSettingsManager15restartRequiredEv",
+            "_ZNK5geode18ModSettingsManager15restartRequiredEv",
+            "_ZNK5geode18ModSettingsManager15restartRequiredEv",
+            "_ZNK5geode18ModSettingsManager15restartRequiredEv",
+        ],
+    ),
+    (
+        "geode::ModSettingsManager::addDependant(geode::Mod*)",
+        2,
+        [
+            "?addDependant@ModSettingsManager@geode@@QEAAXPEAVMod@2@@Z",
+            "_ZN5geode18ModSettingsManager12addDependantEPNS_3ModE",
+            "_ZN5geode18ModSettingsManager12addDependantEPNS_3ModE",
+            "_ZN5geode18ModSettingsManager12addDependantEPNS_3ModE",
+            "_ZN5geode18ModSettingsManager12addDependantEPNS_3ModE",
+            "_ZN5geode18ModSettingsManager12addDependantEPNS_3ModE",
+        ],
+    ),
+    (
+        "geode::hook::createConvention(enum tulip::hook::TulipConvention) -> std::shared_ptr<tulip::hook::CallingConvention>",
+        1,
+        [
+            "?createConvention@hook@geode@@YA?AV?$shared_ptr@VCallingConvention@hook@tulip@@@std@@W4TulipConvention@1tulip@@@Z",
+            "_ZN5geode4hook16createConventionEN5tulip4hook15TulipConventionE",
+            "_ZN5geode4hook16createConventionEN5tulip4hook15TulipConventionE",
+            "_ZN5geode4hook16createConventionEN5tulip4hook15TulipConventionE",
+            "_ZN5geode4hook16createConventionEN5tulip4hook15TulipConventionE",
+            "_ZN5geode4hook16createConventionEN5tulip4hook15TulipConventionE",
+        ],
+    ),
+    (
+        "static geode::Hook::create(void*, void*, std::string, tulip::hook::HandlerMetadata, tulip::hook::HookMetadata) -> std::shared_ptr<geode::Hook>",
+        5,
+        [
+            "?create@Hook@geode@@SA?AV?$shared_ptr@VHook@geode@@@std@@PEAX0V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@4@VHandlerMetadata@hook@tulip@@VHookMetadata@78@@Z",
+            "_ZN5geode4Hook6createEPvS1_NSt3__112basic_stringIcNS2_11char_traitsIcEENS2_9allocatorIcEEEEN5tulip4hook15HandlerMetadataENSA_12HookMetadataE",
+            "_ZN5geode4Hook6createEPvS1_NSt3__112basic_stringIcNS2_11char_traitsIcEENS2_9allocatorIcEEEEN5tulip4hook15HandlerMetadataENSA_12HookMetadataE",
+            "_ZN5geode4Hook6createEPvS1_NSt3__112basic_stringIcNS2_11char_traitsIcEENS2_9allocatorIcEEEEN5tulip4hook15HandlerMetadataENSA_12HookMetadataE",
+            "_ZN5geode4Hook6createEPvS1_NSt6__ndk112basic_stringIcNS2_11char_traitsIcEENS2_9allocatorIcEEEEN5tulip4hook15HandlerMetadataENSA_12HookMetadataE",
+            "_ZN5geode4Hook6createEPvS1_NSt6__ndk112basic_stringIcNS2_11char_traitsIcEENS2_9allocatorIcEEEEN5tulip4hook15HandlerMetadataENSA_12HookMetadataE",
+        ],
+    ),
+    (
+        "geode::Mod::claimHook(std::shared_ptr<geode::Hook>) -> geode::Result<geode::Hook*>",
+        2,
+        [
+            "?claimHook@Mod@geode@@QEAA?AV?$Result@PEAVHook@geode@@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@V?$shared_ptr@VHook@geode@@@std@@@Z",
+            "_ZN5geode3Mod9claimHookENSt3__110shared_ptrINS_4HookEEE",
+            "_ZN5geode3Mod9claimHookENSt3__110shared_ptrINS_4HookEEE",
+            "_ZN5geode3Mod9claimHookENSt3__110shared_ptrINS_4HookEEE",
+            "_ZN5geode3Mod9claimHookENSt6__ndk110shared_ptrINS_4HookEEE",
+            "_ZN5geode3Mod9claimHookENSt6__ndk110shared_ptrINS_4HookEEE",
+        ],
+    ),
+    (
+        "geode::Hook::getOwner() const -> geode::Mod*",
+        1,
+        [
+            "?getOwner@Hook@geode@@QEBAPEAVMod@2@XZ",
+            "_ZNK5geode4Hook8getOwnerEv",
+            "_ZNK5geode4Hook8getOwnerEv",
+            "_ZNK5geode4Hook8getOwnerEv",
+            "_ZNK5geode4Hook8getOwnerEv",
+            "_ZNK5geode4Hook8getOwnerEv",
+        ],
+    ),
+    (
+        "geode::Hook::isEnabled() const -> bool",
+        1,
+        [
+            "?isEnabled@Hook@geode@@QEBA_NXZ",
+            "_ZNK5geode4Hook9isEnabledEv",
+            "_ZNK5geode4Hook9isEnabledEv",
+            "_ZNK5geode4Hook9isEnabledEv",
+            "_ZNK5geode4Hook9isEnabledEv",
+            "_ZNK5geode4Hook9isEnabledEv",
+        ],
+    ),
+    (
+        "geode::Hook::enable() -> geode::Result<void>",
+        1,
+        [
+            "?enable@Hook@geode@@QEAA?AV?$Result@XV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@XZ",
+            "_ZN5geode4Hook6enableEv",
+            "_ZN5geode4Hook6enableEv",
+            "_ZN5geode4Hook6enableEv",
+            "_ZN5geode4Hook6enableEv",
+            "_ZN5geode4Hook6enableEv",
+        ],
+    ),
+    (
+        "geode::Hook::disable() -> geode::Result<void>",
+        1,
+        [
+            "?disable@Hook@geode@@QEAA?AV?$Result@XV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@XZ",
+            "_ZN5geode4Hook7disableEv",
+            "_ZN5geode4Hook7disableEv",
+            "_ZN5geode4Hook7disableEv",
+            "_ZN5geode4Hook7disableEv",
+            "_ZN5geode4Hook7disableEv",
+        ],
+    ),
+    (
+        "geode::Hook::toggle() -> geode::Result<void>",
+        1,
+        [
+            "?toggle@Hook@geode@@QEAA?AV?$Result@XV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@XZ",
+            "_ZN5geode4Hook6toggleEv",
+            "_ZN5geode4Hook6toggleEv",
+            "_ZN5geode4Hook6toggleEv",
+            "_ZN5geode4Hook6toggleEv",
+            "_ZN5geode4Hook6toggleEv",
+        ],
+    ),
+    (
+        "geode::Hook::toggle(bool) -> geode::Result<void>",
+        2,
+        [
+            "?toggle@Hook@geode@@QEAA?AV?$Result@XV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@_N@Z",
+            "_ZN5geode4Hook6toggleEb",
+            "_ZN5geode4Hook6toggleEb",
+            "_ZN5geode4Hook6toggleEb",
+            "_ZN5geode4Hook6toggleEb",
+            "_ZN5geode4Hook6toggleEb",
+        ],
+    ),
+    (
+        "geode::Hook::getAutoEnable() const -> bool",
+        1,
+        [
+            "?getAutoEnable@Hook@geode@@QEBA_NXZ",
+            "_ZNK5geode4Hook13getAutoEnableEv",
+            "_ZNK5geode4Hook13getAutoEnableEv",
+            "_ZNK5geode4Hook13getAutoEnableEv",
+            "_ZNK5geode4Hook13getAutoEnableEv",
+            "_ZNK5geode4Hook13getAutoEnableEv",
+        ],
+    ),
+    (
+        "geode::Hook::setAutoEnable(bool)",
+        2,
+        [
+            "?setAutoEnable@Hook@geode@@QEAAX_N@Z",
+            "_ZN5geode4Hook13setAutoEnableEb",
+            "_ZN5geode4Hook13setAutoEnableEb",
+            "_ZN5geode4Hook13setAutoEnableEb",
+            "_ZN5geode4Hook13setAutoEnableEb",
+            "_ZN5geode4Hook13setAutoEnableEb",
+        ],
+    ),
+    (
+        "geode::Hook::getAddress() const -> size_t",
+        1,
+        [
+            "?getAddress@Hook@geode@@QEBA_KXZ",
+            "_ZNK5geode4Hook10getAddressEv",
+            "_ZNK5geode4Hook10getAddressEv",
+            "_ZNK5geode4Hook10getAddressEv",
+            "_ZNK5geode4Hook10getAddressEv",
+            "_ZNK5geode4Hook10getAddressEv",
+        ],
+    ),
+    (
+        "geode::Hook::getDisplayName() const -> std::string_view",
+        1,
+        [
+            "?getDisplayName@Hook@geode@@QEBA?AV?$basic_string_view@DU?$char_traits@D@std@@@std@@XZ",
+            "_ZNK5geode4Hook14getDisplayNameEv",
+            "_ZNK5geode4Hook14getDisplayNameEv",
+            "_ZNK5geode4Hook14getDisplayNameEv",
+            "_ZNK5geode4Hook14getDisplayNameEv",
+            "_ZNK5geode4Hook14getDisplayNameEv",
+        ],
+    ),
+    (
+        "geode::Hook::getHookMetadata() const -> tulip::hook::HookMetadata",
+        1,
+        [
+            "?getHookMetadata@Hook@geode@@QEBA?AVHookMetadata@hook@tulip@@XZ",
+            "_ZNK5geode4Hook15getHookMetadataEv",
+            "_ZNK5geode4Hook15getHookMetadataEv",
+            "_ZNK5geode4Hook15getHookMetadataEv",
+            "_ZNK5geode4Hook15getHookMetadataEv",
+            "_ZNK5geode4Hook15getHookMetadataEv",
+        ],
+    ),
+    (
+        "geode::Hook::setHookMetadata(tulip::hook::HookMetadata const&)",
+        2,
+        [
+            "?setHookMetadata@Hook@geode@@QEAAXAEBVHookMetadata@hook@tulip@@@Z",
+            "_ZN5geode4Hook15setHookMetadataERKN5tulip4hook12HookMetadataE",
+            "_ZN5geode4Hook15setHookMetadataERKN5tulip4hook12HookMetadataE",
+            "_ZN5geode4Hook15setHookMetadataERKN5tulip4hook12HookMetadataE",
+            "_ZN5geode4Hook15setHookMetadataERKN5tulip4hook12HookMetadataE",
+            "_ZN5geode4Hook15setHookMetadataERKN5tulip4hook12HookMetadataE",
+        ],
+    ),
+    (
+        "geode::Hook::getPriority() const -> int",
+        1,
+        [
+            "?getPriority@Hook@geode@@QEBAHXZ",
+            "_ZNK5geode4Hook11getPriorityEv",
+            "_ZNK5geode4Hook11getPriorityEv",
+            "_ZNK5geode4Hook11getPriorityEv",
+            "_ZNK5geode4Hook11getPriorityEv",
+            "_ZNK5geode4Hook11getPriorityEv",
+        ],
+    ),
+    (
+        "geode::Hook::setPriority(int)",
+        2,
+        [
+            "?setPriority@Hook@geode@@QEAAXH@Z",
+            "_ZN5geode4Hook11setPriorityEi",
+            "_ZN5geode4Hook11setPriorityEi",
+            "_ZN5geode4Hook11setPriorityEi",
+            "_ZN5geode4Hook11setPriorityEi",
+            "_ZN5geode4Hook11setPriorityEi",
+        ],
+    ),
+    (
+        "static geode::Patch::create(void*, std::span<uint8_t const>) -> std::shared_ptr<geode::Patch>",
+        2,
+        [
+            "?create@Patch@geode@@SA?AV?$shared_ptr@VPatch@geode@@@std@@PEAXV?$span@$$CBE$0?0@4@@Z",
+            "_ZN5geode5Patch6createEPvNSt3__14spanIKhLm18446744073709551615EEE",
+            "_ZN5geode5Patch6createEPvNSt3__14spanIKhLm18446744073709551615EEE",
+            "_ZN5geode5Patch6createEPvNSt3__14spanIKhLm18446744073709551615EEE",
+            "_ZN5geode5Patch6createEPvNSt6__ndk14spanIKhLj4294967295EEE",
+            "_ZN5geode5Patch6createEPvNSt6__ndk14spanIKhLm18446744073709551615EEE",
+        ],
+    ),
+    (
+        "geode::Mod::claimPatch(std::shared_ptr<geode::Patch>) -> geode::Result<geode::Patch*>",
+        2,
+        [
+            "?claimPatch@Mod@geode@@QEAA?AV?$Result@PEAVPatch@geode@@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@V?$shared_ptr@VPatch@geode@@@std@@@Z",
+            "_ZN5geode3Mod10claimPatchENSt3__110shared_ptrINS_5PatchEEE",
+            "_ZN5geode3Mod10claimPatchENSt3__110shared_ptrINS_5PatchEEE",
+            "_ZN5geode3Mod10claimPatchENSt3__110shared_ptrINS_5PatchEEE",
+            "_ZN5geode3Mod10claimPatchENSt6__ndk110shared_ptrINS_5PatchEEE",
+            "_ZN5geode3Mod10claimPatchENSt6__ndk110shared_ptrINS_5PatchEEE",
+        ],
+    ),
+    (
+        "geode::Patch::getOwner() const -> geode::Mod*",
+        1,
+        [
+            "?getOwner@Patch@geode@@QEBAPEAVMod@2@XZ",
+            "_ZNK5geode5Patch8getOwnerEv",
+            "_ZNK5geode5Patch8getOwnerEv",
+            "_ZNK5geode5Patch8getOwnerEv",
+            "_ZNK5geode5Patch8getOwnerEv",
+            "_ZNK5geode5Patch8getOwnerEv",
+        ],
+    ),
+    (
+        "geode::Patch::isEnabled() const -> bool",
+        1,
+        [
+            "?isEnabled@Patch@geode@@QEBA_NXZ",
+            "_ZNK5geode5Patch9isEnabledEv",
+            "_ZNK5geode5Patch9isEnabledEv",
+            "_ZNK5geode5Patch9isEnabledEv",
+            "_ZNK5geode5Patch9isEnabledEv",
+            "_ZNK5geode5Patch9isEnabledEv",
+        ],
+    ),
+    (
+        "geode::Patch::enable() -> geode::Result<void>",
+        1,
+        [
+            "?enable@Patch@geode@@QEAA?AV?$Result@XV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@XZ",
+            "_ZN5geode5Patch6enableEv",
+            "_ZN5geode5Patch6enableEv",
+            "_ZN5geode5Patch6enableEv",
+            "_ZN5geode5Patch6enableEv",
+            "_ZN5geode5Patch6enableEv",
+        ],
+    ),
+    (
+        "geode::Patch::disable() -> geode::Result<void>",
+        1,
+        [
+            "?disable@Patch@geode@@QEAA?AV?$Result@XV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@XZ",
+            "_ZN5geode5Patch7disableEv",
+            "_ZN5geode5Patch7disableEv",
+            "_ZN5geode5Patch7disableEv",
+            "_ZN5geode5Patch7disableEv",
+            "_ZN5geode5Patch7disableEv",
+        ],
+    ),
+    (
+        "geode::Patch::toggle() -> geode::Result<void>",
+        1,
+        [
+            "?toggle@Patch@geode@@QEAA?AV?$Result@XV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@XZ",
+            "_ZN5geode5Patch6toggleEv",
+            "_ZN5geode5Patch6toggleEv",
+            "_ZN5geode5Patch6toggleEv",
+            "_ZN5geode5Patch6toggleEv",
+            "_ZN5geode5Patch6toggleEv",
+        ],
+    ),
+    (
+        "geode::Patch::toggle(bool) -> geode::Result<void>",
+        2,
+        [
+            "?toggle@Patch@geode@@QEAA?AV?$Result@XV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@_N@Z",
+            "_ZN5geode5Patch6toggleEb",
+            "_ZN5geode5Patch6toggleEb",
+            "_ZN5geode5Patch6toggleEb",
+            "_ZN5geode5Patch6toggleEb",
+            "_ZN5geode5Patch6toggleEb",
+        ],
+    ),
+    (
+        "geode::Patch::getAutoEnable() const -> bool",
+        1,
+        [
+            "?getAutoEnable@Patch@geode@@QEBA_NXZ",
+            "_ZNK5geode5Patch13getAutoEnableEv",
+            "_ZNK5geode5Patch13getAutoEnableEv",
+            "_ZNK5geode5Patch13getAutoEnableEv",
+            "_ZNK5geode5Patch13getAutoEnableEv",
+            "_ZNK5geode5Patch13getAutoEnableEv",
+        ],
+    ),
+    (
+        "geode::Patch::setAutoEnable(bool)",
+        2,
+        [
+            "?setAutoEnable@Patch@geode@@QEAAX_N@Z",
+            "_ZN5geode5Patch13setAutoEnableEb",
+            "_ZN5geode5Patch13setAutoEnableEb",
+            "_ZN5geode5Patch13setAutoEnableEb",
+            "_ZN5geode5Patch13setAutoEnableEb",
+            "_ZN5geode5Patch13setAutoEnableEb",
+        ],
+    ),
+    (
+        "geode::Patch::getBytes() const -> std::vector<unsigned char> const&",
+        1,
+        [
+            "?getBytes@Patch@geode@@QEBAAEBV?$vector@EV?$allocator@E@std@@@std@@XZ",
+            "_ZNK5geode5Patch8getBytesEv",
+            "_ZNK5geode5Patch8getBytesEv",
+            "_ZNK5geode5Patch8getBytesEv",
+            "_ZNK5geode5Patch8getBytesEv",
+            "_ZNK5geode5Patch8getBytesEv",
+        ],
+    ),
+    (
+        "geode::Patch::updateBytes(std::span<uint8_t const>) -> geode::Result<void>",
+        2,
+        [
+            "?updateBytes@Patch@geode@@QEAA?AV?$Result@XV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@V?$span@$$CBE$0?0@std@@@Z",
+            "_ZN5geode5Patch11updateBytesENSt3__14spanIKhLm18446744073709551615EEE",
+            "_ZN5geode5Patch11updateBytesENSt3__14spanIKhLm18446744073709551615EEE",
+            "_ZN5geode5Patch11updateBytesENSt3__14spanIKhLm18446744073709551615EEE",
+            "_ZN5geode5Patch11updateBytesENSt6__ndk14spanIKhLj4294967295EEE",
+            "_ZN5geode5Patch11updateBytesENSt6__ndk14spanIKhLm18446744073709551615EEE",
+        ],
+    ),
+    (
+        "geode::Patch::getAddress() const -> size_t",
+        1,
+        [
+            "?getAddress@Patch@geode@@QEBA_KXZ",
+            "_ZNK5geode5Patch10getAddressEv",
+            "_ZNK5geode5Patch10getAddressEv",
+            "_ZNK5geode5Patch10getAddressEv",
+            "_ZNK5geode5Patch10getAddressEv",
+            "_ZNK5geode5Patch10getAddressEv",
+        ],
+    ),
+    (
+        "geode::SettingV3::getKey() const -> std::string",
+        1,
+        [
+            "?getKey@SettingV3@geode@@QEBA?AV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@XZ",
+            "_ZNK5geode9SettingV36getKeyEv",
+            "_ZNK5geode9SettingV36getKeyEv",
+            "_ZNK5geode9SettingV36getKeyEv",
+            "_ZNK5geode9SettingV36getKeyEv",
+            "_ZNK5geode9SettingV36getKeyEv",
+        ],
+    ),
+    (
+        "geode::SettingV3::getModID() const -> std::string",
+        1,
+        [
+            "?getModID@SettingV3@geode@@QEBA?AV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@XZ",
+            "_ZNK5geode9SettingV38getModIDEv",
+            "_ZNK5geode9SettingV38getModIDEv",
+            "_ZNK5geode9SettingV38getModIDEv",
+            "_ZNK5geode9SettingV38getModIDEv",
+            "_ZNK5geode9SettingV38getModIDEv",
+        ],
+    ),
+    (
+        "geode::SettingV3::getMod() const -> geode::Mod*",
+        1,
+        [
+            "?getMod@SettingV3@geode@@QEBAPEAVMod@2@XZ",
+            "_ZNK5geode9SettingV36getModEv",
+            "_ZNK5geode9SettingV36getModEv",
+            "_ZNK5geode9SettingV36getModEv",
+            "_ZNK5geode9SettingV36getModEv",
+            "_ZNK5geode9SettingV36getModEv",
+        ],
+    ),
+    (
+        "geode::SettingV3::getName() const -> std::optional<std::string>",
+        1,
+        [
+            "?getName@SettingV3@geode@@QEBA?AV?$optional@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@std@@XZ",
+            "_ZNK5geode9SettingV37getNameEv",
+            "_ZNK5geode9SettingV37getNameEv",
+            "_ZNK5geode9SettingV37getNameEv",
+            "_ZNK5geode9SettingV37getNameEv",
+            "_ZNK5geode9SettingV37getNameEv",
+        ],
+    ),
+    (
+        "geode::SettingV3::getDisplayName() const -> std::string",
+        1,
+        [
+            "?getDisplayName@SettingV3@geode@@QEBA?AV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@XZ",
+            "_ZNK5geode9SettingV314getDisplayNameEv",
+            "_ZNK5geode9SettingV314getDisplayNameEv",
+            "_ZNK5geode9SettingV314getDisplayNameEv",
+            "_ZNK5geode9SettingV314getDisplayNameEv",
+            "_ZNK5geode9SettingV314getDisplayNameEv",
+        ],
+    ),
+    (
+        "geode::SettingV3::getDescription() const -> std::optional<std::string>",
+        1,
+        [
+            "?getDescription@SettingV3@geode@@QEBA?AV?$optional@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@std@@XZ",
+            "_ZNK5geode9SettingV314getDescriptionEv",
+            "_ZNK5geode9SettingV314getDescriptionEv",
+            "_ZNK5geode9SettingV314getDescriptionEv",
+            "_ZNK5geode9SettingV314getDescriptionEv",
+            "_ZNK5geode9SettingV314getDescriptionEv",
+        ],
+    ),
+    (
+        "geode::SettingV3::getEnableIf() const -> std::optional<std::string>",
+        1,
+        [
+            "?getEnableIf@SettingV3@geode@@QEBA?AV?$optional@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@std@@XZ",
+            "_ZNK5geode9SettingV311getEnableIfEv",
+            "_ZNK5geode9SettingV311getEnableIfEv",
+            "_ZNK5geode9SettingV311getEnableIfEv",
+            "_ZNK5geode9SettingV311getEnableIfEv",
+            "_ZNK5geode9SettingV311getEnableIfEv",
+        ],
+    ),
+    (
+        "geode::SettingV3::shouldEnable() const -> bool",
+        1,
+        [
+            "?shouldEnable@SettingV3@geode@@QEBA_NXZ",
+            "_ZNK5geode9SettingV312shouldEnableEv",
+            "_ZNK5geode9SettingV312shouldEnableEv",
+            "_ZNK5geode9SettingV312shouldEnableEv",
+            "_ZNK5geode9SettingV312shouldEnableEv",
+            "_ZNK5geode9SettingV312shouldEnableEv",
+        ],
+    ),
+    (
+        "geode::SettingV3::getEnableIfDescription() const -> std::optional<std::string>",
+        1,
+        [
+            "?getEnableIfDescription@SettingV3@geode@@QEBA?AV?$optional@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@std@@XZ",
+            "_ZNK5geode9SettingV322getEnableIfDescriptionEv",
+            "_ZNK5geode9SettingV322getEnableIfDescriptionEv",
+            "_ZNK5geode9SettingV322getEnableIfDescriptionEv",
+            "_ZNK5geode9SettingV322getEnableIfDescriptionEv",
+            "_ZNK5geode9SettingV322getEnableIfDescriptionEv",
+        ],
+    ),
+    (
+        "geode::SettingV3::requiresRestart() const -> bool",
+        1,
+        [
+            "?requiresRestart@SettingV3@geode@@QEBA_NXZ",
+            "_ZNK5geode9SettingV315requiresRestartEv",
+            "_ZNK5geode9SettingV315requiresRestartEv",
+            "_ZNK5geode9SettingV315requiresRestartEv",
+            "_ZNK5geode9SettingV315requiresRestartEv",
+            "_ZNK5geode9SettingV315requiresRestartEv",
+        ],
+    ),
+    (
+        "geode::dirs::getGameDir() -> std::filesystem::path",
+        0,
+        [
+            "?getGameDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs10getGameDirEv",
+            "_ZN5geode4dirs10getGameDirEv",
+            "_ZN5geode4dirs10getGameDirEv",
+            "_ZN5geode4dirs10getGameDirEv",
+            "_ZN5geode4dirs10getGameDirEv",
+        ],
+    ),
+    (
+        "geode::dirs::getSaveDir() -> std::filesystem::path",
+        0,
+        [
+            "?getSaveDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs10getSaveDirEv",
+            "_ZN5geode4dirs10getSaveDirEv",
+            "_ZN5geode4dirs10getSaveDirEv",
+            "_ZN5geode4dirs10getSaveDirEv",
+            "_ZN5geode4dirs10getSaveDirEv",
+        ],
+    ),
+    (
+        "geode::dirs::getGeodeDir() -> std::filesystem::path",
+        0,
+        [
+            "?getGeodeDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs11getGeodeDirEv",
+            "_ZN5geode4dirs11getGeodeDirEv",
+            "_ZN5geode4dirs11getGeodeDirEv",
+            "_ZN5geode4dirs11getGeodeDirEv",
+            "_ZN5geode4dirs11getGeodeDirEv",
+        ],
+    ),
+    (
+        "geode::dirs::getGeodeSaveDir() -> std::filesystem::path",
+        0,
+        [
+            "?getGeodeSaveDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs15getGeodeSaveDirEv",
+            "_ZN5geode4dirs15getGeodeSaveDirEv",
+            "_ZN5geode4dirs15getGeodeSaveDirEv",
+            "_ZN5geode4dirs15getGeodeSaveDirEv",
+            "_ZN5geode4dirs15getGeodeSaveDirEv",
+        ],
+    ),
+    (
+        "geode::dirs::getGeodeResourcesDir() -> std::filesystem::path",
+        0,
+        [
+            "?getGeodeResourcesDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs20getGeodeResourcesDirEv",
+            "_ZN5geode4dirs20getGeodeResourcesDirEv",
+            "_ZN5geode4dirs20getGeodeResourcesDirEv",
+            "_ZN5geode4dirs20getGeodeResourcesDirEv",
+            "_ZN5geode4dirs20getGeodeResourcesDirEv",
+        ],
+    ),
+    (
+        "geode::dirs::getGeodeLogDir() -> std::filesystem::path",
+        0,
+        [
+            "?getGeodeLogDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs14getGeodeLogDirEv",
+            "_ZN5geode4dirs14getGeodeLogDirEv",
+            "_ZN5geode4dirs14getGeodeLogDirEv",
+            "_ZN5geode4dirs14getGeodeLogDirEv",
+            "_ZN5geode4dirs14getGeodeLogDirEv",
+        ],
+    ),
+    (
+        "geode::dirs::getTempDir() -> std::filesystem::path",
+        0,
+        [
+            "?getTempDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs10getTempDirEv",
+            "_ZN5geode4dirs10getTempDirEv",
+            "_ZN5geode4dirs10getTempDirEv",
+            "_ZN5geode4dirs10getTempDirEv",
+            "_ZN5geode4dirs10getTempDirEv",
+        ],
+    ),
+    (
+        "geode::dirs::getModsDir() -> std::filesystem::path",
+        0,
+        [
+            "?getModsDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs10getModsDirEv",
+            "_ZN5geode4dirs10getModsDirEv",
+            "_ZN5geode4dirs10getModsDirEv",
+            "_ZN5geode4dirs10getModsDirEv",
+            "_ZN5geode4dirs10getModsDirEv",
+        ],
+    ),
+    (
+        "geode::dirs::getModsSaveDir() -> std::filesystem::path",
+        0,
+        [
+            "?getModsSaveDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs14getModsSaveDirEv",
+            "_ZN5geode4dirs14getModsSaveDirEv",
+            "_ZN5geode4dirs14getModsSaveDirEv",
+            "_ZN5geode4dirs14getModsSaveDirEv",
+            "_ZN5geode4dirs14getModsSaveDirEv",
+        ],
+    ),
+    (
+        "geode::dirs::getModRuntimeDir() -> std::filesystem::path",
+        0,
+        [
+            "?getModRuntimeDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs16getModRuntimeDirEv",
+            "_ZN5geode4dirs16getModRuntimeDirEv",
+            "_ZN5geode4dirs16getModRuntimeDirEv",
+            "_ZN5geode4dirs16getModRuntimeDirEv",
+            "_ZN5geode4dirs16getModRuntimeDirEv",
+        ],
+    ),
+    (
+        "geode::dirs::getModConfigDir() -> std::filesystem::path",
+        0,
+        [
+            "?getModConfigDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs15getModConfigDirEv",
+            "_ZN5geode4dirs15getModConfigDirEv",
+            "_ZN5geode4dirs15getModConfigDirEv",
+            "_ZN5geode4dirs15getModConfigDirEv",
+            "_ZN5geode4dirs15getModConfigDirEv",
+        ],
+    ),
+    (
+        "geode::dirs::getIndexDir() -> std::filesystem::path",
+        0,
+        [
+            "?getIndexDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs11getIndexDirEv",
+            "_ZN5geode4dirs11getIndexDirEv",
+            "_ZN5geode4dirs11getIndexDirEv",
+            "_ZN5geode4dirs11getIndexDirEv",
+            "_ZN5geode4dirs11getIndexDirEv",
+        ],
+    ),
+    (
+        "geode::dirs::getCrashlogsDir() -> std::filesystem::path",
+        0,
+        [
+            "?getCrashlogsDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs15getCrashlogsDirEv",
+            "_ZN5geode4dirs15getCrashlogsDirEv",
+            "_ZN5geode4dirs15getCrashlogsDirEv",
+            "_ZN5geode4dirs15getCrashlogsDirEv",
+            "_ZN5geode4dirs15getCrashlogsDirEv",
+        ],
+    ),
+    (
+        "geode::dirs::getModPersistentDir() -> std::filesystem::path",
+        0,
+        [
+            "?getModPersistentDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs19getModPersistentDirEv",
+            "_ZN5geode4dirs19getModPersistentDirEv",
+            "_ZN5geode4dirs19getModPersistentDirEv",
+            "_ZN5geode4dirs19getModPersistentDirEv",
+            "_ZN5geode4dirs19getModPersistentDirEv",
+        ],
+    ),
+    (
+        "geode::dirs::getResourcesDir() -> std::filesystem::path",
+        0,
+        [
+            "?getResourcesDir@dirs@geode@@YA?AVpath@filesystem@std@@XZ",
+            "_ZN5geode4dirs15getResourcesDirEv",
+            "_ZN5geode4dirs15getResourcesDirEv",
+            "_ZN5geode4dirs15getResourcesDirEv",
+            "_ZN5geode4dirs15getResourcesDirEv",
+            "_ZN5geode4dirs15getResourcesDirEv",
+        ],
+    ),
+    (
+        "geode::log::getCurrentLogPath() -> std::filesystem::path const&",
+        0,
+        [
+            "?getCurrentLogPath@log@geode@@YAAEBVpath@filesystem@std@@XZ",
+            "_ZN5geode3log17getCurrentLogPathEv",
+            "_ZN5geode3log17getCurrentLogPathEv",
+            "_ZN5geode3log17getCurrentLogPathEv",
+            "_ZN5geode3log17getCurrentLogPathEv",
+            "_ZN5geode3log17getCurrentLogPathEv",
+        ],
+    ),
+    (
+        "geode::Loader::isForwardCompatMode() -> bool",
+        1,
+        [
+            "?isForwardCompatMode@Loader@geode@@QEAA_NXZ",
+            "_ZN5geode6Loader19isForwardCompatModeEv",
+            "_ZN5geode6Loader19isForwardCompatModeEv",
+            "_ZN5geode6Loader19isForwardCompatModeEv",
+            "_ZN5geode6Loader19isForwardCompatModeEv",
+            "_ZN5geode6Loader19isForwardCompatModeEv",
+        ],
+    ),
+    (
+        "geode::Loader::saveData()",
+        1,
+        [
+            "?saveData@Loader@geode@@QEAAXXZ",
+            "_ZN5geode6Loader8saveDataEv",
+            "_ZN5geode6Loader8saveDataEv",
+            "_ZN5geode6Loader8saveDataEv",
+            "_ZN5geode6Loader8saveDataEv",
+            "_ZN5geode6Loader8saveDataEv",
+        ],
+    ),
+    (
+        "geode::Loader::loadData()",
+        1,
+        [
+            "?loadData@Loader@geode@@QEAAXXZ",
+            "_ZN5geode6Loader8loadDataEv",
+            "_ZN5geode6Loader8loadDataEv",
+            "_ZN5geode6Loader8loadDataEv",
+            "_ZN5geode6Loader8loadDataEv",
+            "_ZN5geode6Loader8loadDataEv",
+        ],
+    ),
+    (
+        "geode::Loader::getVersion() -> geode::VersionInfo",
+        1,
+        [
+            "?getVersion@Loader@geode@@QEAA?AVVersionInfo@2@XZ",
+            "_ZN5geode6Loader10getVersionEv",
+            "_ZN5geode6Loader10getVersionEv",
+            "_ZN5geode6Loader10getVersionEv",
+            "_ZN5geode6Loader10getVersionEv",
+            "_ZN5geode6Loader10getVersionEv",
+        ],
+    ),
+    (
+        "geode::Loader::minModVersion() -> geode::VersionInfo",
+        1,
+        [
+            "?minModVersion@Loader@geode@@QEAA?AVVersionInfo@2@XZ",
+            "_ZN5geode6Loader13minModVersionEv",
+            "_ZN5geode6Loader13minModVersionEv",
+            "_ZN5geode6Loader13minModVersionEv",
+            "_ZN5geode6Loader13minModVersionEv",
+            "_ZN5geode6Loader13minModVersionEv",
+        ],
+    ),
+    (
+        "geode::Loader::maxModVersion() -> geode::VersionInfo",
+        1,
+        [
+            "?maxModVersion@Loader@geode@@QEAA?AVVersionInfo@2@XZ",
+            "_ZN5geode6Loader13maxModVersionEv",
+            "_ZN5geode6Loader13maxModVersionEv",
+            "_ZN5geode6Loader13maxModVersionEv",
+            "_ZN5geode6Loader13maxModVersionEv",
+            "_ZN5geode6Loader13maxModVersionEv",
+        ],
+    ),
+    (
+        "geode::Loader::isModVersionSupported(geode::VersionInfo const&) -> bool",
+        2,
+        [
+            "?isModVersionSupported@Loader@geode@@QEAA_NAEBVVersionInfo@2@@Z",
+            "_ZN5geode6Loader21isModVersionSupportedERKNS_11VersionInfoE",
+            "_ZN5geode6Loader21isModVersionSupportedERKNS_11VersionInfoE",
+            "_ZN5geode6Loader21isModVersionSupportedERKNS_11VersionInfoE",
+            "_ZN5geode6Loader21isModVersionSupportedERKNS_11VersionInfoE",
+            "_ZN5geode6Loader21isModVersionSupportedERKNS_11VersionInfoE",
+        ],
+    ),
+    (
+        "geode::Loader::getLoadProblems() const -> std::vector<struct geode::LoadProblem>",
+        1,
+        [
+            "?getLoadProblems@Loader@geode@@QEBA?AV?$vector@ULoadProblem@geode@@V?$allocator@ULoadProblem@geode@@@std@@@std@@XZ",
+            "_ZNK5geode6Loader15getLoadProblemsEv",
+            "_ZNK5geode6Loader15getLoadProblemsEv",
+            "_ZNK5geode6Loader15getLoadProblemsEv",
+            "_ZNK5geode6Loader15getLoadProblemsEv",
+            "_ZNK5geode6Loader15getLoadProblemsEv",
+        ],
+    ),
+    (
+        "geode::Loader::getLaunchFlag(std::string_view) const -> bool",
+        2,
+        [
+            "?getLaunchFlag@Loader@geode@@QEBA_NV?$basic_string_view@DU?$char_traits@D@std@@@std@@@Z",
+            "_ZNK5geode6Loader13getLaunchFlagENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader13getLaunchFlagENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader13getLaunchFlagENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader13getLaunchFlagENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode6Loader13getLaunchFlagENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Mod::getDevelopers() const -> std::vector<std::string>",
+        1,
+        [
+            "?getDevelopers@Mod@geode@@QEBA?AV?$vector@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@V?$allocator@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@@std@@XZ",
+            "_ZNK5geode3Mod13getDevelopersEv",
+            "_ZNK5geode3Mod13getDevelopersEv",
+            "_ZNK5geode3Mod13getDevelopersEv",
+            "_ZNK5geode3Mod13getDevelopersEv",
+            "_ZNK5geode3Mod13getDevelopersEv",
+        ],
+    ),
+    (
+        "geode::Mod::getDescription() const -> std::optional<std::string>",
+        1,
+        [
+            "?getDescription@Mod@geode@@QEBA?AV?$optional@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@std@@XZ",
+            "_ZNK5geode3Mod14getDescriptionEv",
+            "_ZNK5geode3Mod14getDescriptionEv",
+            "_ZNK5geode3Mod14getDescriptionEv",
+            "_ZNK5geode3Mod14getDescriptionEv",
+            "_ZNK5geode3Mod14getDescriptionEv",
+        ],
+    ),
+    (
+        "geode::Mod::getDetails() const -> std::optional<std::string>",
+        1,
+        [
+            "?getDetails@Mod@geode@@QEBA?AV?$optional@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@std@@XZ",
+            "_ZNK5geode3Mod10getDetailsEv",
+            "_ZNK5geode3Mod10getDetailsEv",
+            "_ZNK5geode3Mod10getDetailsEv",
+            "_ZNK5geode3Mod10getDetailsEv",
+            "_ZNK5geode3Mod10getDetailsEv",
+        ],
+    ),
+    (
+        "geode::Mod::getPackagePath() const -> std::filesystem::path",
+        1,
+        [
+            "?getPackagePath@Mod@geode@@QEBA?AVpath@filesystem@std@@XZ",
+            "_ZNK5geode3Mod14getPackagePathEv",
+            "_ZNK5geode3Mod14getPackagePathEv",
+            "_ZNK5geode3Mod14getPackagePathEv",
+            "_ZNK5geode3Mod14getPackagePathEv",
+            "_ZNK5geode3Mod14getPackagePathEv",
+        ],
+    ),
+    (
+        "geode::Mod::getVersion() const -> geode::VersionInfo",
+        1,
+        [
+            "?getVersion@Mod@geode@@QEBA?AVVersionInfo@2@XZ",
+            "_ZNK5geode3Mod10getVersionEv",
+            "_ZNK5geode3Mod10getVersionEv",
+            "_ZNK5geode3Mod10getVersionEv",
+            "_ZNK5geode3Mod10getVersionEv",
+            "_ZNK5geode3Mod10getVersionEv",
+        ],
+    ),
+    (
+        "geode::Mod::isOrWillBeEnabled() const -> bool",
+        1,
+        [
+            "?isOrWillBeEnabled@Mod@geode@@QEBA_NXZ",
+            "_ZNK5geode3Mod17isOrWillBeEnabledEv",
+            "_ZNK5geode3Mod17isOrWillBeEnabledEv",
+            "_ZNK5geode3Mod17isOrWillBeEnabledEv",
+            "_ZNK5geode3Mod17isOrWillBeEnabledEv",
+            "_ZNK5geode3Mod17isOrWillBeEnabledEv",
+        ],
+    ),
+    (
+        "geode::Mod::isInternal() const -> bool",
+        1,
+        [
+            "?isInternal@Mod@geode@@QEBA_NXZ",
+            "_ZNK5geode3Mod10isInternalEv",
+            "_ZNK5geode3Mod10isInternalEv",
+            "_ZNK5geode3Mod10isInternalEv",
+            "_ZNK5geode3Mod10isInternalEv",
+            "_ZNK5geode3Mod10isInternalEv",
+        ],
+    ),
+    (
+        "geode::Mod::needsEarlyLoad() const -> bool",
+        1,
+        [
+            "?needsEarlyLoad@Mod@geode@@QEBA_NXZ",
+            "_ZNK5geode3Mod14needsEarlyLoadEv",
+            "_ZNK5geode3Mod14needsEarlyLoadEv",
+            "_ZNK5geode3Mod14needsEarlyLoadEv",
+            "_ZNK5geode3Mod14needsEarlyLoadEv",
+            "_ZNK5geode3Mod14needsEarlyLoadEv",
+        ],
+    ),
+    (
+        "geode::Mod::getMetadata() const -> geode::ModMetadata const&",
+        1,
+        [
+            "?getMetadata@Mod@geode@@QEBAAEBVModMetadata@2@XZ",
+            "_ZNK5geode3Mod11getMetadataEv",
+            "_ZNK5geode3Mod11getMetadataEv",
+            "_ZNK5geode3Mod11getMetadataEv",
+            "_ZNK5geode3Mod11getMetadataEv",
+            "_ZNK5geode3Mod11getMetadataEv",
+        ],
+    ),
+    (
+        "geode::Mod::getTempDir() const -> std::filesystem::path",
+        1,
+        [
+            "?getTempDir@Mod@geode@@QEBA?AVpath@filesystem@std@@XZ",
+            "_ZNK5geode3Mod10getTempDirEv",
+            "_ZNK5geode3Mod10getTempDirEv",
+            "_ZNK5geode3Mod10getTempDirEv",
+            "_ZNK5geode3Mod10getTempDirEv",
+            "_ZNK5geode3Mod10getTempDirEv",
+        ],
+    ),
+    (
+        "geode::Mod::getBinaryPath() const -> std::filesystem::path",
+        1,
+        [
+            "?getBinaryPath@Mod@geode@@QEBA?AVpath@filesystem@std@@XZ",
+            "_ZNK5geode3Mod13getBinaryPathEv",
+            "_ZNK5geode3Mod13getBinaryPathEv",
+            "_ZNK5geode3Mod13getBinaryPathEv",
+            "_ZNK5geode3Mod13getBinaryPathEv",
+            "_ZNK5geode3Mod13getBinaryPathEv",
+        ],
+    ),
+    (
+        "geode::Mod::getResourcesDir() const -> std::filesystem::path",
+        1,
+        [
+            "?getResourcesDir@Mod@geode@@QEBA?AVpath@filesystem@std@@XZ",
+            "_ZNK5geode3Mod15getResourcesDirEv",
+            "_ZNK5geode3Mod15getResourcesDirEv",
+            "_ZNK5geode3Mod15getResourcesDirEv",
+            "_ZNK5geode3Mod15getResourcesDirEv",
+            "_ZNK5geode3Mod15getResourcesDirEv",
+        ],
+    ),
+    (
+        "geode::Mod::getDependencySettingsFor(std::string_view) const -> matjson::Value",
+        2,
+        [
+            "?getDependencySettingsFor@Mod@geode@@QEBA?AVValue@matjson@@V?$basic_string_view@DU?$char_traits@D@std@@@std@@@Z",
+            "_ZNK5geode3Mod24getDependencySettingsForENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod24getDependencySettingsForENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod24getDependencySettingsForENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod24getDependencySettingsForENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod24getDependencySettingsForENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Mod::saveData() -> geode::Result<void>",
+        1,
+        [
+            "?saveData@Mod@geode@@QEAA?AV?$Result@XV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@XZ",
+            "_ZN5geode3Mod8saveDataEv",
+            "_ZN5geode3Mod8saveDataEv",
+            "_ZN5geode3Mod8saveDataEv",
+            "_ZN5geode3Mod8saveDataEv",
+            "_ZN5geode3Mod8saveDataEv",
+        ],
+    ),
+    (
+        "geode::Mod::loadData() -> geode::Result<void>",
+        1,
+        [
+            "?loadData@Mod@geode@@QEAA?AV?$Result@XV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@XZ",
+            "_ZN5geode3Mod8loadDataEv",
+            "_ZN5geode3Mod8loadDataEv",
+            "_ZN5geode3Mod8loadDataEv",
+            "_ZN5geode3Mod8loadDataEv",
+            "_ZN5geode3Mod8loadDataEv",
+        ],
+    ),
+    (
+        "geode::Mod::getSaveDir() const -> std::filesystem::path",
+        1,
+        [
+            "?getSaveDir@Mod@geode@@QEBA?AVpath@filesystem@std@@XZ",
+            "_ZNK5geode3Mod10getSaveDirEv",
+            "_ZNK5geode3Mod10getSaveDirEv",
+            "_ZNK5geode3Mod10getSaveDirEv",
+            "_ZNK5geode3Mod10getSaveDirEv",
+            "_ZNK5geode3Mod10getSaveDirEv",
+        ],
+    ),
+    (
+        "geode::Mod::getConfigDir(bool) const -> std::filesystem::path",
+        2,
+        [
+            "?getConfigDir@Mod@geode@@QEBA?AVpath@filesystem@std@@_N@Z",
+            "_ZNK5geode3Mod12getConfigDirEb",
+            "_ZNK5geode3Mod12getConfigDirEb",
+            "_ZNK5geode3Mod12getConfigDirEb",
+            "_ZNK5geode3Mod12getConfigDirEb",
+            "_ZNK5geode3Mod12getConfigDirEb",
+        ],
+    ),
+    (
+        "geode::Mod::getPersistentDir(bool) const -> std::filesystem::path",
+        2,
+        [
+            "?getPersistentDir@Mod@geode@@QEBA?AVpath@filesystem@std@@_N@Z",
+            "_ZNK5geode3Mod16getPersistentDirEb",
+            "_ZNK5geode3Mod16getPersistentDirEb",
+            "_ZNK5geode3Mod16getPersistentDirEb",
+            "_ZNK5geode3Mod16getPersistentDirEb",
+            "_ZNK5geode3Mod16getPersistentDirEb",
+        ],
+    ),
+    (
+        "geode::Mod::getLaunchFlag(std::string_view) const -> bool",
+        2,
+        [
+            "?getLaunchFlag@Mod@geode@@QEBA_NV?$basic_string_view@DU?$char_traits@D@std@@@std@@@Z",
+            "_ZNK5geode3Mod13getLaunchFlagENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod13getLaunchFlagENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod13getLaunchFlagENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod13getLaunchFlagENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod13getLaunchFlagENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Mod::uninstall(bool) -> geode::Result<void>",
+        2,
+        [
+            "?uninstall@Mod@geode@@QEAA?AV?$Result@XV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@_N@Z",
+            "_ZN5geode3Mod9uninstallEb",
+            "_ZN5geode3Mod9uninstallEb",
+            "_ZN5geode3Mod9uninstallEb",
+            "_ZN5geode3Mod9uninstallEb",
+            "_ZN5geode3Mod9uninstallEb",
+        ],
+    ),
+    (
+        "geode::Mod::isUninstalled() const -> bool",
+        1,
+        [
+            "?isUninstalled@Mod@geode@@QEBA_NXZ",
+            "_ZNK5geode3Mod13isUninstalledEv",
+            "_ZNK5geode3Mod13isUninstalledEv",
+            "_ZNK5geode3Mod13isUninstalledEv",
+            "_ZNK5geode3Mod13isUninstalledEv",
+            "_ZNK5geode3Mod13isUninstalledEv",
+        ],
+    ),
+    (
+        "geode::Mod::getRequestedAction() const -> enum geode::ModRequestedAction",
+        1,
+        [
+            "?getRequestedAction@Mod@geode@@QEBA?AW4ModRequestedAction@2@XZ",
+            "_ZNK5geode3Mod18getRequestedActionEv",
+            "_ZNK5geode3Mod18getRequestedActionEv",
+            "_ZNK5geode3Mod18getRequestedActionEv",
+            "_ZNK5geode3Mod18getRequestedActionEv",
+            "_ZNK5geode3Mod18getRequestedActionEv",
+        ],
+    ),
+    (
+        "geode::Mod::depends(std::string_view) const -> bool",
+        2,
+        [
+            "?depends@Mod@geode@@QEBA_NV?$basic_string_view@DU?$char_traits@D@std@@@std@@@Z",
+            "_ZNK5geode3Mod7dependsENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod7dependsENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod7dependsENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod7dependsENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK5geode3Mod7dependsENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Mod::hasUnresolvedDependencies() const -> bool",
+        1,
+        [
+            "?hasUnresolvedDependencies@Mod@geode@@QEBA_NXZ",
+            "_ZNK5geode3Mod25hasUnresolvedDependenciesEv",
+            "_ZNK5geode3Mod25hasUnresolvedDependenciesEv",
+            "_ZNK5geode3Mod25hasUnresolvedDependenciesEv",
+            "_ZNK5geode3Mod25hasUnresolvedDependenciesEv",
+            "_ZNK5geode3Mod25hasUnresolvedDependenciesEv",
+        ],
+    ),
+    (
+        "geode::Mod::hasUnresolvedIncompatibilities() const -> bool",
+        1,
+        [
+            "?hasUnresolvedIncompatibilities@Mod@geode@@QEBA_NXZ",
+            "_ZNK5geode3Mod30hasUnresolvedIncompatibilitiesEv",
+            "_ZNK5geode3Mod30hasUnresolvedIncompatibilitiesEv",
+            "_ZNK5geode3Mod30hasUnresolvedIncompatibilitiesEv",
+            "_ZNK5geode3Mod30hasUnresolvedIncompatibilitiesEv",
+            "_ZNK5geode3Mod30hasUnresolvedIncompatibilitiesEv",
+        ],
+    ),
+    (
+        "geode::Mod::expandSpriteName(std::string_view) -> std::string",
+        2,
+        [
+            "?expandSpriteName@Mod@geode@@QEAA?AV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@V?$basic_string_view@DU?$char_traits@D@std@@@4@@Z",
+            "_ZN5geode3Mod16expandSpriteNameENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZN5geode3Mod16expandSpriteNameENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZN5geode3Mod16expandSpriteNameENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZN5geode3Mod16expandSpriteNameENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZN5geode3Mod16expandSpriteNameENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "geode::Mod::getRuntimeInfo() const -> matjson::Value",
+        1,
+        [
+            "?getRuntimeInfo@Mod@geode@@QEBA?AVValue@matjson@@XZ",
+            "_ZNK5geode3Mod14getRuntimeInfoEv",
+            "_ZNK5geode3Mod14getRuntimeInfoEv",
+            "_ZNK5geode3Mod14getRuntimeInfoEv",
+            "_ZNK5geode3Mod14getRuntimeInfoEv",
+            "_ZNK5geode3Mod14getRuntimeInfoEv",
+        ],
+    ),
+    (
+        "geode::Mod::isLoggingEnabled() const -> bool",
+        1,
+        [
+            "?isLoggingEnabled@Mod@geode@@QEBA_NXZ",
+            "_ZNK5geode3Mod16isLoggingEnabledEv",
+            "_ZNK5geode3Mod16isLoggingEnabledEv",
+            "_ZNK5geode3Mod16isLoggingEnabledEv",
+            "_ZNK5geode3Mod16isLoggingEnabledEv",
+            "_ZNK5geode3Mod16isLoggingEnabledEv",
+        ],
+    ),
+    (
+        "geode::Mod::setLoggingEnabled(bool)",
+        2,
+        [
+            "?setLoggingEnabled@Mod@geode@@QEAAX_N@Z",
+            "_ZN5geode3Mod17setLoggingEnabledEb",
+            "_ZN5geode3Mod17setLoggingEnabledEb",
+            "_ZN5geode3Mod17setLoggingEnabledEb",
+            "_ZN5geode3Mod17setLoggingEnabledEb",
+            "_ZN5geode3Mod17setLoggingEnabledEb",
+        ],
+    ),
+    (
+        "geode::Mod::getLogLevel() const -> struct geode::Severity",
+        1,
+        [
+            "?getLogLevel@Mod@geode@@QEBA?AUSeverity@2@XZ",
+            "_ZNK5geode3Mod11getLogLevelEv",
+            "_ZNK5geode3Mod11getLogLevelEv",
+            "_ZNK5geode3Mod11getLogLevelEv",
+            "_ZNK5geode3Mod11getLogLevelEv",
+            "_ZNK5geode3Mod11getLogLevelEv",
+        ],
+    ),
+    (
+        "geode::Mod::setLogLevel(struct geode::Severity)",
+        2,
+        [
+            "?setLogLevel@Mod@geode@@QEAAXUSeverity@2@@Z",
+            "_ZN5geode3Mod11setLogLevelENS_8SeverityE",
+            "_ZN5geode3Mod11setLogLevelENS_8SeverityE",
+            "_ZN5geode3Mod11setLogLevelENS_8SeverityE",
+            "_ZN5geode3Mod11setLogLevelENS_8SeverityE",
+            "_ZN5geode3Mod11setLogLevelENS_8SeverityE",
+        ],
+    ),
+    (
+        "geode::Mod::targetsOutdatedVersion() const -> std::optional<struct geode::LoadProblem>",
+        1,
+        [
+            "?targetsOutdatedVersion@Mod@geode@@QEBA?AV?$optional@ULoadProblem@geode@@@std@@XZ",
+            "_ZNK5geode3Mod22targetsOutdatedVersionEv",
+            "_ZNK5geode3Mod22targetsOutdatedVersionEv",
+            "_ZNK5geode3Mod22targetsOutdatedVersionEv",
+            "_ZNK5geode3Mod22targetsOutdatedVersionEv",
+            "_ZNK5geode3Mod22targetsOutdatedVersionEv",
+        ],
+    ),
+    (
+        "geode::Mod::failedToLoad() const -> std::optional<struct geode::LoadProblem>",
+        1,
+        [
+            "?failedToLoad@Mod@geode@@QEBA?AV?$optional@ULoadProblem@geode@@@std@@XZ",
+            "_ZNK5geode3Mod12failedToLoadEv",
+            "_ZNK5geode3Mod12failedToLoadEv",
+            "_ZNK5geode3Mod12failedToLoadEv",
+            "_ZNK5geode3Mod12failedToLoadEv",
+            "_ZNK5geode3Mod12failedToLoadEv",
+        ],
+    ),
+    (
+        "geode::Mod::getLoadProblem() const -> std::optional<struct geode::LoadProblem>",
+        1,
+        [
+            "?getLoadProblem@Mod@geode@@QEBA?AV?$optional@ULoadProblem@geode@@@std@@XZ",
+            "_ZNK5geode3Mod14getLoadProblemEv",
+            "_ZNK5geode3Mod14getLoadProblemEv",
+            "_ZNK5geode3Mod14getLoadProblemEv",
+            "_ZNK5geode3Mod14getLoadProblemEv",
+            "_ZNK5geode3Mod14getLoadProblemEv",
+        ],
+    ),
+    (
+        "geode::Mod::shouldLoad() const -> bool",
+        1,
+        [
+            "?shouldLoad@Mod@geode@@QEBA_NXZ",
+            "_ZNK5geode3Mod10shouldLoadEv",
+            "_ZNK5geode3Mod10shouldLoadEv",
+            "_ZNK5geode3Mod10shouldLoadEv",
+            "_ZNK5geode3Mod10shouldLoadEv",
+            "_ZNK5geode3Mod10shouldLoadEv",
+        ],
+    ),
+    (
+        "geode::Mod::getLoadPriority() const -> int",
+        1,
+        [
+            "?getLoadPriority@Mod@geode@@QEBAHXZ",
+            "_ZNK5geode3Mod15getLoadPriorityEv",
+            "_ZNK5geode3Mod15getLoadPriorityEv",
+            "_ZNK5geode3Mod15getLoadPriorityEv",
+            "_ZNK5geode3Mod15getLoadPriorityEv",
+            "_ZNK5geode3Mod15getLoadPriorityEv",
+        ],
+    ),
+    (
+        "geode::Mod::isPinned() const -> bool",
+        1,
+        [
+            "?isPinned@Mod@geode@@QEBA_NXZ",
+            "_ZNK5geode3Mod8isPinnedEv",
+            "_ZNK5geode3Mod8isPinnedEv",
+            "_ZNK5geode3Mod8isPinnedEv",
+            "_ZNK5geode3Mod8isPinnedEv",
+            "_ZNK5geode3Mod8isPinnedEv",
+        ],
+    ),
+    (
+        "geode::Mod::setPinned(bool)",
+        2,
+        [
+            "?setPinned@Mod@geode@@QEAAX_N@Z",
+            "_ZN5geode3Mod9setPinnedEb",
+            "_ZN5geode3Mod9setPinnedEb",
+            "_ZN5geode3Mod9setPinnedEb",
+            "_ZN5geode3Mod9setPinnedEb",
+            "_ZN5geode3Mod9setPinnedEb",
+        ],
+    ),
+    (
+        "matjson::Value::Value(matjson::Value const&)",
+        2,
+        [
+            "??0Value@matjson@@QEAA@AEBV01@@Z",
+            "_ZN7matjson5ValueC1ERKS0_",
+            "_ZN7matjson5ValueC1ERKS0_",
+            "_ZN7matjson5ValueC1ERKS0_",
+            "_ZN7matjson5ValueC1ERKS0_",
+            "_ZN7matjson5ValueC1ERKS0_",
+        ],
+    ),
+    (
+        "matjson::Value::~Value()",
+        1,
+        [
+            "??1Value@matjson@@QEAA@XZ",
+            "_ZN7matjson5ValueD1Ev",
+            "_ZN7matjson5ValueD1Ev",
+            "_ZN7matjson5ValueD1Ev",
+            "_ZN7matjson5ValueD1Ev",
+            "_ZN7matjson5ValueD1Ev",
+        ],
+    ),
+    (
+        "matjson::Value::dump(int) const -> std::string",
+        2,
+        [
+            "?dump@Value@matjson@@QEBA?AV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@H@Z",
+            "_ZNK7matjson5Value4dumpEi",
+            "_ZNK7matjson5Value4dumpEi",
+            "_ZNK7matjson5Value4dumpEi",
+            "_ZNK7matjson5Value4dumpEi",
+            "_ZNK7matjson5Value4dumpEi",
+        ],
+    ),
+    (
+        "matjson::Value::type() const -> enum matjson::Type",
+        1,
+        [
+            "?type@Value@matjson@@QEBA?AW4Type@2@XZ",
+            "_ZNK7matjson5Value4typeEv",
+            "_ZNK7matjson5Value4typeEv",
+            "_ZNK7matjson5Value4typeEv",
+            "_ZNK7matjson5Value4typeEv",
+            "_ZNK7matjson5Value4typeEv",
+        ],
+    ),
+    (
+        "matjson::Value::size() const -> size_t",
+        1,
+        [
+            "?size@Value@matjson@@QEBA_KXZ",
+            "_ZNK7matjson5Value4sizeEv",
+            "_ZNK7matjson5Value4sizeEv",
+            "_ZNK7matjson5Value4sizeEv",
+            "_ZNK7matjson5Value4sizeEv",
+            "_ZNK7matjson5Value4sizeEv",
+        ],
+    ),
+    (
+        "matjson::Value::contains(std::string_view) const -> bool",
+        2,
+        [
+            "?contains@Value@matjson@@QEBA_NV?$basic_string_view@DU?$char_traits@D@std@@@std@@@Z",
+            "_ZNK7matjson5Value8containsENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK7matjson5Value8containsENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK7matjson5Value8containsENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK7matjson5Value8containsENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZNK7matjson5Value8containsENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "matjson::Value::asString() const -> geode::Result<std::string>",
+        1,
+        [
+            "?asString@Value@matjson@@QEBA?AV?$Result@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@V12@@geode@@XZ",
+            "_ZNK7matjson5Value8asStringEv",
+            "_ZNK7matjson5Value8asStringEv",
+            "_ZNK7matjson5Value8asStringEv",
+            "_ZNK7matjson5Value8asStringEv",
+            "_ZNK7matjson5Value8asStringEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::ModMetadata(geode::ModMetadata const&)",
+        2,
+        [
+            "??0ModMetadata@geode@@QEAA@AEBV01@@Z",
+            "_ZN5geode11ModMetadataC1ERKS0_",
+            "_ZN5geode11ModMetadataC1ERKS0_",
+            "_ZN5geode11ModMetadataC1ERKS0_",
+            "_ZN5geode11ModMetadataC1ERKS0_",
+            "_ZN5geode11ModMetadataC1ERKS0_",
+        ],
+    ),
+    (
+        "geode::ModMetadata::~ModMetadata()",
+        1,
+        [
+            "??1ModMetadata@geode@@QEAA@XZ",
+            "_ZN5geode11ModMetadataD1Ev",
+            "_ZN5geode11ModMetadataD1Ev",
+            "_ZN5geode11ModMetadataD1Ev",
+            "_ZN5geode11ModMetadataD1Ev",
+            "_ZN5geode11ModMetadataD1Ev",
+        ],
+    ),
+    (
+        "geode::ModMetadataLinks::ModMetadataLinks(geode::ModMetadataLinks const&)",
+        2,
+        [
+            "??0ModMetadataLinks@geode@@QEAA@AEBV01@@Z",
+            "_ZN5geode16ModMetadataLinksC1ERKS0_",
+            "_ZN5geode16ModMetadataLinksC1ERKS0_",
+            "_ZN5geode16ModMetadataLinksC1ERKS0_",
+            "_ZN5geode16ModMetadataLinksC1ERKS0_",
+            "_ZN5geode16ModMetadataLinksC1ERKS0_",
+        ],
+    ),
+    (
+        "geode::ModMetadataLinks::~ModMetadataLinks()",
+        1,
+        [
+            "??1ModMetadataLinks@geode@@QEAA@XZ",
+            "_ZN5geode16ModMetadataLinksD1Ev",
+            "_ZN5geode16ModMetadataLinksD1Ev",
+            "_ZN5geode16ModMetadataLinksD1Ev",
+            "_ZN5geode16ModMetadataLinksD1Ev",
+            "_ZN5geode16ModMetadataLinksD1Ev",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Dependency::Dependency(geode::ModMetadata::Dependency const&)",
+        2,
+        [
+            "??0Dependency@ModMetadata@geode@@QEAA@AEBV012@@Z",
+            "_ZN5geode11ModMetadata10DependencyC1ERKS1_",
+            "_ZN5geode11ModMetadata10DependencyC1ERKS1_",
+            "_ZN5geode11ModMetadata10DependencyC1ERKS1_",
+            "_ZN5geode11ModMetadata10DependencyC1ERKS1_",
+            "_ZN5geode11ModMetadata10DependencyC1ERKS1_",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Dependency::~Dependency()",
+        1,
+        [
+            "??1Dependency@ModMetadata@geode@@QEAA@XZ",
+            "_ZN5geode11ModMetadata10DependencyD1Ev",
+            "_ZN5geode11ModMetadata10DependencyD1Ev",
+            "_ZN5geode11ModMetadata10DependencyD1Ev",
+            "_ZN5geode11ModMetadata10DependencyD1Ev",
+            "_ZN5geode11ModMetadata10DependencyD1Ev",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Incompatibility::Incompatibility(geode::ModMetadata::Incompatibility const&)",
+        2,
+        [
+            "??0Incompatibility@ModMetadata@geode@@QEAA@AEBV012@@Z",
+            "_ZN5geode11ModMetadata15IncompatibilityC1ERKS1_",
+            "_ZN5geode11ModMetadata15IncompatibilityC1ERKS1_",
+            "_ZN5geode11ModMetadata15IncompatibilityC1ERKS1_",
+            "_ZN5geode11ModMetadata15IncompatibilityC1ERKS1_",
+            "_ZN5geode11ModMetadata15IncompatibilityC1ERKS1_",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Incompatibility::~Incompatibility()",
+        1,
+        [
+            "??1Incompatibility@ModMetadata@geode@@QEAA@XZ",
+            "_ZN5geode11ModMetadata15IncompatibilityD1Ev",
+            "_ZN5geode11ModMetadata15IncompatibilityD1Ev",
+            "_ZN5geode11ModMetadata15IncompatibilityD1Ev",
+            "_ZN5geode11ModMetadata15IncompatibilityD1Ev",
+            "_ZN5geode11ModMetadata15IncompatibilityD1Ev",
+        ],
+    ),
+    (
+        "geode::ModMetadata::IssuesInfo::IssuesInfo(geode::ModMetadata::IssuesInfo const&)",
+        2,
+        [
+            "??0IssuesInfo@ModMetadata@geode@@QEAA@AEBV012@@Z",
+            "_ZN5geode11ModMetadata10IssuesInfoC1ERKS1_",
+            "_ZN5geode11ModMetadata10IssuesInfoC1ERKS1_",
+            "_ZN5geode11ModMetadata10IssuesInfoC1ERKS1_",
+            "_ZN5geode11ModMetadata10IssuesInfoC1ERKS1_",
+            "_ZN5geode11ModMetadata10IssuesInfoC1ERKS1_",
+        ],
+    ),
+    (
+        "geode::ModMetadata::IssuesInfo::~IssuesInfo()",
+        1,
+        [
+            "??1IssuesInfo@ModMetadata@geode@@QEAA@XZ",
+            "_ZN5geode11ModMetadata10IssuesInfoD1Ev",
+            "_ZN5geode11ModMetadata10IssuesInfoD1Ev",
+            "_ZN5geode11ModMetadata10IssuesInfoD1Ev",
+            "_ZN5geode11ModMetadata10IssuesInfoD1Ev",
+            "_ZN5geode11ModMetadata10IssuesInfoD1Ev",
+        ],
+    ),
+    (
+        "static geode::ModMetadata::create(matjson::Value const&) -> geode::ModMetadata",
+        1,
+        [
+            "?create@ModMetadata@geode@@SA?AV12@AEBVValue@matjson@@@Z",
+            "_ZN5geode11ModMetadata6createERKN7matjson5ValueE",
+            "_ZN5geode11ModMetadata6createERKN7matjson5ValueE",
+            "_ZN5geode11ModMetadata6createERKN7matjson5ValueE",
+            "_ZN5geode11ModMetadata6createERKN7matjson5ValueE",
+            "_ZN5geode11ModMetadata6createERKN7matjson5ValueE",
+        ],
+    ),
+    (
+        "static geode::ModMetadata::createFromGeodeFile(std::filesystem::path const&) -> geode::ModMetadata",
+        1,
+        [
+            "?createFromGeodeFile@ModMetadata@geode@@SA?AV12@AEBVpath@filesystem@std@@@Z",
+            "_ZN5geode11ModMetadata19createFromGeodeFileERKNSt3__14__fs10filesystem4pathE",
+            "_ZN5geode11ModMetadata19createFromGeodeFileERKNSt3__14__fs10filesystem4pathE",
+            "_ZN5geode11ModMetadata19createFromGeodeFileERKNSt3__14__fs10filesystem4pathE",
+            "_ZN5geode11ModMetadata19createFromGeodeFileERKNSt6__ndk14__fs10filesystem4pathE",
+            "_ZN5geode11ModMetadata19createFromGeodeFileERKNSt6__ndk14__fs10filesystem4pathE",
+        ],
+    ),
+    (
+        "geode::ModMetadata::getBinaryName() const -> geode::ZStringView",
+        1,
+        [
+            "?getBinaryName@ModMetadata@geode@@QEBA?AV?$BasicZStringView@D@2@XZ",
+            "_ZNK5geode11ModMetadata13getBinaryNameEv",
+            "_ZNK5geode11ModMetadata13getBinaryNameEv",
+            "_ZNK5geode11ModMetadata13getBinaryNameEv",
+            "_ZNK5geode11ModMetadata13getBinaryNameEv",
+            "_ZNK5geode11ModMetadata13getBinaryNameEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::getID() const -> geode::ZStringView",
+        1,
+        [
+            "?getID@ModMetadata@geode@@QEBA?AV?$BasicZStringView@D@2@XZ",
+            "_ZNK5geode11ModMetadata5getIDEv",
+            "_ZNK5geode11ModMetadata5getIDEv",
+            "_ZNK5geode11ModMetadata5getIDEv",
+            "_ZNK5geode11ModMetadata5getIDEv",
+            "_ZNK5geode11ModMetadata5getIDEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::getName() const -> geode::ZStringView",
+        1,
+        [
+            "?getName@ModMetadata@geode@@QEBA?AV?$BasicZStringView@D@2@XZ",
+            "_ZNK5geode11ModMetadata7getNameEv",
+            "_ZNK5geode11ModMetadata7getNameEv",
+            "_ZNK5geode11ModMetadata7getNameEv",
+            "_ZNK5geode11ModMetadata7getNameEv",
+            "_ZNK5geode11ModMetadata7getNameEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::getVersion() const -> geode::VersionInfo",
+        1,
+        [
+            "?getVersion@ModMetadata@geode@@QEBA?AVVersionInfo@2@XZ",
+            "_ZNK5geode11ModMetadata10getVersionEv",
+            "_ZNK5geode11ModMetadata10getVersionEv",
+            "_ZNK5geode11ModMetadata10getVersionEv",
+            "_ZNK5geode11ModMetadata10getVersionEv",
+            "_ZNK5geode11ModMetadata10getVersionEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::getPath() const -> std::filesystem::path const&",
+        1,
+        [
+            "?getPath@ModMetadata@geode@@QEBAAEBVpath@filesystem@std@@XZ",
+            "_ZNK5geode11ModMetadata7getPathEv",
+            "_ZNK5geode11ModMetadata7getPathEv",
+            "_ZNK5geode11ModMetadata7getPathEv",
+            "_ZNK5geode11ModMetadata7getPathEv",
+            "_ZNK5geode11ModMetadata7getPathEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::getDevelopers() const -> std::vector<std::string> const&",
+        1,
+        [
+            "?getDevelopers@ModMetadata@geode@@QEBAAEBV?$vector@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@V?$allocator@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@@std@@XZ",
+            "_ZNK5geode11ModMetadata13getDevelopersEv",
+            "_ZNK5geode11ModMetadata13getDevelopersEv",
+            "_ZNK5geode11ModMetadata13getDevelopersEv",
+            "_ZNK5geode11ModMetadata13getDevelopersEv",
+            "_ZNK5geode11ModMetadata13getDevelopersEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::getDescription() const -> std::optional<std::string> const&",
+        1,
+        [
+            "?getDescription@ModMetadata@geode@@QEBAAEBV?$optional@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@std@@XZ",
+            "_ZNK5geode11ModMetadata14getDescriptionEv",
+            "_ZNK5geode11ModMetadata14getDescriptionEv",
+            "_ZNK5geode11ModMetadata14getDescriptionEv",
+            "_ZNK5geode11ModMetadata14getDescriptionEv",
+            "_ZNK5geode11ModMetadata14getDescriptionEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::getDetails() const -> std::optional<std::string> const&",
+        1,
+        [
+            "?getDetails@ModMetadata@geode@@QEBAAEBV?$optional@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@std@@XZ",
+            "_ZNK5geode11ModMetadata10getDetailsEv",
+            "_ZNK5geode11ModMetadata10getDetailsEv",
+            "_ZNK5geode11ModMetadata10getDetailsEv",
+            "_ZNK5geode11ModMetadata10getDetailsEv",
+            "_ZNK5geode11ModMetadata10getDetailsEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::getLinks() const -> geode::ModMetadataLinks const&",
+        1,
+        [
+            "?getLinks@ModMetadata@geode@@QEBAAEBVModMetadataLinks@2@XZ",
+            "_ZNK5geode11ModMetadata8getLinksEv",
+            "_ZNK5geode11ModMetadata8getLinksEv",
+            "_ZNK5geode11ModMetadata8getLinksEv",
+            "_ZNK5geode11ModMetadata8getLinksEv",
+            "_ZNK5geode11ModMetadata8getLinksEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::getIssues() const -> std::optional<geode::ModMetadata::IssuesInfo> const&",
+        1,
+        [
+            "?getIssues@ModMetadata@geode@@QEBAAEBV?$optional@VIssuesInfo@ModMetadata@geode@@@std@@XZ",
+            "_ZNK5geode11ModMetadata9getIssuesEv",
+            "_ZNK5geode11ModMetadata9getIssuesEv",
+            "_ZNK5geode11ModMetadata9getIssuesEv",
+            "_ZNK5geode11ModMetadata9getIssuesEv",
+            "_ZNK5geode11ModMetadata9getIssuesEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::getDependencies() const -> std::vector<geode::ModMetadata::Dependency> const&",
+        1,
+        [
+            "?getDependencies@ModMetadata@geode@@QEBAAEBV?$vector@VDependency@ModMetadata@geode@@V?$allocator@VDependency@ModMetadata@geode@@@std@@@std@@XZ",
+            "_ZNK5geode11ModMetadata15getDependenciesEv",
+            "_ZNK5geode11ModMetadata15getDependenciesEv",
+            "_ZNK5geode11ModMetadata15getDependenciesEv",
+            "_ZNK5geode11ModMetadata15getDependenciesEv",
+            "_ZNK5geode11ModMetadata15getDependenciesEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::getIncompatibilities() const -> std::vector<geode::ModMetadata::Incompatibility> const&",
+        1,
+        [
+            "?getIncompatibilities@ModMetadata@geode@@QEBAAEBV?$vector@VIncompatibility@ModMetadata@geode@@V?$allocator@VIncompatibility@ModMetadata@geode@@@std@@@std@@XZ",
+            "_ZNK5geode11ModMetadata20getIncompatibilitiesEv",
+            "_ZNK5geode11ModMetadata20getIncompatibilitiesEv",
+            "_ZNK5geode11ModMetadata20getIncompatibilitiesEv",
+            "_ZNK5geode11ModMetadata20getIncompatibilitiesEv",
+            "_ZNK5geode11ModMetadata20getIncompatibilitiesEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::getLoadPriority() const -> int",
+        1,
+        [
+            "?getLoadPriority@ModMetadata@geode@@QEBAHXZ",
+            "_ZNK5geode11ModMetadata15getLoadPriorityEv",
+            "_ZNK5geode11ModMetadata15getLoadPriorityEv",
+            "_ZNK5geode11ModMetadata15getLoadPriorityEv",
+            "_ZNK5geode11ModMetadata15getLoadPriorityEv",
+            "_ZNK5geode11ModMetadata15getLoadPriorityEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::getRawJSON() const -> matjson::Value",
+        1,
+        [
+            "?getRawJSON@ModMetadata@geode@@QEBA?AVValue@matjson@@XZ",
+            "_ZNK5geode11ModMetadata10getRawJSONEv",
+            "_ZNK5geode11ModMetadata10getRawJSONEv",
+            "_ZNK5geode11ModMetadata10getRawJSONEv",
+            "_ZNK5geode11ModMetadata10getRawJSONEv",
+            "_ZNK5geode11ModMetadata10getRawJSONEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::toJSON() const -> matjson::Value",
+        1,
+        [
+            "?toJSON@ModMetadata@geode@@QEBA?AVValue@matjson@@XZ",
+            "_ZNK5geode11ModMetadata6toJSONEv",
+            "_ZNK5geode11ModMetadata6toJSONEv",
+            "_ZNK5geode11ModMetadata6toJSONEv",
+            "_ZNK5geode11ModMetadata6toJSONEv",
+            "_ZNK5geode11ModMetadata6toJSONEv",
+        ],
+    ),
+    (
+        "static geode::ModMetadata::validateID(std::string_view) -> bool",
+        1,
+        [
+            "?validateID@ModMetadata@geode@@SA_NV?$basic_string_view@DU?$char_traits@D@std@@@std@@@Z",
+            "_ZN5geode11ModMetadata10validateIDENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZN5geode11ModMetadata10validateIDENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZN5geode11ModMetadata10validateIDENSt3__117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZN5geode11ModMetadata10validateIDENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+            "_ZN5geode11ModMetadata10validateIDENSt6__ndk117basic_string_viewIcNS1_11char_traitsIcEEEE",
+        ],
+    ),
+    (
+        "static geode::ModMetadata::formatDeveloperDisplayString(std::vector<std::string> const&) -> std::string",
+        1,
+        [
+            "?formatDeveloperDisplayString@ModMetadata@geode@@SA?AV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@AEBV?$vector@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@V?$allocator@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@2@@4@@Z",
+            "_ZN5geode11ModMetadata28formatDeveloperDisplayStringERKNSt3__16vectorINS1_12basic_stringIcNS1_11char_traitsIcEENS1_9allocatorIcEEEENS6_IS8_EEEE",
+            "_ZN5geode11ModMetadata28formatDeveloperDisplayStringERKNSt3__16vectorINS1_12basic_stringIcNS1_11char_traitsIcEENS1_9allocatorIcEEEENS6_IS8_EEEE",
+            "_ZN5geode11ModMetadata28formatDeveloperDisplayStringERKNSt3__16vectorINS1_12basic_stringIcNS1_11char_traitsIcEENS1_9allocatorIcEEEENS6_IS8_EEEE",
+            "_ZN5geode11ModMetadata28formatDeveloperDisplayStringERKNSt6__ndk16vectorINS1_12basic_stringIcNS1_11char_traitsIcEENS1_9allocatorIcEEEENS6_IS8_EEEE",
+            "_ZN5geode11ModMetadata28formatDeveloperDisplayStringERKNSt6__ndk16vectorINS1_12basic_stringIcNS1_11char_traitsIcEENS1_9allocatorIcEEEENS6_IS8_EEEE",
+        ],
+    ),
+    (
+        "geode::ModMetadataLinks::getHomepageURL() const -> std::optional<std::string>",
+        1,
+        [
+            "?getHomepageURL@ModMetadataLinks@geode@@QEBA?AV?$optional@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@std@@XZ",
+            "_ZNK5geode16ModMetadataLinks14getHomepageURLEv",
+            "_ZNK5geode16ModMetadataLinks14getHomepageURLEv",
+            "_ZNK5geode16ModMetadataLinks14getHomepageURLEv",
+            "_ZNK5geode16ModMetadataLinks14getHomepageURLEv",
+            "_ZNK5geode16ModMetadataLinks14getHomepageURLEv",
+        ],
+    ),
+    (
+        "geode::ModMetadataLinks::getSourceURL() const -> std::optional<std::string>",
+        1,
+        [
+            "?getSourceURL@ModMetadataLinks@geode@@QEBA?AV?$optional@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@std@@XZ",
+            "_ZNK5geode16ModMetadataLinks12getSourceURLEv",
+            "_ZNK5geode16ModMetadataLinks12getSourceURLEv",
+            "_ZNK5geode16ModMetadataLinks12getSourceURLEv",
+            "_ZNK5geode16ModMetadataLinks12getSourceURLEv",
+            "_ZNK5geode16ModMetadataLinks12getSourceURLEv",
+        ],
+    ),
+    (
+        "geode::ModMetadataLinks::getCommunityURL() const -> std::optional<std::string>",
+        1,
+        [
+            "?getCommunityURL@ModMetadataLinks@geode@@QEBA?AV?$optional@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@std@@XZ",
+            "_ZNK5geode16ModMetadataLinks15getCommunityURLEv",
+            "_ZNK5geode16ModMetadataLinks15getCommunityURLEv",
+            "_ZNK5geode16ModMetadataLinks15getCommunityURLEv",
+            "_ZNK5geode16ModMetadataLinks15getCommunityURLEv",
+            "_ZNK5geode16ModMetadataLinks15getCommunityURLEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::IssuesInfo::getInfo() const -> std::string const&",
+        1,
+        [
+            "?getInfo@IssuesInfo@ModMetadata@geode@@QEBAAEBV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@XZ",
+            "_ZNK5geode11ModMetadata10IssuesInfo7getInfoEv",
+            "_ZNK5geode11ModMetadata10IssuesInfo7getInfoEv",
+            "_ZNK5geode11ModMetadata10IssuesInfo7getInfoEv",
+            "_ZNK5geode11ModMetadata10IssuesInfo7getInfoEv",
+            "_ZNK5geode11ModMetadata10IssuesInfo7getInfoEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::IssuesInfo::getURL() const -> std::optional<std::string> const&",
+        1,
+        [
+            "?getURL@IssuesInfo@ModMetadata@geode@@QEBAAEBV?$optional@V?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@@std@@XZ",
+            "_ZNK5geode11ModMetadata10IssuesInfo6getURLEv",
+            "_ZNK5geode11ModMetadata10IssuesInfo6getURLEv",
+            "_ZNK5geode11ModMetadata10IssuesInfo6getURLEv",
+            "_ZNK5geode11ModMetadata10IssuesInfo6getURLEv",
+            "_ZNK5geode11ModMetadata10IssuesInfo6getURLEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Dependency::getID() const -> std::string const&",
+        1,
+        [
+            "?getID@Dependency@ModMetadata@geode@@QEBAAEBV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@XZ",
+            "_ZNK5geode11ModMetadata10Dependency5getIDEv",
+            "_ZNK5geode11ModMetadata10Dependency5getIDEv",
+            "_ZNK5geode11ModMetadata10Dependency5getIDEv",
+            "_ZNK5geode11ModMetadata10Dependency5getIDEv",
+            "_ZNK5geode11ModMetadata10Dependency5getIDEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Dependency::getVersion() const -> geode::ComparableVersionInfo const&",
+        1,
+        [
+            "?getVersion@Dependency@ModMetadata@geode@@QEBAAEBVComparableVersionInfo@3@XZ",
+            "_ZNK5geode11ModMetadata10Dependency10getVersionEv",
+            "_ZNK5geode11ModMetadata10Dependency10getVersionEv",
+            "_ZNK5geode11ModMetadata10Dependency10getVersionEv",
+            "_ZNK5geode11ModMetadata10Dependency10getVersionEv",
+            "_ZNK5geode11ModMetadata10Dependency10getVersionEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Dependency::isRequired() const -> bool",
+        1,
+        [
+            "?isRequired@Dependency@ModMetadata@geode@@QEBA_NXZ",
+            "_ZNK5geode11ModMetadata10Dependency10isRequiredEv",
+            "_ZNK5geode11ModMetadata10Dependency10isRequiredEv",
+            "_ZNK5geode11ModMetadata10Dependency10isRequiredEv",
+            "_ZNK5geode11ModMetadata10Dependency10isRequiredEv",
+            "_ZNK5geode11ModMetadata10Dependency10isRequiredEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Dependency::getMod() const -> geode::Mod*",
+        1,
+        [
+            "?getMod@Dependency@ModMetadata@geode@@QEBAPEAVMod@3@XZ",
+            "_ZNK5geode11ModMetadata10Dependency6getModEv",
+            "_ZNK5geode11ModMetadata10Dependency6getModEv",
+            "_ZNK5geode11ModMetadata10Dependency6getModEv",
+            "_ZNK5geode11ModMetadata10Dependency6getModEv",
+            "_ZNK5geode11ModMetadata10Dependency6getModEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Dependency::getSettings() const -> matjson::Value const&",
+        1,
+        [
+            "?getSettings@Dependency@ModMetadata@geode@@QEBAAEBVValue@matjson@@XZ",
+            "_ZNK5geode11ModMetadata10Dependency11getSettingsEv",
+            "_ZNK5geode11ModMetadata10Dependency11getSettingsEv",
+            "_ZNK5geode11ModMetadata10Dependency11getSettingsEv",
+            "_ZNK5geode11ModMetadata10Dependency11getSettingsEv",
+            "_ZNK5geode11ModMetadata10Dependency11getSettingsEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Dependency::isResolved() const -> bool",
+        1,
+        [
+            "?isResolved@Dependency@ModMetadata@geode@@QEBA_NXZ",
+            "_ZNK5geode11ModMetadata10Dependency10isResolvedEv",
+            "_ZNK5geode11ModMetadata10Dependency10isResolvedEv",
+            "_ZNK5geode11ModMetadata10Dependency10isResolvedEv",
+            "_ZNK5geode11ModMetadata10Dependency10isResolvedEv",
+            "_ZNK5geode11ModMetadata10Dependency10isResolvedEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Incompatibility::getID() const -> std::string const&",
+        1,
+        [
+            "?getID@Incompatibility@ModMetadata@geode@@QEBAAEBV?$basic_string@DU?$char_traits@D@std@@V?$allocator@D@2@@std@@XZ",
+            "_ZNK5geode11ModMetadata15Incompatibility5getIDEv",
+            "_ZNK5geode11ModMetadata15Incompatibility5getIDEv",
+            "_ZNK5geode11ModMetadata15Incompatibility5getIDEv",
+            "_ZNK5geode11ModMetadata15Incompatibility5getIDEv",
+            "_ZNK5geode11ModMetadata15Incompatibility5getIDEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Incompatibility::getVersion() const -> geode::ComparableVersionInfo const&",
+        1,
+        [
+            "?getVersion@Incompatibility@ModMetadata@geode@@QEBAAEBVComparableVersionInfo@3@XZ",
+            "_ZNK5geode11ModMetadata15Incompatibility10getVersionEv",
+            "_ZNK5geode11ModMetadata15Incompatibility10getVersionEv",
+            "_ZNK5geode11ModMetadata15Incompatibility10getVersionEv",
+            "_ZNK5geode11ModMetadata15Incompatibility10getVersionEv",
+            "_ZNK5geode11ModMetadata15Incompatibility10getVersionEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Incompatibility::isBreaking() const -> bool",
+        1,
+        [
+            "?isBreaking@Incompatibility@ModMetadata@geode@@QEBA_NXZ",
+            "_ZNK5geode11ModMetadata15Incompatibility10isBreakingEv",
+            "_ZNK5geode11ModMetadata15Incompatibility10isBreakingEv",
+            "_ZNK5geode11ModMetadata15Incompatibility10isBreakingEv",
+            "_ZNK5geode11ModMetadata15Incompatibility10isBreakingEv",
+            "_ZNK5geode11ModMetadata15Incompatibility10isBreakingEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Incompatibility::getMod() const -> geode::Mod*",
+        1,
+        [
+            "?getMod@Incompatibility@ModMetadata@geode@@QEBAPEAVMod@3@XZ",
+            "_ZNK5geode11ModMetadata15Incompatibility6getModEv",
+            "_ZNK5geode11ModMetadata15Incompatibility6getModEv",
+            "_ZNK5geode11ModMetadata15Incompatibility6getModEv",
+            "_ZNK5geode11ModMetadata15Incompatibility6getModEv",
+            "_ZNK5geode11ModMetadata15Incompatibility6getModEv",
+        ],
+    ),
+    (
+        "geode::ModMetadata::Incompatibility::isResolved() const -> bool",
+        1,
+        [
+            "?isResolved@Incompatibility@ModMetadata@geode@@QEBA_NXZ",
+            "_ZNK5geode11ModMetadata15Incompatibility10isResolvedEv",
+            "_ZNK5geode11ModMetadata15Incompatibility10isResolvedEv",
+            "_ZNK5geode11ModMetadata15Incompatibility10isResolvedEv",
+            "_ZNK5geode11ModMetadata15Incompatibility10isResolvedEv",
+            "_ZNK5geode11ModMetadata15Incompatibility10isResolvedEv",
+        ],
+    ),
+];
+
+fn bind(source: &str, binding_args: usize) -> Declaration {
+    let mut decl = parse_declaration(source).unwrap();
+    decl.match_binding_args(binding_args).unwrap();
+    decl
+}
+
+fn named(kind: RecordKind, path: &str) -> CxxType {
+    let path = path
+        .split("::")
+        .map(|name| geode_codegen::declaration::NameComponent {
+            name: name.to_string(),
+            template_args: None,
+        })
+        .collect();
+    CxxType::Named { kind, path }
+}
+
+#[test]
+fn test_loader_symbols() {
+    for (source, binding_args, symbols) in LOADER_BINDINGS {
+        let decl = bind(source, *binding_args);
+        for (platform, expected) in Platform::all().iter().zip(symbols) {
+            assert_eq!(decl.symbol(*platform), *expected, "{source} on {platform}");
+        }
+    }
+}
+
+#[test]
+fn test_libcxx_namespaces() {
+    // Apple's libc++ puts the standard library in `std::__1`, the NDK in `std::__ndk1`
+    let decl = bind("geode::Mod::hasSetting(std::string_view) const -> bool", 2);
+    for &platform in Platform::all() {
+        let symbol = decl.symbol(platform);
+        match platform {
+            Platform::Windows => assert!(!symbol.contains("__"), "{symbol}"),
+            Platform::Android32 | Platform::Android64 => {
+                assert!(symbol.contains("NSt6__ndk117basic_string_view"), "{symbol}")
+            }
+            _ => assert!(symbol.contains("NSt3__117basic_string_view"), "{symbol}"),
+        }
+    }
+}
+
+#[test]
+fn test_platform_sized_types() {
+    let decl = bind("geode::Hook::setAddress(size_t, int64_t)", 3);
+    assert_eq!(
+        decl.symbol(Platform::Windows),
+        "?setAddress@Hook@geode@@QEAAX_K_J@Z"
+    );
+    assert_eq!(
+        decl.symbol(Platform::Android32),
+        "_ZN5geode4Hook10setAddressEjx"
+    );
+    assert_eq!(
+        decl.symbol(Platform::Android64),
+        "_ZN5geode4Hook10setAddressEml"
+    );
+    assert_eq!(
+        decl.symbol(Platform::MacArm),
+        "_ZN5geode4Hook10setAddressEmx"
+    );
+}
+
+#[test]
+fn test_parse_declaration() {
+    let decl = parse_declaration(
+        "protected geode::Loader::getLaunchArgument(std::string_view name) const -> std::optional<std::string>",
+    )
+    .unwrap();
+    assert_eq!(decl.kind, FunctionKind::Method);
+    assert_eq!(decl.access, AccessModifier::Protected);
+    assert!(decl.is_const);
+    assert_eq!(decl.name, "getLaunchArgument");
+    assert_eq!(
+        decl.scope
+            .iter()
+            .map(|component| component.name.as_str())
+            .collect::<Vec<_>>(),
+        ["geode", "Loader"]
+    );
+
+    // aliases are expanded to the templates they name, parameter names are dropped
+    let [CxxType::Named { kind, path }] = decl.args.as_slice() else {
+        panic!("{:?}", decl.args);
+    };
+    assert_eq!(*kind, RecordKind::Class);
+    assert_eq!(path[1].name, "basic_string_view");
+    let args = path[1].template_args.as_ref().unwrap();
+    assert_eq!(args[0], TemplateArg::Type(CxxType::Builtin(Builtin::Char)));
+    let TemplateArg::Type(CxxType::Named { kind, path }) = &args[1] else {
+        panic!("{args:?}");
+    };
+    assert_eq!(*kind, RecordKind::Struct);
+    assert_eq!(path[1].name, "char_traits");
+
+    let CxxType::Named { path, .. } = &decl.ret else {
+        panic!("{:?}", decl.ret);
+    };
+    let Some([TemplateArg::Type(CxxType::Named { path: inner, .. })]) =
+        path[1].template_args.as_deref()
+    else {
+        panic!("{path:?}");
+    };
+    assert_eq!(inner[1].name, "basic_string");
+    assert_eq!(inner[1].template_args.as_ref().unwrap().len(), 3);
+}
+
+#[test]
+fn test_parse_types() {
+    let decl = parse_declaration(
+        "geode::f(unsigned long long, std::size_t, char const* const, int const&, struct geode::LoadProblem*, enum geode::Severity)",
+    )
+    .unwrap();
+    assert_eq!(
+        decl.args,
+        [
+            CxxType::Builtin(Builtin::UnsignedLongLong),
+            CxxType::Builtin(Builtin::Size),
+            CxxType::Const(Box::new(CxxType::Pointer(Box::new(CxxType::Const(
+                Box::new(CxxType::Builtin(Builtin::Char))
+            ))))),
+            CxxType::Reference(Box::new(CxxType::Const(Box::new(CxxType::Builtin(
+                Builtin::Int
+            ))))),
+            CxxType::Pointer(Box::new(named(RecordKind::Struct, "geode::LoadProblem"))),
+            named(RecordKind::Enum, "geode::Severity"),
+        ]
+    );
+    assert_eq!(decl.ret, CxxType::Builtin(Builtin::Void));
+
+    let decl = parse_declaration("geode::Mod::getBytes(void) -> std::span<uint8_t const>").unwrap();
+    assert!(decl.args.is_empty());
+    let CxxType::Named { path, .. } = &decl.ret else {
+        panic!("{:?}", decl.ret);
+    };
+    assert_eq!(
+        path[1].template_args.as_ref().unwrap()[1],
+        TemplateArg::Integer(-1)
+    );
+}
+
+#[test]
+fn test_parse_special_members() {
+    let decl = parse_declaration("matjson::Value::Value(matjson::Value const&)").unwrap();
+    assert_eq!(decl.kind, FunctionKind::Constructor);
+    assert_eq!(decl.name, "Value");
+
+    let decl = parse_declaration("geode::ModMetadata::Dependency::~Dependency()").unwrap();
+    assert_eq!(decl.kind, FunctionKind::Destructor);
+    assert_eq!(decl.name, "Dependency");
+    assert_eq!(decl.scope.len(), 3);
+
+    let decl = parse_declaration("static geode::Loader::get() -> geode::Loader*").unwrap();
+    assert_eq!(decl.kind, FunctionKind::Static);
+    assert!(!decl.has_this());
+
+    let decl = parse_declaration("virtual cocos2d::CCNode::onEnter()").unwrap();
+    assert_eq!(decl.kind, FunctionKind::Virtual);
+
+    let decl = parse_declaration("getGameVersion() -> std::string").unwrap();
+    assert_eq!(decl.kind, FunctionKind::Free);
+}
+
+#[test]
+fn test_parse_errors() {
+    for (source, error) in [
+        ("geode::Loader::get<int>()", "function templates"),
+        ("geode::Loader::~Mod()", "does not match its class"),
+        ("static geode::Loader::get() const", "only member functions"),
+        ("f() const", "only member functions"),
+        ("matjson::Value::Value() -> int", "no return type"),
+        (
+            "geode::Loader::get() -> int)",
+            "unexpected `)` after the declaration",
+        ),
+        ("geode::Loader::get(int", "expected `,`"),
+        ("geode::Loader::get(int[4])", "unexpected character `[`"),
+        (
+            "geode::Loader::get(long float)",
+            "unsupported type `long float`",
+        ),
+    ] {
+        let err = parse_declaration(source).unwrap_err().to_string();
+        assert!(err.contains(error), "{source}: {err}");
+    }
+}
+
+#[test]
+fn test_binding_arity() {
+    // a binding with exactly the declared arguments makes a member-looking declaration free
+    let decl = bind("geode::dirs::getGameDir() -> std::filesystem::path", 0);
+    assert_eq!(decl.kind, FunctionKind::Free);
+    let decl = bind("geode::log::setLevel(int)", 1);
+    assert_eq!(decl.kind, FunctionKind::Free);
+    assert_eq!(decl.symbol(Platform::Android64), "_ZN5geode3log8setLevelEi");
+
+    // one more is `this`
+    let decl = bind("geode::Hook::setPriority(int)", 2);
+    assert_eq!(decl.kind, FunctionKind::Method);
+    assert_eq!(
+        decl.symbol(Platform::Android64),
+        "_ZN5geode4Hook11setPriorityEi"
+    );
+    assert_eq!(
+        decl.symbol(Platform::Windows),
+        "?setPriority@Hook@geode@@QEAAXH@Z"
+    );
+
+    // only plain methods are guessed, everything else has to match exactly
+    let decl = bind("virtual geode::Hook::update(int)", 2);
+    assert_eq!(decl.kind, FunctionKind::Virtual);
+    let decl = bind("matjson::Value::~Value()", 1);
+    assert_eq!(decl.kind, FunctionKind::Destructor);
+    let decl = bind("static geode::Loader::get()", 0);
+    assert_eq!(decl.kind, FunctionKind::Static);
+
+    for (source, binding_args, error) in [
+        (
+            "geode::Hook::setPriority(int)",
+            3,
+            "takes 1 argument(s) plus `this`, the binding takes 3",
+        ),
+        (
+            "static geode::Loader::get()",
+            1,
+            "takes 0 argument(s), the binding takes 1",
+        ),
+        (
+            "virtual geode::Hook::update(int)",
+            1,
+            "takes 1 argument(s) plus `this`, the binding takes 1",
+        ),
+        (
+            "geode::Hook::getPriority() const -> int",
+            0,
+            "takes 0 argument(s) plus `this`, the binding takes 0",
+        ),
+        (
+            "matjson::Value::Value(matjson::Value const&)",
+            1,
+            "takes 1 argument(s) plus `this`, the binding takes 1",
+        ),
+    ] {
+        let mut decl = parse_declaration(source).unwrap();
+        let err = decl
+            .match_binding_args(binding_args)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(error), "{source}: {err}");
+    }
+}
@@ -0,0 +1,193 @@
+use broma_rs::{FunctionBindField, Root, parse_str};
+use geode_codegen::overload::{
+    FunctionNames, OverloadTable, assign_function_names, derive_overload_name,
+};
+
+const BROMA: &str = r#"
+class Base {
+    virtual void add(Base*) = win 0x10, imac 0x10, m1 0x10, ios 0x10;
+    virtual void add(Base*, int) = win 0x20, imac 0x20, m1 0x20, ios 0x20;
+    void scale(float) = win 0x40, imac 0x40, m1 0x40, ios 0x40;
+    void scale(float, float) = win 0x50, imac 0x50, m1 0x50, ios 0x50;
+}
+
+class Derived : Base {
+    virtual void add(Base*) = win 0x110, imac 0x110, m1 0x110, ios 0x110;
+    virtual void add(Base*, int) = win 0x120, imac 0x120, m1 0x120, ios 0x120;
+}
+
+class Single : Derived {
+    virtual void add(Base*, int) = win 0x220, imac 0x220, m1 0x220, ios 0x220;
+}
+"#;
+
+const TABLE: &str = r#"
+# comments and blank lines are skipped
+
+Base::add(Base *) = add
+Base::add(Base *, int) = add_with_z_order
+Base::scale(float) = scale
+Base::scale(float, float) = scale_xy
+Derived::add(Base *) = add
+Derived::add(Base *, int) = add_with_z_order
+"#;
+
+fn function<'a>(root: &'a Root, class: &str, name: &str, args: usize) -> &'a FunctionBindField {
+    root.find_class(class)
+        .unwrap()
+        .fields
+        .iter()
+        .filter_map(|field| field.as_function_bind())
+        .find(|func| func.prototype.name == name && func.prototype.args.len() == args)
+        .unwrap()
+}
+
+fn name<'a>(
+    root: &Root,
+    names: &'a FunctionNames,
+    class: &str,
+    function: &str,
+    args: usize,
+) -> &'a str {
+    let field_id = root
+        .find_class(class)
+        .unwrap()
+        .fields
+        .iter()
+        .find(|field| {
+            field.as_function_bind().is_some_and(|func| {
+                func.prototype.name == function && func.prototype.args.len() == args
+            })
+        })
+        .unwrap()
+        .field_id;
+    &names[&(class.to_string(), field_id)]
+}
+
+fn names_error(broma: &str, table: &str) -> String {
+    let root = parse_str(broma).unwrap();
+    let table = OverloadTable::parse(table).unwrap();
+    assign_function_names(&root.classes, Some(&table))
+        .unwrap_err()
+        .to_string()
+}
+
+#[test]
+fn test_derive_overload_name() {
+    let root = parse_str(
+        r#"
+class Node {
+    void none();
+    void point(cocos2d::CCPoint const&, float, unsigned int);
+    void strings(char const*, gd::string, std::string const&);
+    void pointers(void*, cocos2d::CCNode*, Node*);
+    void colors(cocos2d::ccColor4B, cocos2d::ccColor3B const&);
+    void templates(gd::vector<int>, cocos2d::CCArray*);
+}
+"#,
+    )
+    .unwrap();
+
+    let derive =
+        |name: &str, args: usize| derive_overload_name(name, function(&root, "Node", name, args));
+    assert_eq!(derive("none", 0), "none");
+    assert_eq!(derive("point", 3), "point_with_point_float_uint");
+    assert_eq!(derive("strings", 3), "strings_with_string_string_string");
+    assert_eq!(derive("pointers", 3), "pointers_with_ptr_node_node");
+    assert_eq!(derive("colors", 2), "colors_with_color4b_color3b");
+    assert_eq!(derive("templates", 2), "templates_with_vector_array");
+}
+
+#[test]
+fn test_overrides_share_names() {
+    let root = parse_str(BROMA).unwrap();
+    let table = OverloadTable::parse(TABLE).unwrap();
+    let names = assign_function_names(&root.classes, Some(&table)).unwrap();
+
+    assert_eq!(name(&root, &names, "Base", "scale", 2), "scale_xy");
+    assert_eq!(name(&root, &names, "Derived", "add", 1), "add");
+    assert_eq!(name(&root, &names, "Derived", "add", 2), "add_with_z_order");
+    // not overloaded in this class, the override still takes the overridden function's name
+    assert_eq!(name(&root, &names, "Single", "add", 2), "add_with_z_order");
+
+    // without a table every overload gets a derived name, overrides included
+    let names = assign_function_names(&root.classes, None).unwrap();
+    assert_eq!(name(&root, &names, "Base", "add", 2), "add_with_base_int");
+    assert_eq!(
+        name(&root, &names, "Derived", "add", 2),
+        "add_with_base_int"
+    );
+}
+
+#[test]
+fn test_override_renamed_by_table() {
+    let table = TABLE.replace(
+        "Derived::add(Base *, int) = add_with_z_order",
+        "Derived::add(Base *, int) = add_with_base_int",
+    );
+    let error = names_error(BROMA, &table);
+    assert!(
+        error.contains(
+            "Derived::add(Base *, int) = add_with_base_int overrides Base::add, which is named add_with_z_order"
+        ),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_missing_entry() {
+    let table = TABLE.replace("Base::scale(float, float) = scale_xy\n", "");
+    let error = names_error(BROMA, &table);
+    assert!(
+        error.contains(
+            "overloaded functions missing from the overload table:\nBase::scale(float, float) = scale_with_float_float"
+        ),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_stale_entry() {
+    let table = format!("{TABLE}Base::scale(double) = scale_double\n");
+    let error = names_error(BROMA, &table);
+    assert!(
+        error.contains(
+            "overload table entries that match no overloaded function:\nBase::scale(double)"
+        ),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_collision() {
+    let table = TABLE.replace(
+        "Base::scale(float, float) = scale_xy",
+        "Base::scale(float, float) = scale",
+    );
+    let error = names_error(BROMA, &table);
+    assert!(
+        error.contains(
+            "Base::scale is generated for both `Base::scale(float)` and `Base::scale(float, float)`"
+        ),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = OverloadTable::parse("Base::scale(float)").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "overload table line 1: expected `signature = name`"
+    );
+    let error = OverloadTable::parse("Base::scale(float) = scale-x").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "overload table line 1: invalid name `scale-x`"
+    );
+    let error = OverloadTable::parse("A::f() = f\nA::f() = g").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "overload table line 2: duplicate entry for `A::f()`"
+    );
+}
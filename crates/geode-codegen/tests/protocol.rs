@@ -0,0 +1,37 @@
+use broma_rs::parse_str;
+use geode_codegen::protocol::{generate_protocol, is_protocol};
+
+const BROMA: &str = r#"
+class TestProtocol {
+    virtual int count();
+    virtual cocos2d::CCNode* node();
+    virtual cocos2d::CCPoint const& point();
+    virtual void changed(int);
+}
+"#;
+
+fn thunk<'a>(output: &'a str, name: &str) -> &'a str {
+    let start = output
+        .find(&format!("extern \"C\" fn {name}<"))
+        .unwrap_or_else(|| panic!("no thunk for {name}"));
+    let end = output[start..].find("\n        }\n").unwrap();
+    &output[start..start + end]
+}
+
+#[test]
+fn test_every_thunk_catches_panics() {
+    let root = parse_str(BROMA).unwrap();
+    let class = root.find_class("TestProtocol").unwrap();
+    assert!(is_protocol(class));
+    let output = generate_protocol(class);
+
+    assert!(thunk(&output, "count").contains("crate::modify::run_hook(\"TestProtocol::count\""));
+    assert!(thunk(&output, "changed").contains("crate::modify::run_hook("));
+
+    let node = thunk(&output, "node");
+    assert!(node.contains("crate::modify::run_hook("));
+    assert!(node.contains("Some(this.node())"));
+    assert!(node.contains(".unwrap_or(std::ptr::null_mut())"));
+
+    assert!(thunk(&output, "point").contains("crate::modify::run_hook_or_abort("));
+}
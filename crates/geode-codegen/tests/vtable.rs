@@ -0,0 +1,257 @@
+use std::path::Path;
+
+use broma_rs::{Root, parse_str};
+use geode_codegen::platform::Platform;
+use geode_codegen::vtable::{Slot, primary_vtable};
+
+const BROMA: &str = r#"
+class Base {
+    virtual ~Base();
+    virtual void first();
+    virtual void overloaded(int);
+    virtual void overloaded(float);
+}
+
+class Delegate {
+    virtual void delegated();
+}
+
+class Derived : Base, Delegate {
+    virtual ~Derived();
+    virtual void first();
+    virtual void delegated();
+    virtual void added();
+    [[missing(android)]]
+    virtual void notOnAndroid();
+}
+
+class NotInBroma : cocos2d::CCNode {
+    virtual void added();
+}
+
+class cocos2d::CCLayer : cocos2d::CCNode, cocos2d::CCTouchDelegate {
+    virtual void onEnter() = imac 0x10, m1 0x10, ios 0x10;
+    virtual bool ccTouchBegan(cocos2d::CCTouch*, cocos2d::CCEvent*) = imac 0x20, m1 0x20, ios 0x20;
+}
+
+class GameLayer : cocos2d::CCLayer {
+    virtual void onEnter() = win 0x30, imac 0x30, m1 0x30, ios 0x30;
+    virtual void ccTouchBegan(cocos2d::CCTouch *, cocos2d::CCEvent *) = win 0x40, imac 0x40, m1 0x40, ios 0x40;
+    virtual void added() = win 0x50, imac 0x50, m1 0x50, ios 0x50;
+}
+"#;
+
+// the cocos classes in header order, CCLayer declaring its overrides in another order than above
+const VTABLES: &str = r#"
+class cocos2d::CCObject {
+    virtual ~CCObject();
+    virtual void update(float);
+}
+
+class cocos2d::CCNode : cocos2d::CCObject {
+    virtual ~CCNode();
+    virtual void visit();
+    virtual void onEnter();
+}
+
+class cocos2d::CCTouchDelegate {
+    virtual bool ccTouchBegan(cocos2d::CCTouch*, cocos2d::CCEvent*);
+}
+
+class cocos2d::CCLayer : cocos2d::CCNode, cocos2d::CCTouchDelegate {
+    virtual bool ccTouchBegan(cocos2d::CCTouch*, cocos2d::CCEvent*);
+    virtual void onEnter();
+    virtual void registerWithTouchDispatcher();
+}
+"#;
+
+fn field_id(root: &broma_rs::Root, class: &str, name: &str) -> usize {
+    let class = root.find_class(class).unwrap();
+    class
+        .fields
+        .iter()
+        .find(|field| {
+            field
+                .as_function_bind()
+                .is_some_and(|f| f.prototype.name == name)
+        })
+        .unwrap()
+        .field_id
+}
+
+#[test]
+fn test_itanium_layout() {
+    let root = parse_str(BROMA).unwrap();
+    let derived = root.find_class("Derived").unwrap();
+    let layout = primary_vtable(derived, &root, &Root::default(), Platform::Android64).unwrap();
+
+    // two destructor slots, the overloads in declaration order, then an entry for the override
+    // of the secondary base, which C++ still calls through the secondary vtable
+    assert_eq!(layout.slots, 7);
+    assert_eq!(
+        layout.own[&field_id(&root, "Derived", "first")],
+        Slot::Primary(2)
+    );
+    assert_eq!(
+        layout.own[&field_id(&root, "Derived", "delegated")],
+        Slot::Secondary("Delegate".to_string())
+    );
+    assert_eq!(
+        layout.own[&field_id(&root, "Derived", "added")],
+        Slot::Primary(6)
+    );
+}
+
+#[test]
+fn test_msvc_layout() {
+    let root = parse_str(BROMA).unwrap();
+    let derived = root.find_class("Derived").unwrap();
+    let layout = primary_vtable(derived, &root, &Root::default(), Platform::Windows).unwrap();
+
+    // one destructor slot, no primary entry for `delegated`
+    assert_eq!(layout.slots, 6);
+    assert_eq!(
+        layout.own[&field_id(&root, "Derived", "first")],
+        Slot::Primary(1)
+    );
+    assert_eq!(
+        layout.own[&field_id(&root, "Derived", "delegated")],
+        Slot::Secondary("Delegate".to_string())
+    );
+    assert_eq!(
+        layout.own[&field_id(&root, "Derived", "added")],
+        Slot::Primary(4)
+    );
+    assert_eq!(
+        layout.own[&field_id(&root, "Derived", "notOnAndroid")],
+        Slot::Primary(5)
+    );
+
+    let base = root.find_class("Base").unwrap();
+    let layout = primary_vtable(base, &root, &Root::default(), Platform::Windows).unwrap();
+    let overloads: Vec<_> = base
+        .fields
+        .iter()
+        .filter(|field| {
+            field
+                .as_function_bind()
+                .is_some_and(|f| f.prototype.name == "overloaded")
+        })
+        .map(|field| layout.own[&field.field_id].clone())
+        .collect();
+    // overloads are grouped in reverse declaration order
+    assert_eq!(overloads, [Slot::Primary(3), Slot::Primary(2)]);
+}
+
+#[test]
+fn test_missing_virtual_has_no_slot() {
+    let root = parse_str(BROMA).unwrap();
+    let derived = root.find_class("Derived").unwrap();
+    let layout = primary_vtable(derived, &root, &Root::default(), Platform::Android32).unwrap();
+    assert_eq!(layout.slots, 7);
+    assert!(
+        !layout
+            .own
+            .contains_key(&field_id(&root, "Derived", "notOnAndroid"))
+    );
+}
+
+#[test]
+fn test_unknown_base_has_no_layout() {
+    let root = parse_str(BROMA).unwrap();
+    let class = root.find_class("NotInBroma").unwrap();
+    assert!(primary_vtable(class, &root, &Root::default(), Platform::Android64).is_none());
+}
+
+#[test]
+fn test_cocos_layout_from_vtables() {
+    let root = parse_str(BROMA).unwrap();
+    let vtables = parse_str(VTABLES).unwrap();
+
+    // onEnter sits where CCNode declares it, not where Cocos2d.bro lists it
+    let layer = root.find_class("cocos2d::CCLayer").unwrap();
+    let layout = primary_vtable(layer, &root, &vtables, Platform::Android64).unwrap();
+    assert_eq!(layout.slots, 7);
+    assert_eq!(
+        layout.own[&field_id(&root, "cocos2d::CCLayer", "onEnter")],
+        Slot::Primary(4)
+    );
+    assert_eq!(
+        layout.own[&field_id(&root, "cocos2d::CCLayer", "ccTouchBegan")],
+        Slot::Secondary("cocos2d::CCTouchDelegate".to_string())
+    );
+
+    let layout = primary_vtable(layer, &root, &vtables, Platform::Windows).unwrap();
+    assert_eq!(layout.slots, 5);
+    assert_eq!(
+        layout.own[&field_id(&root, "cocos2d::CCLayer", "onEnter")],
+        Slot::Primary(3)
+    );
+
+    // GD classes build on the described layout, `cocos2d::CCTouch *` matching `cocos2d::CCTouch*`
+    let game = root.find_class("GameLayer").unwrap();
+    let layout = primary_vtable(game, &root, &vtables, Platform::Windows).unwrap();
+    assert_eq!(layout.slots, 6);
+    assert_eq!(
+        layout.own[&field_id(&root, "GameLayer", "onEnter")],
+        Slot::Primary(3)
+    );
+    assert_eq!(
+        layout.own[&field_id(&root, "GameLayer", "ccTouchBegan")],
+        Slot::Secondary("cocos2d::CCTouchDelegate".to_string())
+    );
+    assert_eq!(
+        layout.own[&field_id(&root, "GameLayer", "added")],
+        Slot::Primary(5)
+    );
+
+    // without a description the layout stays unknown
+    assert!(primary_vtable(game, &root, &Root::default(), Platform::Windows).is_none());
+}
+
+#[test]
+fn test_shipped_vtables_cover_bound_virtuals() {
+    let bindings = Path::new(env!("CARGO_MANIFEST_DIR")).join("../geode-rs/bindings/2.2081");
+    let mut root = Root::default();
+    for file in ["Cocos2d.bro", "GeometryDash.bro", "Extras.bro"] {
+        let parsed = broma_rs::parse_file(&bindings.join(file)).unwrap();
+        root.classes.extend(parsed.classes);
+    }
+    let vtables = broma_rs::parse_file(&bindings.join("vtables/Cocos2d.bro")).unwrap();
+
+    // a virtual can be missing on some platforms, but not on all of them
+    let mut unmatched = Vec::new();
+    for class in &root.classes {
+        if vtables.find_class(&class.name).is_none() {
+            continue;
+        }
+        let layouts: Vec<_> = Platform::all()
+            .iter()
+            .map(|&platform| primary_vtable(class, &root, &vtables, platform).unwrap())
+            .collect();
+        for field in &class.fields {
+            let Some(func) = field.as_function_bind() else {
+                continue;
+            };
+            if func.prototype.is_virtual
+                && func.prototype.fn_type == broma_rs::FunctionType::Normal
+                && !layouts
+                    .iter()
+                    .any(|layout| layout.own.contains_key(&field.field_id))
+            {
+                unmatched.push(format!("{}::{}", class.name, func.prototype.name));
+            }
+        }
+    }
+    assert!(unmatched.is_empty(), "{unmatched:#?}");
+
+    // counted from the headers by hand
+    let layer = root.find_class("cocos2d::CCLayer").unwrap();
+    let on_enter = field_id(&root, "cocos2d::CCLayer", "onEnter");
+    let windows = primary_vtable(layer, &root, &vtables, Platform::Windows).unwrap();
+    assert_eq!(windows.slots, 127);
+    assert_eq!(windows.own[&on_enter], Slot::Primary(85));
+    let android = primary_vtable(layer, &root, &vtables, Platform::Android64).unwrap();
+    assert_eq!(android.slots, 141);
+    assert_eq!(android.own[&on_enter], Slot::Primary(86));
+}
@@ -3,18 +3,19 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use geode_egui::egui;
 use geode_rs::classes::PlayLayer;
 use geode_rs::classes::{
-    CCDirector, CCLabelBMFont, CCLayer, CCLayerColor, CCMenu, CCMenuItemFont, MenuLayer,
+    CCDirector, CCLabelBMFont, CCLayer, CCLayerColor, CCMenu, CCMenuItemFont, FLAlertLayer,
+    FLAlertLayerProtocol, FLAlertLayerProtocolImpl, MenuLayer,
 };
 use geode_rs::inherit::Obj;
 use geode_rs::loader::Mod;
 use geode_rs::types::{CCPoint, ccColor4B};
-use geode_rs::{geode_main, inherit, modify, spr};
+use geode_rs::{geode_main, inherit, modify, on_loaded, on_save, spr};
 
 geode_egui::install_hooks!();
 
 static RUST_POPUP_SHOWN: AtomicBool = AtomicBool::new(false);
 
-#[inherit(CCLayer)]
+#[inherit(CCLayer, implements(FLAlertLayerProtocol))]
 struct RustPopup {}
 
 impl RustPopup {
@@ -22,6 +23,7 @@ impl RustPopup {
         let mut popup =
             Obj::from_non_null(Self::try_alloc_uninit().expect("failed to allocate RustPopup"));
         popup.cc_layer_ctor();
+        Self::install_vtable(popup.as_non_null()).expect("failed to override RustPopup vtable");
         Self::init_fields(popup.as_non_null());
         assert!(popup.init_popup(), "RustPopup::init_popup failed");
         Self::autorelease(popup.as_non_null());
@@ -40,10 +42,13 @@ impl RustPopup {
             a: 170,
         };
 
-        let mut background = CCLayerColor::create_2(&background_color, 310.0, 170.0);
-        let mut title = CCLabelBMFont::create_1("Rust Cocos Popup", "bigFont.fnt");
-        let mut body =
-            CCLabelBMFont::create_1("A Cocos class inherited and built in Rust.", "bigFont.fnt");
+        let mut background =
+            CCLayerColor::create_with_color_width_height(&background_color, 310.0, 170.0);
+        let mut title = CCLabelBMFont::create_with_string_string("Rust Cocos Popup", "bigFont.fnt");
+        let mut body = CCLabelBMFont::create_with_string_string(
+            "A Cocos class inherited and built in Rust.",
+            "bigFont.fnt",
+        );
         let mut menu = CCMenu::create();
         let mut close = CCMenuItemFont::create("OK");
 
@@ -70,6 +75,33 @@ impl RustPopup {
     }
 }
 
+#[inherit(CCLayer)]
+impl RustPopup {
+    #[override_virtual]
+    fn on_enter(&mut self) {
+        self.base_on_enter(); // calls CCLayer::onEnter
+        log::info!("RustPopup entered the scene");
+
+        // the popup itself is the alert's delegate, see the FLAlertLayerProtocolImpl below
+        let mut alert =
+            FLAlertLayer::create_with_fl_alert_layer_protocol_string_string_string_string_float(
+                self.as_protocol(),
+                "Rust delegate",
+                "Button clicks on this alert are handled in Rust.",
+                "Cancel",
+                "OK",
+                300.0,
+            );
+        alert.show();
+    }
+}
+
+impl FLAlertLayerProtocolImpl for RustPopup {
+    fn fl_alert_clicked(&mut self, _layer: *mut FLAlertLayer, btn2: bool) {
+        log::info!("RustPopup alert clicked, second button: {btn2}");
+    }
+}
+
 fn show_rust_popup_once(parent: &mut MenuLayer) {
     if RUST_POPUP_SHOWN.load(Ordering::SeqCst) {
         return;
@@ -82,7 +114,7 @@ fn show_rust_popup_once(parent: &mut MenuLayer) {
         x: size.width * 0.5,
         y: size.height * 0.5,
     });
-    parent.add_child_2(&mut popup, 1000, 0);
+    parent.add_child_with_z_order_and_tag(&mut popup, 1000, 0);
 
     RUST_POPUP_SHOWN.store(true, Ordering::SeqCst);
 }
@@ -113,6 +145,19 @@ fn main() {
     log::info!("Example mod loaded from Rust!");
 }
 
+// `main` can also return a `Result`, an error is reported as the mod failing to load.
+// Panics in any of these are caught and logged.
+
+#[on_loaded]
+fn all_mods_loaded() {
+    log::info!("Every mod finished loading");
+}
+
+#[on_save]
+fn save_game() {
+    log::info!("The game is saving");
+}
+
 #[modify(MenuLayer)]
 struct MyMenuLayer {
     my_custom_field: i32,
@@ -121,7 +166,8 @@ struct MyMenuLayer {
 #[modify(MenuLayer)]
 impl MyMenuLayer {
     fn init(&mut self, this: &mut MenuLayer) -> bool {
-        if !this.init() {
+        // `<method>_orig` goes on to the next hook or the original function
+        if !unsafe { Self::init_orig(this) } {
             return false;
         }
 
@@ -136,8 +182,7 @@ impl MyMenuLayer {
 // Note: by default, #[modify] storage is unique per-hook. This means that even if the game
 //       eventually constructs a second PlayLayer, `self` will still point to the same data.
 //       If you want to have unique storage per instance of a class, use #[modify(Class, unique)]
-//       However, this makes you responsible of hooking a destructor and calling Self::free(this)
-//       to free up the storage slot when the class eventually gets destructed.
+//       The storage slot is released automatically when the object is destructed.
 
 #[modify(PlayLayer, unique)] // unique storage per constructed instance of PlayLayer
 struct MyPlayLayer {
@@ -148,14 +193,15 @@ struct MyPlayLayer {
 impl MyPlayLayer {
     fn play_layer_ctor(&mut self, this: &mut PlayLayer) {
         log::info!("PlayLayer constructor hook!");
-        this.play_layer_ctor();
+        unsafe { Self::play_layer_ctor_orig(this) };
     }
 
     fn play_layer_dtor(&mut self, this: &mut PlayLayer) {
-        Self::free(this); // release the #[modify] storage slot, or this is a memory leak!
-        this.play_layer_dtor();
+        log::info!("PlayLayer destructor hook!"); // storage is still alive here
+        unsafe { Self::play_layer_dtor_orig(this) };
     }
 
+    #[hook(first)] // or `priority = -100`, `last`, `before = "other.mod"`, `after = "other.mod"`
     fn post_update(&mut self, this: &mut PlayLayer, dt: f32) {
         self.print_timer += dt;
         if self.print_timer > 1.0 {
@@ -163,7 +209,7 @@ impl MyPlayLayer {
             self.print_timer = 0.0;
         }
         this.remove_all_checkpoints(); // disallow placing checkpoints
-        this.post_update(dt);
+        unsafe { Self::post_update_orig(this, dt) };
     }
 }
 
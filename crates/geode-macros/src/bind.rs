@@ -1,3 +1,5 @@
+use geode_codegen::declaration::parse_declaration;
+use geode_codegen::platform::Platform;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
 use syn::{
@@ -7,10 +9,10 @@ use syn::{
     token::Comma,
 };
 
-struct PlatformEntry {
-    platform: Ident,
-    symbol_bytes: Vec<u8>,
-    symbol_span: proc_macro2::Span,
+pub struct PlatformEntry {
+    pub platform: Ident,
+    pub symbol_bytes: Vec<u8>,
+    pub symbol_span: proc_macro2::Span,
 }
 
 impl Parse for PlatformEntry {
@@ -70,6 +72,7 @@ struct BindingDecl {
     fn_name: Ident,
     args: Vec<FnArg>,
     ret: ReturnKind,
+    cpp: Option<LitStr>,
     platforms: Vec<PlatformEntry>,
 }
 
@@ -115,9 +118,26 @@ impl Parse for BindingDecl {
 
         let body_content;
         syn::braced!(body_content in input);
-        let entries: Punctuated<PlatformEntry, Comma> =
-            body_content.parse_terminated(PlatformEntry::parse, Token![,])?;
-        let platforms: Vec<PlatformEntry> = entries.into_iter().collect();
+        let mut cpp = None;
+        let mut platforms = Vec::new();
+        while !body_content.is_empty() {
+            if body_content.peek(Ident) && body_content.peek2(Token![:]) {
+                let key: Ident = body_content.fork().parse()?;
+                if key == "cpp" {
+                    body_content.parse::<Ident>()?;
+                    body_content.parse::<Token![:]>()?;
+                    cpp = Some(body_content.parse::<LitStr>()?);
+                } else {
+                    platforms.push(body_content.parse()?);
+                }
+            } else {
+                platforms.push(body_content.parse()?);
+            }
+            if body_content.is_empty() {
+                break;
+            }
+            body_content.parse::<Token![,]>()?;
+        }
 
         Ok(Self {
             attrs,
@@ -126,6 +146,7 @@ impl Parse for BindingDecl {
             fn_name,
             args,
             ret,
+            cpp,
             platforms,
         })
     }
@@ -154,13 +175,47 @@ pub fn expand_geode_bind(input: TokenStream2) -> Result<TokenStream2> {
     Ok(output)
 }
 
-fn expand_one(decl: BindingDecl) -> Result<TokenStream2> {
+fn expand_one(mut decl: BindingDecl) -> Result<TokenStream2> {
+    if let Some(cpp) = decl.cpp.take() {
+        derive_symbols(&mut decl, &cpp)?;
+    }
     match &decl.ret {
         ReturnKind::Sret(_) | ReturnKind::MethodSret(_) => expand_sret(decl),
         ReturnKind::Plain(_) | ReturnKind::Void => expand_plain(decl),
     }
 }
 
+// `cpp: "geode::Loader::isModLoaded(std::string_view) const -> bool"` derives the symbol for
+// every platform, platform entries next to it take precedence
+fn derive_symbols(decl: &mut BindingDecl, cpp: &LitStr) -> Result<()> {
+    let mut declaration = parse_declaration(&cpp.value())
+        .map_err(|err| syn::Error::new(cpp.span(), format!("invalid C++ declaration: {err}")))?;
+
+    declaration
+        .match_binding_args(decl.args.len())
+        .map_err(|err| syn::Error::new(cpp.span(), err.to_string()))?;
+
+    let overridden: Vec<Platform> = decl
+        .platforms
+        .iter()
+        .flat_map(|entry| platform_key_to_platforms(&entry.platform))
+        .copied()
+        .collect();
+    for &platform in Platform::all() {
+        if overridden.contains(&platform) {
+            continue;
+        }
+        let mut symbol_bytes = declaration.symbol(platform).into_bytes();
+        symbol_bytes.push(0);
+        decl.platforms.push(PlatformEntry {
+            platform: Ident::new(&platform.to_string(), cpp.span()),
+            symbol_bytes,
+            symbol_span: cpp.span(),
+        });
+    }
+    Ok(())
+}
+
 fn expand_plain(decl: BindingDecl) -> Result<TokenStream2> {
     let BindingDecl {
         attrs,
@@ -170,6 +225,7 @@ fn expand_plain(decl: BindingDecl) -> Result<TokenStream2> {
         args,
         ret,
         platforms,
+        ..
     } = decl;
 
     let ret_ty = match &ret {
@@ -182,8 +238,7 @@ fn expand_plain(decl: BindingDecl) -> Result<TokenStream2> {
     let arg_types: Vec<&Type> = args.iter().map(|a| &a.ty).collect();
 
     let mut platform_arms = TokenStream2::new();
-    for entry in &platforms {
-        let cfg = platform_key_to_cfg(&entry.platform)?;
+    for (cfg, entry, _) in platform_targets(&platforms)? {
         let sym_lit = LitByteStr::new(&entry.symbol_bytes, entry.symbol_span);
         let resolver = platform_key_to_resolver(&entry.platform, &sym_lit, &static_name);
         let abi = platform_key_to_abi(&entry.platform);
@@ -225,6 +280,7 @@ fn expand_sret(decl: BindingDecl) -> Result<TokenStream2> {
         args,
         ret,
         platforms,
+        ..
     } = decl;
 
     let (out_ty, is_method) = match &ret {
@@ -239,16 +295,17 @@ fn expand_sret(decl: BindingDecl) -> Result<TokenStream2> {
 
     let mut platform_arms = TokenStream2::new();
 
-    for entry in &platforms {
+    for (cfg, entry, is_host) in platform_targets(&platforms)? {
         let platform_str = entry.platform.to_string();
-        let cfg = platform_key_to_cfg(&entry.platform)?;
         let sym_lit = LitByteStr::new(&entry.symbol_bytes, entry.symbol_span);
         let resolver = platform_key_to_resolver(&entry.platform, &sym_lit, &static_name);
 
-        let is_aarch64 = matches!(
-            platform_str.as_str(),
-            "android64" | "mac_arm" | "m1" | "ios" | "ios_arm"
-        );
+        // host fakes are plain `extern "C"` functions taking the out pointer first
+        let is_aarch64 = !is_host
+            && matches!(
+                platform_str.as_str(),
+                "android64" | "mac_arm" | "m1" | "ios" | "ios_arm"
+            );
 
         let addr_resolve = quote! {
             static #static_name: ::std::sync::atomic::AtomicUsize =
@@ -293,6 +350,34 @@ fn expand_sret(decl: BindingDecl) -> Result<TokenStream2> {
     })
 }
 
+// Every platform entry under its own cfg, plus the Android symbol again for hosts without
+// the game, where only a `SymbolProvider` can resolve it
+fn platform_targets(
+    platforms: &[PlatformEntry],
+) -> Result<Vec<(TokenStream2, &PlatformEntry, bool)>> {
+    let mut targets = Vec::with_capacity(platforms.len() + 1);
+    for entry in platforms {
+        targets.push((platform_key_to_cfg(&entry.platform)?, entry, false));
+    }
+
+    let host = ["android64", "android", "android32"]
+        .iter()
+        .find_map(|key| platforms.iter().find(|entry| entry.platform == key));
+    if let Some(entry) = host {
+        targets.push((
+            quote!(not(any(
+                target_os = "windows",
+                target_os = "macos",
+                target_os = "ios",
+                target_os = "android"
+            ))),
+            entry,
+            true,
+        ));
+    }
+    Ok(targets)
+}
+
 fn uses_direct_aarch64_return(ty: &Type) -> bool {
     let Type::Path(path) = ty else {
         return false;
@@ -414,7 +499,7 @@ fn platform_key_to_abi(ident: &Ident) -> TokenStream2 {
     }
 }
 
-fn platform_key_to_cfg(ident: &Ident) -> Result<TokenStream2> {
+pub fn platform_key_to_cfg(ident: &Ident) -> Result<TokenStream2> {
     let key = ident.to_string();
     let ts = match key.as_str() {
         "win" | "windows" => quote!(target_os = "windows"),
@@ -440,6 +525,20 @@ fn platform_key_to_cfg(ident: &Ident) -> Result<TokenStream2> {
     Ok(ts)
 }
 
+fn platform_key_to_platforms(ident: &Ident) -> &'static [Platform] {
+    match ident.to_string().as_str() {
+        "win" | "windows" => &[Platform::Windows],
+        "mac" | "macos" => &[Platform::MacIntel, Platform::MacArm],
+        "mac_intel" | "imac" => &[Platform::MacIntel],
+        "mac_arm" | "m1" => &[Platform::MacArm],
+        "ios" | "ios_arm" => &[Platform::IOS],
+        "android32" => &[Platform::Android32],
+        "android64" => &[Platform::Android64],
+        "android" => &[Platform::Android32, Platform::Android64],
+        _ => &[],
+    }
+}
+
 fn platform_key_to_resolver(ident: &Ident, sym: &LitByteStr, static_name: &Ident) -> TokenStream2 {
     let key = ident.to_string();
     match key.as_str() {
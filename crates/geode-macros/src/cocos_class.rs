@@ -1,29 +1,89 @@
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
 use syn::parse::{Parse, ParseStream};
-use syn::{Fields, ItemStruct, Path, Result, Visibility};
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Expr, Fields, FnArg, Ident, ImplItemFn, Item, ItemImpl, ItemStruct, Meta, Path,
+    Result, Type, Visibility,
+};
+
+use crate::modify::{
+    DetourTarget, HookAttrs, detour_body, detour_signature_check, ffi_param, pat_ident,
+};
 
 struct CocosClassArgs {
     base: Path,
+    vtable_slots: Option<Expr>,
+    implements: Option<(Ident, Vec<Path>)>,
 }
 
 impl Parse for CocosClassArgs {
     fn parse(input: ParseStream) -> Result<Self> {
+        let base: Path = input.parse()?;
+        let mut vtable_slots = None;
+        let mut implements = None;
+
+        while input.peek(syn::Token![,]) {
+            input.parse::<syn::Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let ident: Ident = input.parse()?;
+            if ident == "vtable_slots" {
+                input.parse::<syn::Token![=]>()?;
+                vtable_slots = Some(input.parse()?);
+            } else if ident == "implements" {
+                let content;
+                syn::parenthesized!(content in input);
+                let protocols = content.parse_terminated(Path::parse, syn::Token![,])?;
+                implements = Some((ident, protocols.into_iter().collect()));
+            } else {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "expected `vtable_slots = ..` or `implements(..)`",
+                ));
+            }
+        }
+
         Ok(Self {
-            base: input.parse()?,
+            base,
+            vtable_slots,
+            implements,
         })
     }
 }
 
 pub fn expand_cocos_class(args: TokenStream2, item: TokenStream2) -> Result<TokenStream2> {
     let args: CocosClassArgs = syn::parse2(args)?;
-    let struct_item: ItemStruct = syn::parse2(item)?;
+    match syn::parse2(item)? {
+        Item::Struct(struct_item) => expand_cocos_struct(args, struct_item),
+        Item::Impl(impl_item) => expand_cocos_impl(args, impl_item),
+        other => Err(syn::Error::new_spanned(
+            other,
+            "inherit attribute can only be applied to structs or impl blocks",
+        )),
+    }
+}
+
+fn expand_cocos_struct(args: CocosClassArgs, struct_item: ItemStruct) -> Result<TokenStream2> {
+    if let Some(vtable_slots) = &args.vtable_slots {
+        return Err(syn::Error::new_spanned(
+            vtable_slots,
+            "`vtable_slots` belongs on the #[inherit] impl block with the overrides",
+        ));
+    }
+
+    let protocols = args
+        .implements
+        .as_ref()
+        .map_or(&[][..], |(_, protocols)| protocols.as_slice());
 
     let attrs = &struct_item.attrs;
     let vis = &struct_item.vis;
     let name = &struct_item.ident;
     let generics = &struct_item.generics;
     let base = &args.base;
+    let vtable_static = vtable_static(name);
 
     if !generics.params.is_empty() {
         return Err(syn::Error::new_spanned(
@@ -60,11 +120,69 @@ pub fn expand_cocos_class(args: TokenStream2, item: TokenStream2) -> Result<Toke
         _ => quote!(),
     };
 
+    // each protocol is one vtable pointer right after the primary base, like in C++
+    let protocol_fields: Vec<_> = protocols
+        .iter()
+        .map(|protocol| {
+            let last = &protocol.segments.last().expect("protocol path").ident;
+            format_ident!("_vt_{}", last)
+        })
+        .collect();
+    let protocol_installs: Vec<_> = protocols
+        .iter()
+        .zip(&protocol_fields)
+        .map(|(protocol, field)| {
+            quote! {
+                {
+                    const OFFSET: usize = ::std::mem::offset_of!(#name, #field);
+                    static VTABLE: ::std::sync::OnceLock<::geode_rs::inherit::ProtocolVtable> =
+                        ::std::sync::OnceLock::new();
+                    VTABLE
+                        .get_or_init(|| {
+                            ::geode_rs::inherit::ProtocolVtable::build(
+                                this,
+                                OFFSET,
+                                #protocol::vtable_for::<#name, OFFSET>(),
+                            )
+                        })
+                        .install(this, OFFSET);
+                }
+            }
+        })
+        .collect();
+    let protocol_impls = protocols
+        .iter()
+        .zip(&protocol_fields)
+        .map(|(protocol, field)| {
+            quote! {
+                unsafe impl ::geode_rs::inherit::Implements<#protocol> for #name {
+                    const OFFSET: usize = ::std::mem::offset_of!(#name, #field);
+                }
+            }
+        });
+    let as_protocol = (!protocols.is_empty()).then(|| {
+        quote! {
+            // the pointer C++ expects wherever it takes one of the implemented protocols
+            pub fn as_protocol<P>(&mut self) -> &mut P
+            where
+                Self: ::geode_rs::inherit::Implements<P>,
+            {
+                unsafe {
+                    <Self as ::geode_rs::inherit::Implements<P>>::protocol_ptr(
+                        ::std::ptr::NonNull::from(self),
+                    )
+                    .as_mut()
+                }
+            }
+        }
+    });
+
     let expanded = quote! {
         #(#attrs)*
         #[repr(C)]
         #vis struct #name #generics {
             #base_vis base: #base,
+            #(#base_vis #protocol_fields: *mut ::std::ffi::c_void,)*
             #(#fields),*
         }
 
@@ -77,10 +195,13 @@ pub fn expand_cocos_class(args: TokenStream2, item: TokenStream2) -> Result<Toke
                 unsafe { ::geode_rs::inherit::alloc_cocos_object::<Self>() }
             }
 
+            // also points the protocol vtables at the Rust impls, so call it after the base
+            // constructor
             pub fn init_fields(this: ::std::ptr::NonNull<Self>) {
                 unsafe {
                     #(#defaults)*
                 }
+                #(#protocol_installs)*
             }
 
             pub unsafe fn init_default_fields(this: *mut Self) {
@@ -138,6 +259,31 @@ pub fn expand_cocos_class(args: TokenStream2, item: TokenStream2) -> Result<Toke
             pub fn base_mut(&mut self) -> &mut #base {
                 &mut self.base
             }
+
+            #as_protocol
+        }
+
+        #(#protocol_impls)*
+
+        // filled in by the #[inherit] impl's `install_vtable`
+        #[allow(non_upper_case_globals)]
+        static #vtable_static: ::std::sync::OnceLock<
+            Result<::geode_rs::inherit::OverrideVtable, String>,
+        > = ::std::sync::OnceLock::new();
+
+        unsafe impl ::geode_rs::refs::CocosObject for #name
+        where
+            #base: ::geode_rs::refs::CocosObject,
+        {
+            fn is_instance(
+                object: ::std::ptr::NonNull<::geode_rs::classes::CCObject>,
+            ) -> bool {
+                #vtable_static.get().is_some_and(|vtable| {
+                    vtable
+                        .as_ref()
+                        .is_ok_and(|vtable| vtable.is_installed_on(object))
+                })
+            }
         }
 
         impl ::std::ops::Deref for #name {
@@ -157,3 +303,298 @@ pub fn expand_cocos_class(args: TokenStream2, item: TokenStream2) -> Result<Toke
 
     Ok(expanded)
 }
+
+// declared by the struct, so it exists whether or not the impl overrides anything
+fn vtable_static(struct_name: &Ident) -> Ident {
+    format_ident!("__{}_VTABLE", struct_name.to_string().to_uppercase())
+}
+
+struct OverrideArgs {
+    slot: Option<Expr>,
+    from: Option<Path>,
+}
+
+fn parse_override_args(attr: &Attribute) -> Result<OverrideArgs> {
+    let mut args = OverrideArgs {
+        slot: None,
+        from: None,
+    };
+    if let Meta::Path(_) = attr.meta {
+        return Ok(args);
+    }
+
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("slot") {
+            args.slot = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("from") {
+            args.from = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("expected `slot = ..` or `from = Class`"))
+        }
+    })?;
+    Ok(args)
+}
+
+fn expand_cocos_impl(args: CocosClassArgs, mut impl_block: ItemImpl) -> Result<TokenStream2> {
+    if let Some((implements, _)) = &args.implements {
+        return Err(syn::Error::new_spanned(
+            implements,
+            "`implements` belongs on the #[inherit] struct",
+        ));
+    }
+
+    let struct_name = match &*impl_block.self_ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|s| s.ident.clone())
+            .ok_or_else(|| syn::Error::new_spanned(&impl_block.self_ty, "invalid type"))?,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &impl_block.self_ty,
+                "expected a type path",
+            ));
+        }
+    };
+
+    let base = &args.base;
+    let vtable_slots = match &args.vtable_slots {
+        Some(slots) => quote!(Some(#slots)),
+        None => quote!(#base::VTABLE_SLOTS),
+    };
+    let vtable_static = vtable_static(&struct_name);
+
+    let mut thunks = Vec::new();
+    let mut overrides = Vec::new();
+    let mut base_fns = Vec::new();
+    let mut signature_checks = Vec::new();
+
+    for item in &mut impl_block.items {
+        let syn::ImplItem::Fn(method) = item else {
+            continue;
+        };
+        let Some(attr) = method
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("override_virtual"))
+        else {
+            continue;
+        };
+        let override_args = parse_override_args(attr)?;
+        method
+            .attrs
+            .retain(|attr| !attr.path().is_ident("override_virtual"));
+
+        let index = overrides.len();
+        let owner = override_args.from.unwrap_or_else(|| base.clone());
+        let generated = expand_override(&struct_name, &owner, &vtable_static, index, method)?;
+        thunks.push(generated.thunk);
+        base_fns.push(generated.base_fn);
+        signature_checks.push(generated.signature_check);
+
+        let thunk_name = generated.thunk_name;
+        let name = generated.name;
+        let addr_fn = generated.addr_fn;
+        let slot = match override_args.slot {
+            Some(slot) => quote!(Some(#slot)),
+            None => {
+                let method = addr_fn.to_string();
+                let slot_const = format_ident!("{}_SLOT", method.trim_end_matches("_ADDR"));
+                quote!(#owner::#slot_const)
+            }
+        };
+        // the slot consts of a secondary base's virtuals panic, this makes that a compile error
+        signature_checks.push(quote!(const _: Option<usize> = #slot;));
+        overrides.push(quote! {
+            ::geode_rs::inherit::VirtualOverride {
+                name: #name,
+                base_address: #owner::#addr_fn(),
+                detour: #thunk_name as *mut ::std::ffi::c_void,
+                slot: #slot,
+            }
+        });
+    }
+
+    if overrides.is_empty() {
+        return Ok(quote!(#impl_block));
+    }
+
+    Ok(quote! {
+        #impl_block
+
+        #(#thunks)*
+
+        #(#signature_checks)*
+
+        impl #struct_name {
+            // call after the base constructor, which is what sets the vtable being cloned
+            pub fn install_vtable(this: ::std::ptr::NonNull<Self>) -> Result<(), String> {
+                let vtable = #vtable_static.get_or_init(|| {
+                    ::geode_rs::inherit::OverrideVtable::build(
+                        this,
+                        stringify!(#base),
+                        #vtable_slots,
+                        &[#(#overrides),*],
+                    )
+                });
+                match vtable {
+                    Ok(vtable) => {
+                        vtable.install(this);
+                        Ok(())
+                    }
+                    Err(err) => Err(err.clone()),
+                }
+            }
+
+            #(#base_fns)*
+        }
+    })
+}
+
+struct GeneratedOverride {
+    name: String,
+    addr_fn: Ident,
+    thunk_name: Ident,
+    thunk: TokenStream2,
+    base_fn: TokenStream2,
+    signature_check: TokenStream2,
+}
+
+fn expand_override(
+    struct_name: &Ident,
+    owner: &Path,
+    vtable_static: &Ident,
+    index: usize,
+    method: &ImplItemFn,
+) -> Result<GeneratedOverride> {
+    let method_name = &method.sig.ident;
+    let method_name_str = method_name.to_string();
+    let owner_name = owner
+        .segments
+        .last()
+        .map(|s| s.ident.to_string())
+        .unwrap_or_default();
+    let name = format!("{owner_name}::{method_name_str}");
+
+    let receiver = match method.sig.inputs.first() {
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() => receiver,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &method.sig,
+                "override_virtual methods must take `&self` or `&mut self`",
+            ));
+        }
+    };
+    let this_ref = if receiver.mutability.is_some() {
+        quote!(&mut *this)
+    } else {
+        quote!(&*this)
+    };
+
+    let mut params = Vec::new();
+    let mut ffi_params = Vec::new();
+    let mut call_args = Vec::new();
+    let mut base_args = Vec::new();
+    let mut detour_types = vec![(quote!(*mut #owner), Span::call_site())];
+    let mut thunk_names = vec![Some(format_ident!("this"))];
+    for arg in method.sig.inputs.iter().skip(1) {
+        let FnArg::Typed(pat_type) = arg else {
+            continue;
+        };
+        let pat = &pat_type.pat;
+        let ty = &pat_type.ty;
+        let (ffi_ty, call_arg) = ffi_param(pat, ty);
+        let base_arg = match &**ty {
+            Type::Reference(reference) if reference.mutability.is_some() => {
+                quote!(#pat as *mut _)
+            }
+            Type::Reference(_) => quote!(#pat as *const _),
+            _ => quote!(#pat),
+        };
+
+        params.push(quote!(#pat: #ty));
+        ffi_params.push(quote!(#pat: #ffi_ty));
+        call_args.push(call_arg);
+        base_args.push(base_arg);
+        detour_types.push((ffi_ty, arg.span()));
+        thunk_names.push(pat_ident(pat));
+    }
+    let ffi_types: Vec<_> = detour_types.iter().skip(1).map(|(ty, _)| ty).collect();
+
+    let output = &method.sig.output;
+    let addr_fn = format_ident!("{}_ADDR", method_name_str.to_uppercase());
+    let fn_marker = format_ident!("{}_FN", method_name_str.to_uppercase());
+    let thunk_name = format_ident!(
+        "__vthunk_{}_{}",
+        struct_name.to_string().to_lowercase(),
+        method_name_str
+    );
+    let base_fn_name = format_ident!("base_{}", method_name_str);
+
+    let base_address = quote! {
+        #vtable_static
+            .get()
+            .and_then(|vtable| vtable.as_ref().ok())
+            .map_or_else(|| #owner::#addr_fn(), |vtable| vtable.original(#index))
+    };
+    // a panicking override falls back to the base implementation, unless it already called it
+    let mut thunk_types = vec![(quote!(*mut #struct_name), Span::call_site())];
+    thunk_types.extend(detour_types.iter().skip(1).cloned());
+    let thunk_body = detour_body(
+        &DetourTarget {
+            name: &name,
+            address: quote!(#owner::#addr_fn),
+            this: quote!(this as *const ::std::ffi::c_void),
+            original: base_address.clone(),
+        },
+        &HookAttrs {
+            order: None,
+            enabled: true,
+            panic_action: quote!(None),
+            disable_after: quote!(None),
+        },
+        &thunk_names,
+        &thunk_types,
+        output,
+        quote! {
+            let this = unsafe { #this_ref };
+            this.#method_name(#(#call_args),*)
+        },
+    );
+    let thunk = quote! {
+        #[allow(clippy::not_unsafe_ptr_arg_deref)]
+        extern "C" fn #thunk_name(this: *mut #struct_name, #(#ffi_params),*) #output {
+            #thunk_body
+        }
+    };
+
+    let self_ptr = if receiver.mutability.is_some() {
+        quote!(self as *mut Self)
+    } else {
+        quote!(self as *const Self as *mut Self)
+    };
+    let base_fn = quote! {
+        pub fn #base_fn_name(#receiver, #(#params),*) #output {
+            let addr = #base_address;
+            let this = #self_ptr;
+            ::geode_rs::modify::note_original(#owner::#addr_fn(), this as *const ::std::ffi::c_void);
+            let func: extern "C" fn(*mut Self, #(#ffi_types),*) #output =
+                unsafe { ::std::mem::transmute(addr) };
+            func(this, #(#base_args),*)
+        }
+    };
+
+    let signature_check = detour_signature_check(owner, &fn_marker, &name, &detour_types, output);
+
+    Ok(GeneratedOverride {
+        name,
+        addr_fn,
+        thunk_name,
+        thunk,
+        base_fn,
+        signature_check,
+    })
+}
@@ -0,0 +1,362 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Expr, FnArg, Ident, ItemFn, LitByteStr, LitInt, LitStr, MetaNameValue, Path, Result};
+
+use crate::bind::{PlatformEntry, platform_key_to_cfg};
+use crate::modify::{
+    DetourTarget, abstract_signature, detour_body, detour_signature_check, ffi_param,
+    parse_hook_attrs, pat_ident, to_snake_case,
+};
+
+// What a free-standing detour is attached to
+struct FreeHook {
+    name: String,
+    // statements returning the absolute address, falls through to 0 when it can't be
+    // resolved on this platform
+    address: TokenStream2,
+    // `module::NAME_FN` marker of a bound function to check the detour against
+    marker: Option<(Path, Ident)>,
+}
+
+pub fn expand_hook_fn(args: TokenStream2, item: TokenStream2) -> Result<TokenStream2> {
+    let target: Path = syn::parse2(args)?;
+    let func: ItemFn = syn::parse2(item)?;
+
+    if target.segments.len() < 2 {
+        return Err(syn::Error::new_spanned(
+            &target,
+            "expected a path to a bound function, such as `geode_rs::functions::km_gl_push_matrix`",
+        ));
+    }
+
+    let mut module_path = target.clone();
+    let target_segment = module_path.segments.pop().unwrap().into_value();
+    module_path.segments.pop_punct();
+    let target_name = target_segment.ident.to_string();
+    let addr_fn = format_ident!("{}_ADDR", target_name.to_uppercase());
+    let fn_marker = format_ident!("{}_FN", target_name.to_uppercase());
+
+    expand_free_hook(
+        func,
+        "hook_fn",
+        FreeHook {
+            name: target_name,
+            address: quote!(return #module_path::#addr_fn();),
+            marker: Some((module_path, fn_marker)),
+        },
+    )
+}
+
+// #[hook_at(win = 0x1234, android64 = 0x5678, module = cocos)], offsets are relative to the
+// game binary unless `module` says otherwise (only meaningful on Windows)
+pub fn expand_hook_at(args: TokenStream2, item: TokenStream2) -> Result<TokenStream2> {
+    let args = syn::parse::Parser::parse2(
+        Punctuated::<MetaNameValue, syn::Token![,]>::parse_terminated,
+        args,
+    )?;
+    let func: ItemFn = syn::parse2(item)?;
+
+    let mut windows_base = quote!(::geode_rs::base::get());
+    let mut offsets: Vec<(Ident, LitInt)> = Vec::new();
+    for arg in args {
+        let key = arg.path.require_ident()?.clone();
+        if key == "module" {
+            let Expr::Path(module) = &arg.value else {
+                return Err(syn::Error::new_spanned(
+                    &arg.value,
+                    "expected `game`, `cocos` or `extensions`",
+                ));
+            };
+            windows_base = match module.path.require_ident()?.to_string().as_str() {
+                "game" => quote!(::geode_rs::base::get()),
+                "cocos" => quote!(::geode_rs::base::get_cocos()),
+                "extensions" => quote!(::geode_rs::base::get_extensions()),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        module,
+                        "expected `game`, `cocos` or `extensions`",
+                    ));
+                }
+            };
+            continue;
+        }
+        let Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(offset),
+            ..
+        }) = &arg.value
+        else {
+            return Err(syn::Error::new_spanned(
+                &arg.value,
+                "expected an address offset such as `0x1234`",
+            ));
+        };
+        offsets.push((key, offset.clone()));
+    }
+    if offsets.is_empty() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "#[hook_at] needs at least one `platform = 0x...` address",
+        ));
+    }
+
+    let mut arms = TokenStream2::new();
+    for (platform, offset) in &offsets {
+        let cfg = platform_key_to_cfg(platform)?;
+        let base = if matches!(platform.to_string().as_str(), "win" | "windows") {
+            windows_base.clone()
+        } else {
+            quote!(::geode_rs::base::get())
+        };
+        arms.extend(quote! {
+            #[cfg(#cfg)]
+            {
+                return #base + #offset;
+            }
+        });
+    }
+
+    let name = func.sig.ident.to_string();
+    expand_free_hook(
+        func,
+        "hook_at",
+        FreeHook {
+            name,
+            address: arms,
+            marker: None,
+        },
+    )
+}
+
+struct HookSymbolInput {
+    platforms: Vec<PlatformEntry>,
+    func: ItemFn,
+}
+
+impl Parse for HookSymbolInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut platforms = Vec::new();
+        if input.peek(LitStr) {
+            // a single symbol shared by every platform
+            let symbol: LitStr = input.parse()?;
+            input.parse::<syn::Token![,]>()?;
+            let mut symbol_bytes = symbol.value().into_bytes();
+            symbol_bytes.push(0);
+            platforms.push(PlatformEntry {
+                platform: Ident::new("all", symbol.span()),
+                symbol_bytes,
+                symbol_span: symbol.span(),
+            });
+        } else {
+            while input.peek(Ident) && input.peek2(syn::Token![:]) {
+                platforms.push(input.parse()?);
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+        if platforms.is_empty() {
+            return Err(input.error("expected a symbol or `platform: \"symbol\"` entries"));
+        }
+        let func: ItemFn = input.parse()?;
+        Ok(Self { platforms, func })
+    }
+}
+
+// hook_symbol!("_ZN7cocos2d6CCNode6setTagEi", fn set_tag(..) { .. }) or with per-platform
+// `win: "...", android: "...",` entries in front of the function
+pub fn expand_hook_symbol(input: TokenStream2) -> Result<TokenStream2> {
+    let HookSymbolInput { platforms, func } = syn::parse2(input)?;
+
+    let slot = format_ident!(
+        "__HOOK_SYMBOL_{}",
+        to_snake_case(&func.sig.ident.to_string()).to_uppercase()
+    );
+    let mut arms = TokenStream2::new();
+    for entry in &platforms {
+        let symbol = LitByteStr::new(&entry.symbol_bytes, entry.symbol_span);
+        let resolve = quote! {
+            static #slot: ::std::sync::atomic::AtomicUsize =
+                ::std::sync::atomic::AtomicUsize::new(0);
+            return ::geode_rs::base::resolve_symbol(
+                ::geode_rs::base::SymbolScope::Process,
+                #symbol,
+                &#slot,
+            );
+        };
+        if entry.platform == "all" {
+            arms.extend(quote!({ #resolve }));
+        } else {
+            let cfg = platform_key_to_cfg(&entry.platform)?;
+            arms.extend(quote! {
+                #[cfg(#cfg)]
+                {
+                    #resolve
+                }
+            });
+        }
+    }
+
+    let name = func.sig.ident.to_string();
+    expand_free_hook(
+        func,
+        "hook_symbol!",
+        FreeHook {
+            name,
+            address: arms,
+            marker: None,
+        },
+    )
+}
+
+fn expand_free_hook(mut func: ItemFn, attr_name: &str, target: FreeHook) -> Result<TokenStream2> {
+    if !func.sig.generics.params.is_empty() || func.sig.asyncness.is_some() {
+        return Err(syn::Error::new_spanned(
+            &func.sig,
+            format!("{attr_name} functions must be plain non-generic functions"),
+        ));
+    }
+    if let Some(receiver) = func.sig.receiver() {
+        return Err(syn::Error::new_spanned(
+            receiver,
+            format!("{attr_name} hooks free functions, use #[modify] to hook methods"),
+        ));
+    }
+
+    // a panic can't unwind out of an `extern "C"` body, the detour wrapping it is the
+    // function the game calls
+    func.sig.abi = None;
+
+    let hook_attrs = parse_hook_attrs(&func.attrs)?;
+    let order = hook_attrs
+        .order
+        .clone()
+        .unwrap_or_else(|| quote!(::geode_rs::modify::HookOrder::default()));
+    let enabled = hook_attrs.enabled;
+    func.attrs.retain(|attr| !attr.path().is_ident("hook"));
+
+    let FreeHook {
+        name: hook_name,
+        address,
+        marker,
+    } = target;
+
+    let vis = &func.vis;
+    let fn_name = &func.sig.ident;
+    let snake_name = to_snake_case(&fn_name.to_string());
+    let detour_func_name = format_ident!("__detour_{}", snake_name);
+    let address_fn_name = format_ident!("__hook_address_{}", snake_name);
+    let registry_static_name = format_ident!("__HOOK_REGISTRY_{}", snake_name.to_uppercase());
+    let hooks_static_name = format_ident!("__HOOK_FN_{}", snake_name.to_uppercase());
+    let hook_fn_name = format_ident!("{}_hook", fn_name);
+    let output = &func.sig.output;
+
+    let mut detour_params: Vec<TokenStream2> = Vec::new();
+    let mut detour_types: Vec<(TokenStream2, Span)> = Vec::new();
+    let mut detour_names: Vec<Option<Ident>> = Vec::new();
+    let mut call_args: Vec<TokenStream2> = Vec::new();
+    let mut orig_params: Vec<TokenStream2> = Vec::new();
+    let mut orig_types: Vec<TokenStream2> = Vec::new();
+    let mut orig_args: Vec<Ident> = Vec::new();
+    for (index, arg) in func.sig.inputs.iter().enumerate() {
+        if let FnArg::Typed(pat_type) = arg {
+            let (detour_ty, call_arg) = ffi_param(&pat_type.pat, &pat_type.ty);
+            let pat = &pat_type.pat;
+            detour_params.push(quote!(#pat: #detour_ty));
+            detour_types.push((detour_ty, arg.span()));
+            detour_names.push(pat_ident(pat));
+            call_args.push(call_arg);
+
+            // references are passed as the pointers they are at the ABI level
+            let ty = &pat_type.ty;
+            let arg_name = format_ident!("arg{}", index);
+            orig_params.push(quote!(#arg_name: #ty));
+            orig_types.push(quote!(#ty));
+            orig_args.push(arg_name);
+        }
+    }
+
+    let signature_check = marker.map(|(module_path, fn_marker)| {
+        detour_signature_check(&module_path, &fn_marker, &hook_name, &detour_types, output)
+    });
+
+    // calling the hooked address from inside the detour goes on to the next hook or the
+    // original function, and marks the detour as having reached it
+    let orig_fn_name = format_ident!("{}_orig", fn_name);
+    let ret = match output {
+        syn::ReturnType::Default => quote!(()),
+        syn::ReturnType::Type(_, ty) => quote!(#ty),
+    };
+    let orig = quote! {
+        #vis unsafe fn #orig_fn_name(#(#orig_params),*) -> #ret {
+            let addr = #address_fn_name();
+            assert!(addr != 0, concat!("address for ", #hook_name, " is 0"));
+            ::geode_rs::modify::note_original(addr, ::std::ptr::null());
+            let func: extern "C" fn(#(#orig_types),*) -> #ret =
+                unsafe { ::std::mem::transmute(addr) };
+            func(#(#orig_args),*)
+        }
+    };
+
+    let signature = abstract_signature(&detour_types, output);
+    let detour_body = detour_body(
+        &DetourTarget {
+            name: &hook_name,
+            address: quote!(#address_fn_name),
+            this: quote!(::std::ptr::null()),
+            original: quote!(__SITE.address()),
+        },
+        &hook_attrs,
+        &detour_names,
+        &detour_types,
+        output,
+        quote!(#fn_name(#(#call_args),*)),
+    );
+
+    Ok(quote! {
+        #func
+
+        #[allow(clippy::not_unsafe_ptr_arg_deref)]
+        pub extern "C" fn #detour_func_name(#(#detour_params),*) #output {
+            #detour_body
+        }
+
+        #signature_check
+
+        #[allow(unreachable_code)]
+        fn #address_fn_name() -> usize {
+            #address
+            0
+        }
+
+        #orig
+
+        #[allow(non_upper_case_globals)]
+        static #registry_static_name: ::geode_rs::modify::HookRegistry =
+            ::geode_rs::modify::HookRegistry::new();
+
+        #vis fn #hook_fn_name() -> Option<::geode_rs::loader::Hook> {
+            #registry_static_name.get(#hook_name)
+        }
+
+        #[used]
+        #[::geode_rs::ctor::ctor(crate_path = ::geode_rs::ctor)]
+        static #hooks_static_name: () = {
+            unsafe {
+                ::geode_rs::diagnostics::register_binding("", #hook_name, #address_fn_name);
+                ::geode_rs::modify::register_hook_resolver(
+                    #address_fn_name,
+                    #detour_func_name as *mut ::std::ffi::c_void,
+                    #hook_name,
+                    ::geode_rs::CallingConvention::Default,
+                    ::geode_rs::modify::HookOptions {
+                        order: #order,
+                        enabled: #enabled,
+                        registry: Some((&#registry_static_name, #hook_name)),
+                        signature: #signature,
+                    },
+                );
+            }
+        };
+    })
+}
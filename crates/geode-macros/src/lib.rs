@@ -3,6 +3,7 @@ use proc_macro2::TokenStream as TokenStream2;
 
 mod bind;
 mod cocos_class;
+mod hook;
 mod main_;
 mod modify;
 
@@ -21,12 +22,54 @@ pub fn modify(attr: TokenStream, item: TokenStream) -> TokenStream {
     convert_result(modify::expand_modify(attr, item))
 }
 
+#[proc_macro_attribute]
+pub fn hook_fn(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr: TokenStream2 = attr.into();
+    let item: TokenStream2 = item.into();
+
+    convert_result(hook::expand_hook_fn(attr, item))
+}
+
+#[proc_macro_attribute]
+pub fn hook_at(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr: TokenStream2 = attr.into();
+    let item: TokenStream2 = item.into();
+
+    convert_result(hook::expand_hook_at(attr, item))
+}
+
+#[proc_macro]
+pub fn hook_symbol(input: TokenStream) -> TokenStream {
+    let input: TokenStream2 = input.into();
+    convert_result(hook::expand_hook_symbol(input))
+}
+
 #[proc_macro_attribute]
 pub fn geode_main(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let input: TokenStream2 = item.into();
     convert_result(main_::expand_geode_main(input))
 }
 
+#[proc_macro_attribute]
+pub fn on_loaded(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    convert_result(main_::expand_lifecycle("Loaded", item.into()))
+}
+
+#[proc_macro_attribute]
+pub fn on_enabled(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    convert_result(main_::expand_lifecycle("Enabled", item.into()))
+}
+
+#[proc_macro_attribute]
+pub fn on_disabled(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    convert_result(main_::expand_lifecycle("Disabled", item.into()))
+}
+
+#[proc_macro_attribute]
+pub fn on_save(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    convert_result(main_::expand_lifecycle("Save", item.into()))
+}
+
 #[proc_macro]
 pub fn geode_bind(input: TokenStream) -> TokenStream {
     let input: TokenStream2 = input.into();
@@ -1,25 +1,76 @@
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{ItemFn, Result};
 
 pub fn expand_geode_main(input: TokenStream2) -> Result<TokenStream2> {
     let main_fn: ItemFn = syn::parse2(input)?;
+    check_signature(&main_fn, "geode_main")?;
 
     let fn_name = &main_fn.sig.ident;
-    let fn_block = &main_fn.block;
 
+    // `main` may return `()` or any `Result<(), E: Display>`
     let expanded = quote! {
         #[unsafe(no_mangle)]
         pub extern "C" fn geodeImplicitEntry() {
             ::geode_rs::internal::init_mod();
             ::geode_rs::modify::flush_pending_hooks();
-            #fn_name();
+            ::geode_rs::lifecycle::run_main(#fn_name);
         }
 
-        fn #fn_name() {
-            #fn_block
-        }
+        #main_fn
     };
 
     Ok(expanded)
 }
+
+pub fn expand_lifecycle(event: &str, input: TokenStream2) -> Result<TokenStream2> {
+    let callback: ItemFn = syn::parse2(input)?;
+    let attr_name = match event {
+        "Loaded" => "on_loaded",
+        "Enabled" => "on_enabled",
+        "Disabled" => "on_disabled",
+        _ => "on_save",
+    };
+    check_signature(&callback, attr_name)?;
+    if !matches!(callback.sig.output, syn::ReturnType::Default) {
+        return Err(syn::Error::new_spanned(
+            &callback.sig.output,
+            format!("#[{attr_name}] functions cannot return a value"),
+        ));
+    }
+
+    let fn_name = &callback.sig.ident;
+    let name = fn_name.to_string();
+    let event = format_ident!("{}", event);
+    let register_ident = format_ident!("__GEODE_LIFECYCLE_{}", name.to_uppercase());
+
+    Ok(quote! {
+        #callback
+
+        #[used]
+        #[::geode_rs::ctor::ctor(crate_path = ::geode_rs::ctor)]
+        static #register_ident: () = {
+            ::geode_rs::lifecycle::register(
+                ::geode_rs::lifecycle::LifecycleEvent::#event,
+                #name,
+                #fn_name,
+            );
+        };
+    })
+}
+
+fn check_signature(func: &ItemFn, attr_name: &str) -> Result<()> {
+    if !func.sig.inputs.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &func.sig.inputs,
+            format!("#[{attr_name}] functions take no arguments"),
+        ));
+    }
+    if !func.sig.generics.params.is_empty() || func.sig.asyncness.is_some() {
+        return Err(syn::Error::new_spanned(
+            &func.sig,
+            format!("#[{attr_name}] functions must be plain non-generic functions"),
+        ));
+    }
+    Ok(())
+}
@@ -1,7 +1,11 @@
-use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned};
 use syn::parse::{Parse, ParseStream};
-use syn::{FnArg, Ident, Item, ItemImpl, ItemStruct, Path, Result, Type, TypeReference};
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Expr, FnArg, Ident, Item, ItemImpl, ItemStruct, LitBool, LitStr, Path, Result,
+    ReturnType, Type, TypeReference,
+};
 
 struct ModifyArgs {
     class_path: Path,
@@ -64,6 +68,19 @@ fn expand_modify_struct(
         quote!(0)
     };
 
+    let track_instances = if unique {
+        let track_ident = format_ident!("__{}_TRACK_INSTANCES", struct_name_str.to_uppercase());
+        quote! {
+            #[used]
+            #[::geode_rs::ctor::ctor(crate_path = ::geode_rs::ctor)]
+            static #track_ident: () = {
+                ::geode_rs::modify::track_instances(&#storage_ident);
+            };
+        }
+    } else {
+        quote!()
+    };
+
     let expanded = quote! {
         #struct_item
 
@@ -71,17 +88,32 @@ fn expand_modify_struct(
         static #storage_ident: ::geode_rs::modify::ModifyStorage<#struct_name> =
             ::geode_rs::modify::ModifyStorage::new();
 
+        #track_instances
+
         impl #struct_name {
-            pub fn get(this: *mut #class_path) -> Option<&'static mut Self> {
+            // these return None while the fields are borrowed, by a hook further up this
+            // thread's stack or by another thread
+            pub fn get(
+                this: *mut #class_path,
+            ) -> Option<::geode_rs::modify::FieldsGuard<'static, Self>> {
                 #storage_ident.get(#key_expr)
             }
 
-            pub fn get_or_default(this: *mut #class_path) -> &'static mut Self {
+            pub fn get_or_default(
+                this: *mut #class_path,
+            ) -> Option<::geode_rs::modify::FieldsGuard<'static, Self>> {
                 #storage_ident.get_or_default(#key_expr, || Self {
                     #(#field_names: Default::default()),*
                 })
             }
 
+            pub fn with_fields<R>(
+                this: *mut #class_path,
+                f: impl FnOnce(&mut Self) -> R,
+            ) -> Option<R> {
+                Self::get_or_default(this).map(|mut fields| f(&mut fields))
+            }
+
             pub fn free(this: *mut #class_path) {
                 #storage_ident.remove(#key_expr);
             }
@@ -91,7 +123,92 @@ fn expand_modify_struct(
     Ok(expanded)
 }
 
-fn expand_modify_impl(class_path: Path, impl_block: ItemImpl) -> Result<TokenStream2> {
+pub struct HookAttrs {
+    pub order: Option<TokenStream2>,
+    pub enabled: bool,
+    // `Option<PanicAction>` and `Option<u32>` expressions for the hook's `HookSite`
+    pub panic_action: TokenStream2,
+    pub disable_after: TokenStream2,
+}
+
+pub fn parse_hook_attrs(attrs: &[Attribute]) -> Result<HookAttrs> {
+    let mut order: Option<TokenStream2> = None;
+    let mut enabled = true;
+    let mut panic_action = quote!(None);
+    let mut disable_after = quote!(None);
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("hook")) {
+        attr.parse_nested_meta(|meta| {
+            let priority = |name: &str| {
+                let ident = format_ident!("{}", name);
+                quote!(::geode_rs::modify::HookOrder::Priority(::geode_rs::modify::priority::#ident))
+            };
+            if meta.path.is_ident("enabled") {
+                enabled = meta.value()?.parse::<LitBool>()?.value;
+                return Ok(());
+            }
+            if meta.path.is_ident("on_panic") {
+                let action: Ident = meta.value()?.parse()?;
+                let variant = match action.to_string().as_str() {
+                    "original" => quote!(CallOriginal),
+                    "default" => quote!(ReturnDefault),
+                    "abort" => quote!(Abort),
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            action,
+                            "expected `original`, `default` or `abort`",
+                        ));
+                    }
+                };
+                panic_action = quote!(Some(::geode_rs::modify::PanicAction::#variant));
+                return Ok(());
+            }
+            if meta.path.is_ident("disable_after") {
+                let count: syn::LitInt = meta.value()?.parse()?;
+                let count: u32 = count.base10_parse()?;
+                disable_after = quote!(Some(#count));
+                return Ok(());
+            }
+
+            let parsed = if meta.path.is_ident("priority") {
+                let value: Expr = meta.value()?.parse()?;
+                quote!(::geode_rs::modify::HookOrder::Priority(#value))
+            } else if meta.path.is_ident("first") {
+                priority("FIRST")
+            } else if meta.path.is_ident("early") {
+                priority("EARLY")
+            } else if meta.path.is_ident("late") {
+                priority("LATE")
+            } else if meta.path.is_ident("last") {
+                priority("LAST")
+            } else if meta.path.is_ident("before") {
+                let id: LitStr = meta.value()?.parse()?;
+                quote!(::geode_rs::modify::HookOrder::Before(#id.to_string()))
+            } else if meta.path.is_ident("after") {
+                let id: LitStr = meta.value()?.parse()?;
+                quote!(::geode_rs::modify::HookOrder::After(#id.to_string()))
+            } else {
+                return Err(meta.error(
+                    "expected `priority = ..`, `first`, `early`, `late`, `last`, `before = \"mod.id\"`, `after = \"mod.id\"`, `enabled = ..`, `on_panic = ..` or `disable_after = ..`",
+                ));
+            };
+
+            if order.replace(parsed).is_some() {
+                return Err(meta.error("hook ordering is already specified"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(HookAttrs {
+        order,
+        enabled,
+        panic_action,
+        disable_after,
+    })
+}
+
+fn expand_modify_impl(class_path: Path, mut impl_block: ItemImpl) -> Result<TokenStream2> {
     let struct_name = if let syn::Type::Path(path) = &*impl_block.self_ty {
         path.path
             .segments
@@ -105,11 +222,26 @@ fn expand_modify_impl(class_path: Path, impl_block: ItemImpl) -> Result<TokenStr
         ));
     };
 
+    let registry_static_name = format_ident!(
+        "__MODIFY_HOOK_REGISTRY_{}",
+        to_snake_case(&struct_name.to_string()).to_uppercase()
+    );
+
     let mut hook_registrations: Vec<TokenStream2> = Vec::new();
     let mut detour_functions: Vec<TokenStream2> = Vec::new();
+    let mut signature_checks: Vec<TokenStream2> = Vec::new();
+    let mut orig_functions: Vec<TokenStream2> = Vec::new();
 
-    for item in &impl_block.items {
+    for item in &mut impl_block.items {
         if let syn::ImplItem::Fn(method) = item {
+            let hook_attrs = parse_hook_attrs(&method.attrs)?;
+            let order = hook_attrs
+                .order
+                .clone()
+                .unwrap_or_else(|| quote!(::geode_rs::modify::HookOrder::default()));
+            let enabled = hook_attrs.enabled;
+            method.attrs.retain(|attr| !attr.path().is_ident("hook"));
+
             let method_name = &method.sig.ident;
             let method_name_str = method_name.to_string();
 
@@ -139,50 +271,107 @@ fn expand_modify_impl(class_path: Path, impl_block: ItemImpl) -> Result<TokenStr
             let output = &method.sig.output;
             let block = &method.block;
 
-            let (detour_params, call_args) = build_detour_params_and_call_args(
+            // no receiver and no `this` means the hook targets a static function
+            let is_static = !has_self_param && !method.sig.inputs.iter().any(is_this_arg);
+
+            let DetourParams {
+                params: detour_params,
+                call_args,
+                forward_args,
+                types: detour_types,
+                names: detour_names,
+            } = build_detour_params_and_call_args(
                 &method.sig.inputs,
-                &struct_name,
                 &class_path,
                 has_self_param,
+                is_static,
             );
 
-            let detour_func = if has_self_param {
+            let fn_marker = format_ident!("{}_FN", method_name_str.to_uppercase());
+            signature_checks.push(detour_signature_check(
+                &class_path,
+                &fn_marker,
+                &hook_name,
+                &detour_types,
+                output,
+            ));
+
+            // with the fields already borrowed the hook steps aside, as if it were disabled
+            let body = if has_self_param {
+                let types = detour_types.iter().map(|(ty, _)| ty);
+                let ret = match output {
+                    ReturnType::Default => quote!(()),
+                    ReturnType::Type(_, ty) => quote!(#ty),
+                };
                 quote! {
-                    #[allow(clippy::not_unsafe_ptr_arg_deref)]
-                    pub extern "C" fn #detour_func_name(#detour_params) #output {
-                        ::geode_rs::modify::run_hook(#hook_name, || {
-                            #struct_name::#method_name(#call_args)
-                        })
+                    match #struct_name::get_or_default(this) {
+                        Some(mut __fields) => #struct_name::#method_name(#call_args),
+                        None => {
+                            let func: extern "C" fn(#(#types),*) -> #ret =
+                                unsafe { ::std::mem::transmute(#class_path::#addr_const()) };
+                            func(#forward_args)
+                        }
                     }
                 }
             } else {
-                quote! {
-                    #[allow(clippy::not_unsafe_ptr_arg_deref)]
-                    pub extern "C" fn #detour_func_name(#detour_params) #output {
-                        ::geode_rs::modify::run_hook(#hook_name, || {
-                            #block
-                        })
-                    }
+                quote!(#block)
+            };
+            let this = if is_static {
+                quote!(::std::ptr::null())
+            } else {
+                quote!(this as *const ::std::ffi::c_void)
+            };
+            orig_functions.push(orig_function(
+                &method.sig,
+                &class_path,
+                &quote!(#class_path::#addr_const),
+                &hook_name,
+                is_static,
+            ));
+            let detour_body = detour_body(
+                &DetourTarget {
+                    name: &hook_name,
+                    address: quote!(#class_path::#addr_const),
+                    this,
+                    original: quote!(__SITE.address()),
+                },
+                &hook_attrs,
+                &detour_names,
+                &detour_types,
+                output,
+                body,
+            );
+            let detour_func = quote! {
+                #[allow(clippy::not_unsafe_ptr_arg_deref)]
+                pub extern "C" fn #detour_func_name(#detour_params) #output {
+                    #detour_body
                 }
             };
 
             detour_functions.push(detour_func);
 
+            let signature = abstract_signature(&detour_types, output);
             let class_path_inner = class_path.clone();
+            let class_name_str = class_name.to_string();
             let hook_registration = quote! {
                 unsafe {
-                    let addr = #class_path_inner::#addr_const();
-                    if addr == 0 {
-                        #[cfg(target_os = "android")]
-                        ::geode_rs::loader::android_log(
-                            concat!("WARN: address for ", #hook_name, " resolved to 0, skipping hook\0").as_bytes()
-                        );
-                        #[cfg(not(target_os = "android"))]
-                        eprintln!("[geode-rs] WARN: address for {} is 0, skipping hook", #hook_name);
-                    } else {
-                        let detour = #detour_func_name as *mut ::std::ffi::c_void;
-                        let _ = ::geode_rs::modify::register_hook(addr, detour, #hook_name, #convention);
-                    }
+                    ::geode_rs::diagnostics::register_binding(
+                        #class_name_str,
+                        #method_name_str,
+                        #class_path_inner::#addr_const,
+                    );
+                    ::geode_rs::modify::register_hook_resolver(
+                        #class_path_inner::#addr_const,
+                        #detour_func_name as *mut ::std::ffi::c_void,
+                        #hook_name,
+                        #convention,
+                        ::geode_rs::modify::HookOptions {
+                            order: #order,
+                            enabled: #enabled,
+                            registry: Some((&#registry_static_name, #method_name_str)),
+                            signature: #signature,
+                        },
+                    );
                 }
             };
             hook_registrations.push(hook_registration);
@@ -199,6 +388,24 @@ fn expand_modify_impl(class_path: Path, impl_block: ItemImpl) -> Result<TokenStr
 
         #(#detour_functions)*
 
+        #(#signature_checks)*
+
+        #[allow(non_upper_case_globals)]
+        static #registry_static_name: ::geode_rs::modify::HookRegistry =
+            ::geode_rs::modify::HookRegistry::new();
+
+        impl #struct_name {
+            #(#orig_functions)*
+
+            pub fn hooks() -> Vec<(&'static str, ::geode_rs::loader::Hook)> {
+                #registry_static_name.all()
+            }
+
+            pub fn hook(name: &str) -> Option<::geode_rs::loader::Hook> {
+                #registry_static_name.get(name)
+            }
+        }
+
         #[used]
         #[::geode_rs::ctor::ctor(crate_path = ::geode_rs::ctor)]
         static #hooks_static_name: () = {
@@ -209,19 +416,245 @@ fn expand_modify_impl(class_path: Path, impl_block: ItemImpl) -> Result<TokenStr
     Ok(expanded)
 }
 
+// `<method>_orig(this, args..)` calls the hooked address from inside the detour, which goes on
+// to the next hook or the original function and marks this detour as having reached it.
+// Calling the bound method instead works too, but a panic after it runs the original again.
+fn orig_function(
+    sig: &syn::Signature,
+    class_path: &Path,
+    address_fn: &TokenStream2,
+    hook_name: &str,
+    is_static: bool,
+) -> TokenStream2 {
+    let orig_fn_name = format_ident!("{}_orig", sig.ident);
+    let mut params: Vec<TokenStream2> = Vec::new();
+    let mut types: Vec<TokenStream2> = Vec::new();
+    let mut args: Vec<Ident> = Vec::new();
+    if !is_static {
+        params.push(quote!(this: *const #class_path));
+        types.push(quote!(*const #class_path));
+        args.push(format_ident!("this"));
+    }
+    for (index, arg) in sig.inputs.iter().enumerate() {
+        if let FnArg::Typed(pat_type) = arg {
+            if is_this_arg(arg) {
+                continue;
+            }
+            // references are passed as the pointers they are at the ABI level
+            let ty = &pat_type.ty;
+            let arg_name = format_ident!("arg{}", index);
+            params.push(quote!(#arg_name: #ty));
+            types.push(quote!(#ty));
+            args.push(arg_name);
+        }
+    }
+    let this = if is_static {
+        quote!(::std::ptr::null())
+    } else {
+        quote!(this as *const ::std::ffi::c_void)
+    };
+    let ret = match &sig.output {
+        ReturnType::Default => quote!(()),
+        ReturnType::Type(_, ty) => quote!(#ty),
+    };
+
+    quote! {
+        #[allow(dead_code)]
+        pub unsafe fn #orig_fn_name(#(#params),*) -> #ret {
+            let addr = #address_fn();
+            assert!(addr != 0, concat!("address for ", #hook_name, " is 0"));
+            ::geode_rs::modify::note_original(addr, #this);
+            let func: extern "C" fn(#(#types),*) -> #ret =
+                unsafe { ::std::mem::transmute(addr) };
+            func(#(#args),*)
+        }
+    }
+}
+
+// The hooked function a detour runs for, `this` is the object pointer the detour was called
+// with (null for free and static functions). `original` is the address falling back to the
+// original calls, `__SITE.address()` for hooks.
+pub struct DetourTarget<'a> {
+    pub name: &'a str,
+    pub address: TokenStream2,
+    pub this: TokenStream2,
+    pub original: TokenStream2,
+}
+
+// Runs `body` under the hook's panic policy. Falling back to the original calls the hooked
+// address again with copies of the arguments, taken before `body` can move them.
+pub fn detour_body(
+    target: &DetourTarget,
+    hook_attrs: &HookAttrs,
+    names: &[Option<Ident>],
+    types: &[(TokenStream2, Span)],
+    output: &ReturnType,
+    body: TokenStream2,
+) -> TokenStream2 {
+    let DetourTarget {
+        name: hook_name,
+        address: address_fn,
+        this,
+        original,
+    } = target;
+    let panic_action = &hook_attrs.panic_action;
+    let disable_after = &hook_attrs.disable_after;
+    let site = quote! {
+        static __SITE: ::geode_rs::modify::HookSite = ::geode_rs::modify::HookSite::new(
+            #hook_name,
+            #address_fn,
+            #panic_action,
+            #disable_after,
+        );
+    };
+
+    let ret = match output {
+        ReturnType::Default => quote!(()),
+        ReturnType::Type(_, ty) => quote!(#ty),
+    };
+    let names: Option<Vec<&Ident>> = names.iter().map(Option::as_ref).collect();
+    let Some(names) = names else {
+        return quote! {
+            #site
+            ::geode_rs::modify::run_detour(&__SITE, #this, || { #body }, None::<fn() -> #ret>)
+        };
+    };
+    let types = types.iter().map(|(ty, _)| ty);
+    let call_original = quote! {
+        move || {
+            let func: extern "C" fn(#(#types),*) -> #ret =
+                unsafe { ::std::mem::transmute(#original) };
+            func(#(#names),*)
+        }
+    };
+    if names.is_empty() {
+        return quote! {
+            #site
+            ::geode_rs::modify::run_detour(&__SITE, #this, || { #body }, Some(#call_original))
+        };
+    }
+
+    quote! {
+        #site
+        #[allow(unused_imports)]
+        use ::geode_rs::modify::{CopyArg as _, MoveArg as _};
+        let __original = match (#((&&::geode_rs::modify::ArgProbe(&#names)).copied(),)*) {
+            (#(Some(#names),)*) => Some(#call_original),
+            _ => None,
+        };
+        ::geode_rs::modify::run_detour(&__SITE, #this, || { #body }, __original)
+    }
+}
+
+// The tulip `AbstractFunction` for a detour, see `geode_rs::tulip::TypeProbe`
+pub fn abstract_signature(types: &[(TokenStream2, Span)], output: &ReturnType) -> TokenStream2 {
+    let ret = match output {
+        ReturnType::Default => quote!(()),
+        ReturnType::Type(_, ty) => quote!(#ty),
+    };
+    let types = types.iter().map(|(ty, _)| ty);
+    quote! {{
+        #[allow(unused_imports)]
+        use ::geode_rs::tulip::{KnownAbstractType as _, RecordAbstractType as _};
+        ::geode_rs::tulip::AbstractFunction::from_types(
+            (&&::geode_rs::tulip::TypeProbe::<#ret>::new()).abstract_type(),
+            &[#((&&::geode_rs::tulip::TypeProbe::<#types>::new()).abstract_type()),*],
+        )
+    }}
+}
+
+// Checks a detour's parameter and return types against the `*_FN` marker generated for the
+// bound function.
+pub fn detour_signature_check(
+    class_path: &Path,
+    fn_marker: &Ident,
+    hook_name: &str,
+    detour_types: &[(TokenStream2, Span)],
+    output: &ReturnType,
+) -> TokenStream2 {
+    let arity = detour_types.len();
+    // spanned to the offending parameter so the error points at it rather than the attribute
+    let arg_checks = detour_types.iter().enumerate().map(|(index, (ty, span))| {
+        quote_spanned! {*span=>
+            let marker = #class_path::#fn_marker;
+            ::geode_rs::modify::assert_detour_arg::<#index, #ty, _>(marker);
+        }
+    });
+    let ret_check = match output {
+        ReturnType::Default => quote! {
+            ::geode_rs::modify::assert_detour_output::<(), _>(#class_path::#fn_marker);
+        },
+        ReturnType::Type(_, ty) => quote_spanned! {ty.span()=>
+            let marker = #class_path::#fn_marker;
+            ::geode_rs::modify::assert_detour_output::<#ty, _>(marker);
+        },
+    };
+
+    quote! {
+        const _: () = {
+            assert!(
+                ::geode_rs::modify::detour_arity(#class_path::#fn_marker) == #arity,
+                concat!("argument count of ", #hook_name, " does not match the bound function")
+            );
+
+            #[allow(dead_code)]
+            fn check() {
+                #(#arg_checks)*
+                #ret_check
+            }
+        };
+    }
+}
+
+// (detour parameter type, argument passed on to the Rust method)
+pub fn ffi_param(pat: &syn::Pat, ty: &Type) -> (TokenStream2, TokenStream2) {
+    if is_mut_ref_type(ty) {
+        let inner_ty = extract_ref_inner_type(ty);
+        (
+            quote_spanned!(ty.span()=> *mut #inner_ty),
+            quote!(unsafe { &mut *#pat }),
+        )
+    } else if is_const_ref_type(ty) {
+        let inner_ty = extract_ref_inner_type(ty);
+        (
+            quote_spanned!(ty.span()=> *const #inner_ty),
+            quote!(unsafe { &*#pat }),
+        )
+    } else {
+        (quote!(#ty), quote!(#pat))
+    }
+}
+
+struct DetourParams {
+    params: TokenStream2,
+    call_args: TokenStream2,
+    // the detour's own arguments, to pass them on unchanged
+    forward_args: TokenStream2,
+    types: Vec<(TokenStream2, Span)>,
+    names: Vec<Option<Ident>>,
+}
+
 fn build_detour_params_and_call_args(
     inputs: &syn::punctuated::Punctuated<FnArg, syn::Token![,]>,
-    struct_name: &Ident,
     class_path: &Path,
     has_self_param: bool,
-) -> (TokenStream2, TokenStream2) {
+    is_static: bool,
+) -> DetourParams {
     let mut detour_params: Vec<TokenStream2> = Vec::new();
+    let mut detour_types: Vec<(TokenStream2, Span)> = Vec::new();
+    let mut detour_names: Vec<Option<Ident>> = Vec::new();
     let mut call_args: Vec<TokenStream2> = Vec::new();
+    let mut forward_args: Vec<TokenStream2> = Vec::new();
 
-    detour_params.push(quote!(this: *mut #class_path));
+    if !is_static {
+        forward_args.push(quote!(this));
+        detour_params.push(quote!(this: *mut #class_path));
+        detour_types.push((quote!(*mut #class_path), Span::call_site()));
+        detour_names.push(Some(format_ident!("this")));
+    }
 
     if has_self_param {
-        call_args.push(quote!(#struct_name::get_or_default(this)));
+        call_args.push(quote!(&mut *__fields));
     }
 
     let mut found_this = false;
@@ -230,9 +663,7 @@ fn build_detour_params_and_call_args(
             let pat = &pat_type.pat;
             let ty = &pat_type.ty;
 
-            if let syn::Pat::Ident(pat_ident) = &*pat_type.pat
-                && pat_ident.ident == "this"
-            {
+            if is_this_arg(arg) {
                 found_this = true;
                 if is_mut_ref_type(ty) {
                     call_args.push(quote!(unsafe { &mut *this }));
@@ -244,18 +675,12 @@ fn build_detour_params_and_call_args(
                 continue;
             }
 
-            if is_mut_ref_type(ty) {
-                let inner_ty = extract_ref_inner_type(ty);
-                detour_params.push(quote!(#pat: *mut #inner_ty));
-                call_args.push(quote!(unsafe { &mut *#pat }));
-            } else if is_const_ref_type(ty) {
-                let inner_ty = extract_ref_inner_type(ty);
-                detour_params.push(quote!(#pat: *const #inner_ty));
-                call_args.push(quote!(unsafe { &*#pat }));
-            } else {
-                detour_params.push(quote!(#pat: #ty));
-                call_args.push(quote!(#pat));
-            }
+            let (detour_ty, call_arg) = ffi_param(pat, ty);
+            call_args.push(call_arg);
+            forward_args.push(quote!(#pat));
+            detour_params.push(quote!(#pat: #detour_ty));
+            detour_types.push((detour_ty, arg.span()));
+            detour_names.push(pat_ident(pat));
         }
     }
 
@@ -266,7 +691,29 @@ fn build_detour_params_and_call_args(
     let detour_params_stream = quote!(#(#detour_params),*);
     let call_args_stream = quote!(#(#call_args),*);
 
-    (detour_params_stream, call_args_stream)
+    DetourParams {
+        params: detour_params_stream,
+        call_args: call_args_stream,
+        forward_args: quote!(#(#forward_args),*),
+        types: detour_types,
+        names: detour_names,
+    }
+}
+
+// the binding a detour parameter can be referred to by, None for destructuring patterns
+pub fn pat_ident(pat: &syn::Pat) -> Option<Ident> {
+    match pat {
+        syn::Pat::Ident(pat_ident) if pat_ident.subpat.is_none() => Some(pat_ident.ident.clone()),
+        _ => None,
+    }
+}
+
+fn is_this_arg(arg: &FnArg) -> bool {
+    matches!(
+        arg,
+        FnArg::Typed(pat_type)
+            if matches!(&*pat_type.pat, syn::Pat::Ident(pat_ident) if pat_ident.ident == "this")
+    )
 }
 
 fn is_mut_ref_type(ty: &Type) -> bool {
@@ -298,7 +745,7 @@ fn extract_ref_inner_type(ty: &Type) -> &Type {
     }
 }
 
-fn to_snake_case(s: &str) -> String {
+pub fn to_snake_case(s: &str) -> String {
     let mut result = String::with_capacity(s.len());
     let mut chars = s.chars().peekable();
     let mut prev_was_lower = false;
@@ -18,10 +18,11 @@ fn main() -> anyhow::Result<()> {
     geode_codegen::generate(geode_codegen::Config {
         broma_paths: broma_files,
         output_dir: out_path.clone(),
-        platform: None,
         generate_docs: true,
         separate_files: false,
         use_cocos_bindgen,
+        overload_table: Some(broma_dir.join("overloads.txt")),
+        cocos_vtables: Some(broma_dir.join("vtables/Cocos2d.bro")),
     })?;
 
     #[cfg(feature = "bindgen")]
@@ -249,6 +249,11 @@ mod android {
         use std::sync::atomic::Ordering;
         const SENTINEL: usize = usize::MAX;
 
+        if let Some(addr) = crate::symbols::provided_symbol(super::SymbolScope::Process, sym_bytes)
+        {
+            return addr;
+        }
+
         let cached = slot.load(Ordering::Relaxed);
         if cached == SENTINEL {
             return 0;
@@ -284,6 +289,10 @@ mod android {
         use std::sync::atomic::Ordering;
         const SENTINEL: usize = usize::MAX;
 
+        if let Some(addr) = crate::symbols::provided_symbol(super::SymbolScope::Geode, sym_bytes) {
+            return addr;
+        }
+
         let cached = slot.load(Ordering::Relaxed);
         if cached == SENTINEL {
             return 0;
@@ -388,7 +397,7 @@ pub use android::{
     get_geode,
 };
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum SymbolScope {
     Process,
     Geode,
@@ -396,6 +405,34 @@ pub enum SymbolScope {
     Extensions,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Module {
+    Game,
+    Geode,
+    Cocos,
+    Extensions,
+}
+
+impl Module {
+    // cocos2d and its extensions are only separate libraries on Windows, everywhere else
+    // they are linked into the game binary
+    pub fn base(self) -> usize {
+        if let Some(base) = crate::symbols::provided_module_base(self) {
+            return base;
+        }
+        match self {
+            Self::Game => get(),
+            Self::Geode => get_geode(),
+            #[cfg(target_os = "windows")]
+            Self::Cocos => get_cocos(),
+            #[cfg(target_os = "windows")]
+            Self::Extensions => get_extensions(),
+            #[cfg(not(target_os = "windows"))]
+            Self::Cocos | Self::Extensions => get(),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct SymbolResolveError {
     pub owner: &'static str,
@@ -442,6 +479,20 @@ fn load_cached_symbol(
     if addr == 0 { 0 } else { addr }
 }
 
+// A provided address wins over the platform lookup and is never cached, see `crate::symbols`
+#[allow(dead_code)]
+fn resolve_provided_or_cached(
+    scope: SymbolScope,
+    name: &[u8],
+    slot: &std::sync::atomic::AtomicUsize,
+    resolve: impl FnOnce() -> usize,
+) -> usize {
+    match crate::symbols::provided_symbol(scope, name) {
+        Some(addr) => addr,
+        None => load_cached_symbol(slot, resolve),
+    }
+}
+
 #[cfg(not(any(
     target_os = "windows",
     target_os = "macos",
@@ -449,7 +500,7 @@ fn load_cached_symbol(
     target_os = "android"
 )))]
 pub fn get() -> usize {
-    0
+    crate::symbols::provided_module_base(Module::Game).unwrap_or(0)
 }
 
 #[cfg(not(any(
@@ -459,7 +510,7 @@ pub fn get() -> usize {
     target_os = "android"
 )))]
 pub fn get_geode() -> usize {
-    0
+    crate::symbols::provided_module_base(Module::Geode).unwrap_or(0)
 }
 
 #[cfg(not(target_os = "windows"))]
@@ -472,6 +523,10 @@ pub fn resolve_symbol(
     name: &[u8],
     slot: &std::sync::atomic::AtomicUsize,
 ) -> usize {
+    if let Some(addr) = crate::symbols::provided_symbol(scope, name) {
+        return addr;
+    }
+
     #[cfg(target_os = "windows")]
     {
         let modules: &[usize] = match scope {
@@ -519,18 +574,33 @@ pub fn resolve_windows_symbol_abs(
     name: &[u8],
     slot: &std::sync::atomic::AtomicUsize,
 ) -> usize {
-    load_cached_symbol(slot, || {
+    resolve_provided_or_cached(module_scope(module), name, slot, || {
         unsafe { get_proc_address(module, name) }.unwrap_or(0)
     })
 }
 
+#[cfg(target_os = "windows")]
+fn module_scope(module: usize) -> SymbolScope {
+    if module == 0 {
+        SymbolScope::Process
+    } else if module == get_geode() {
+        SymbolScope::Geode
+    } else if module == get_cocos() {
+        SymbolScope::Cocos
+    } else if module == get_extensions() {
+        SymbolScope::Extensions
+    } else {
+        SymbolScope::Process
+    }
+}
+
 #[cfg(target_os = "windows")]
 pub fn resolve_windows_symbol_in_modules_abs(
     modules: &[usize],
     name: &[u8],
     slot: &std::sync::atomic::AtomicUsize,
 ) -> usize {
-    load_cached_symbol(slot, || {
+    resolve_provided_or_cached(SymbolScope::Process, name, slot, || {
         let mut addr = 0;
         for &module in modules {
             if module == 0 {
@@ -548,23 +618,26 @@ pub fn resolve_windows_symbol_in_modules_abs(
 #[cfg(not(target_os = "windows"))]
 pub fn resolve_windows_symbol_abs(
     _module: usize,
-    _name: &[u8],
+    name: &[u8],
     _slot: &std::sync::atomic::AtomicUsize,
 ) -> usize {
-    0
+    crate::symbols::provided_symbol(SymbolScope::Process, name).unwrap_or(0)
 }
 
 #[cfg(not(target_os = "windows"))]
 pub fn resolve_windows_symbol_in_modules_abs(
     _modules: &[usize],
-    _name: &[u8],
+    name: &[u8],
     _slot: &std::sync::atomic::AtomicUsize,
 ) -> usize {
-    0
+    crate::symbols::provided_symbol(SymbolScope::Process, name).unwrap_or(0)
 }
 
 #[cfg(any(target_os = "macos", target_os = "ios"))]
 pub unsafe fn dylib_resolve_sym(name: &[u8]) -> Option<usize> {
+    if let Some(addr) = crate::symbols::provided_symbol(SymbolScope::Process, name) {
+        return Some(addr);
+    }
     unsafe extern "C" {
         fn dlsym(
             handle: *mut std::ffi::c_void,
@@ -581,18 +654,27 @@ pub unsafe fn dylib_resolve_sym(name: &[u8]) -> Option<usize> {
 }
 
 #[cfg(not(any(target_os = "macos", target_os = "ios")))]
-pub unsafe fn dylib_resolve_sym(_name: &[u8]) -> Option<usize> {
-    None
+pub unsafe fn dylib_resolve_sym(name: &[u8]) -> Option<usize> {
+    crate::symbols::provided_symbol(SymbolScope::Process, name)
 }
 
 #[cfg(any(target_os = "macos", target_os = "ios"))]
 pub fn resolve_dylib_symbol_abs(name: &[u8], slot: &std::sync::atomic::AtomicUsize) -> usize {
-    load_cached_symbol(slot, || unsafe { dylib_resolve_sym(name) }.unwrap_or(0))
+    resolve_provided_or_cached(SymbolScope::Process, name, slot, || {
+        unsafe { dylib_resolve_sym(name) }.unwrap_or(0)
+    })
 }
 
 #[cfg(not(any(target_os = "macos", target_os = "ios")))]
-pub fn resolve_dylib_symbol_abs(_name: &[u8], _slot: &std::sync::atomic::AtomicUsize) -> usize {
-    0
+pub fn resolve_dylib_symbol_abs(name: &[u8], _slot: &std::sync::atomic::AtomicUsize) -> usize {
+    crate::symbols::provided_symbol(SymbolScope::Process, name).unwrap_or(0)
+}
+
+// Generated bindings resolve through this on hosts too, against the Itanium symbols the
+// Android builds use, so a `SymbolProvider` can stand in for the game there
+#[cfg(not(target_os = "android"))]
+pub fn android_resolve_symbol_abs(name: &[u8], _slot: &std::sync::atomic::AtomicUsize) -> usize {
+    crate::symbols::provided_symbol(SymbolScope::Process, name).unwrap_or(0)
 }
 
 #[cfg(target_os = "android")]
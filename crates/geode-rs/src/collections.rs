@@ -0,0 +1,871 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::{CStr, c_char, c_int, c_uint, c_void};
+use std::fmt;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::sync::atomic::AtomicUsize;
+
+use crate::base::{SymbolResolveError, SymbolScope, resolve_symbol};
+use crate::classes::{CCArray, CCDictElement, CCDictionary, CCNode, CCObject};
+use crate::diagnostics::unresolved;
+use crate::refs::{CocosObject, Ref, downcast_ptr, is_instance_of};
+use crate::types::GdString;
+
+// cocos2d's `_ccArray`, the buffer `CCArray::data` points to. Reads work on it directly,
+// anything that changes the array goes through cocos.
+#[repr(C)]
+struct RawArray {
+    num: c_uint,
+    max: c_uint,
+    arr: *mut *mut CCObject,
+}
+
+fn raw_array(array: NonNull<CCArray>) -> Option<NonNull<RawArray>> {
+    NonNull::new(unsafe { (*array.as_ptr()).data }.cast())
+}
+
+fn array_len(array: NonNull<CCArray>) -> usize {
+    raw_array(array).map_or(0, |raw| unsafe { raw.as_ref() }.num as usize)
+}
+
+fn array_get(array: NonNull<CCArray>, index: usize) -> Option<NonNull<CCObject>> {
+    let raw = unsafe { raw_array(array)?.as_ref() };
+    if index >= raw.num as usize {
+        return None;
+    }
+    NonNull::new(unsafe { *raw.arr.add(index) })
+}
+
+// CCArray's own functions, which Broma only partly binds. Windows and Android export them
+// from libcocos2d, on macOS and iOS cocos is linked into the game without symbols, so
+// creating or changing arrays fails there with an error.
+struct ArrayFn {
+    name: &'static str,
+    windows: &'static [u8],
+    itanium: &'static [u8],
+    slot: AtomicUsize,
+}
+
+impl ArrayFn {
+    const fn new(name: &'static str, windows: &'static [u8], itanium: &'static [u8]) -> Self {
+        Self {
+            name,
+            windows,
+            itanium,
+            slot: AtomicUsize::new(0),
+        }
+    }
+
+    fn address(&self) -> Result<usize, String> {
+        let symbol = if cfg!(target_os = "windows") {
+            self.windows
+        } else {
+            self.itanium
+        };
+        match resolve_symbol(SymbolScope::Cocos, symbol, &self.slot) {
+            0 => Err(format!("{} isn't available on this platform", self.name)),
+            address => Ok(address),
+        }
+    }
+}
+
+static ARRAY_CREATE: ArrayFn = ArrayFn::new(
+    "CCArray::create",
+    b"?create@CCArray@cocos2d@@SAPEAV12@XZ\0",
+    b"_ZN7cocos2d7CCArray6createEv\0",
+);
+static ARRAY_ADD_OBJECT: ArrayFn = ArrayFn::new(
+    "CCArray::addObject",
+    b"?addObject@CCArray@cocos2d@@QEAAXPEAVCCObject@2@@Z\0",
+    b"_ZN7cocos2d7CCArray9addObjectEPNS_8CCObjectE\0",
+);
+static ARRAY_REMOVE_OBJECT_AT_INDEX: ArrayFn = ArrayFn::new(
+    "CCArray::removeObjectAtIndex",
+    b"?removeObjectAtIndex@CCArray@cocos2d@@QEAAXI_N@Z\0",
+    b"_ZN7cocos2d7CCArray19removeObjectAtIndexEjb\0",
+);
+static ARRAY_REMOVE_ALL_OBJECTS: ArrayFn = ArrayFn::new(
+    "CCArray::removeAllObjects",
+    b"?removeAllObjects@CCArray@cocos2d@@QEAAXXZ\0",
+    b"_ZN7cocos2d7CCArray16removeAllObjectsEv\0",
+);
+
+// A `CCArray` holding `T`s, like `geode::cocos::CCArrayExt`. It keeps the array retained.
+pub struct CCArrayExt<T: CocosObject> {
+    array: Ref<CCArray>,
+    _marker: PhantomData<*mut T>,
+}
+
+impl<T: CocosObject> CCArrayExt<T> {
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    pub fn try_new() -> Result<Self, String> {
+        let create = ARRAY_CREATE.address()?;
+        let array = unsafe {
+            let create: unsafe extern "C" fn() -> *mut CCArray = std::mem::transmute(create);
+            create()
+        };
+        let array = NonNull::new(array).ok_or("CCArray::create returned null")?;
+        Ok(Self {
+            array: Ref::new(array),
+            _marker: PhantomData,
+        })
+    }
+
+    // every element of the array has to be a `T`
+    pub unsafe fn from_array_unchecked(array: Ref<CCArray>) -> Self {
+        Self {
+            array,
+            _marker: PhantomData,
+        }
+    }
+
+    // checks every element, and hands the array back if one of them isn't a `T`. Objects of
+    // an #[inherit] class only count as one once it has installed its vtable.
+    pub fn from_array(array: Ref<CCArray>) -> Result<Self, Ref<CCArray>> {
+        let array_ptr = array.as_non_null();
+        let valid = (0..array_len(array_ptr))
+            .all(|index| array_get(array_ptr, index).is_some_and(is_instance_of::<T>));
+        if valid {
+            Ok(unsafe { Self::from_array_unchecked(array) })
+        } else {
+            Err(array)
+        }
+    }
+
+    pub unsafe fn from_raw(array: *mut CCArray) -> Option<Self> {
+        NonNull::new(array).and_then(|array| Self::from_array(Ref::new(array)).ok())
+    }
+
+    pub fn inner(&self) -> &Ref<CCArray> {
+        &self.array
+    }
+
+    pub fn into_inner(self) -> Ref<CCArray> {
+        self.array
+    }
+
+    pub fn len(&self) -> usize {
+        array_len(self.array.as_non_null())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        array_get(self.array.as_non_null(), index).map(|object| unsafe { object.cast().as_ref() })
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        array_get(self.array.as_non_null(), index).map(|object| unsafe { object.cast().as_mut() })
+    }
+
+    // the element as a `U`, if its RTTI says it is one
+    pub fn get_as<U: CocosObject>(&self, index: usize) -> Option<&U> {
+        let object = array_get(self.array.as_non_null(), index)?;
+        downcast_ptr::<U>(object).map(|object| unsafe { object.as_ref() })
+    }
+
+    pub fn iter(&self) -> CCArrayIter<'_, T> {
+        CCArrayIter::new(Some(self.array.as_non_null()))
+    }
+
+    // the same object can be in an array twice, don't hold on to two of these at once
+    pub fn iter_mut(&mut self) -> CCArrayIterMut<'_, T> {
+        CCArrayIterMut {
+            inner: CCArrayIter::new(Some(self.array.as_non_null())),
+        }
+    }
+
+    // the elements that are `U`, skipping the rest
+    pub fn iter_as<'a, U: CocosObject + 'a>(&'a self) -> impl Iterator<Item = &'a U> {
+        CCArrayIter::<CCObject>::new(Some(self.array.as_non_null())).filter_map(|object| {
+            downcast_ptr::<U>(NonNull::from(object)).map(|object| unsafe { object.as_ref() })
+        })
+    }
+
+    pub fn push(&mut self, object: impl Into<Ref<T>>) {
+        self.try_push(object).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    pub fn try_push(&mut self, object: impl Into<Ref<T>>) -> Result<(), String> {
+        let add_object = ARRAY_ADD_OBJECT.address()?;
+        // the array retains it, the Ref is released afterwards
+        let object = object.into();
+        unsafe {
+            let add_object: unsafe extern "C" fn(*mut CCArray, *mut CCObject) =
+                std::mem::transmute(add_object);
+            add_object(
+                self.array.as_ptr(),
+                T::cc_object(object.as_non_null()).as_ptr(),
+            );
+        }
+        Ok(())
+    }
+
+    pub fn remove(&mut self, index: usize) -> Ref<T> {
+        self.try_remove(index).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    pub fn try_remove(&mut self, index: usize) -> Result<Ref<T>, String> {
+        let len = self.len();
+        if index >= len {
+            return Err(format!(
+                "removal index (is {index}) should be < len (is {len})"
+            ));
+        }
+        let remove_object_at_index = ARRAY_REMOVE_OBJECT_AT_INDEX.address()?;
+        let object = array_get(self.array.as_non_null(), index).expect("null object in CCArray");
+        // retained before the array releases it
+        let object = Ref::new(object.cast());
+        unsafe {
+            let remove_object_at_index: unsafe extern "C" fn(*mut CCArray, c_uint, bool) =
+                std::mem::transmute(remove_object_at_index);
+            remove_object_at_index(self.array.as_ptr(), index as c_uint, true);
+        }
+        Ok(object)
+    }
+
+    pub fn pop(&mut self) -> Option<Ref<T>> {
+        let len = self.len();
+        (len > 0).then(|| self.remove(len - 1))
+    }
+
+    pub fn clear(&mut self) {
+        self.try_clear().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    pub fn try_clear(&mut self) -> Result<(), String> {
+        let remove_all_objects = ARRAY_REMOVE_ALL_OBJECTS.address()?;
+        unsafe {
+            let remove_all_objects: unsafe extern "C" fn(*mut CCArray) =
+                std::mem::transmute(remove_all_objects);
+            remove_all_objects(self.array.as_ptr());
+        }
+        Ok(())
+    }
+}
+
+impl<T: CocosObject> Default for CCArrayExt<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// any array holds `CCObject`s
+impl From<Ref<CCArray>> for CCArrayExt<CCObject> {
+    fn from(array: Ref<CCArray>) -> Self {
+        unsafe { Self::from_array_unchecked(array) }
+    }
+}
+
+impl<T: CocosObject> From<CCArrayExt<T>> for Ref<CCArray> {
+    fn from(array: CCArrayExt<T>) -> Self {
+        array.array
+    }
+}
+
+impl<T: CocosObject, A: Into<Ref<T>>> FromIterator<A> for CCArrayExt<T> {
+    fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend(iter);
+        array
+    }
+}
+
+impl<T: CocosObject, A: Into<Ref<T>>> Extend<A> for CCArrayExt<T> {
+    fn extend<I: IntoIterator<Item = A>>(&mut self, iter: I) {
+        for object in iter {
+            self.push(object);
+        }
+    }
+}
+
+impl<'a, T: CocosObject> IntoIterator for &'a CCArrayExt<T> {
+    type Item = &'a T;
+    type IntoIter = CCArrayIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: CocosObject> IntoIterator for &'a mut CCArrayExt<T> {
+    type Item = &'a mut T;
+    type IntoIter = CCArrayIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: CocosObject> IntoIterator for CCArrayExt<T> {
+    type Item = Ref<T>;
+    type IntoIter = CCArrayIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        CCArrayIntoIter {
+            back: self.len(),
+            front: 0,
+            array: self.array,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: CocosObject> fmt::Debug for CCArrayExt<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter().map(|object| object as *const T))
+            .finish()
+    }
+}
+
+pub struct CCArrayIter<'a, T> {
+    array: Option<NonNull<CCArray>>,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<T> CCArrayIter<'_, T> {
+    fn new(array: Option<NonNull<CCArray>>) -> Self {
+        Self {
+            array,
+            front: 0,
+            back: array.map_or(0, array_len),
+            _marker: PhantomData,
+        }
+    }
+
+    fn next_ptr(&mut self) -> Option<NonNull<T>> {
+        if self.front >= self.back {
+            return None;
+        }
+        let object = array_get(self.array?, self.front)?;
+        self.front += 1;
+        Some(object.cast())
+    }
+
+    fn next_back_ptr(&mut self) -> Option<NonNull<T>> {
+        if self.front >= self.back {
+            return None;
+        }
+        let object = array_get(self.array?, self.back - 1)?;
+        self.back -= 1;
+        Some(object.cast())
+    }
+}
+
+impl<'a, T> Iterator for CCArrayIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_ptr().map(|object| unsafe { object.as_ref() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back.saturating_sub(self.front);
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for CCArrayIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_back_ptr()
+            .map(|object| unsafe { object.as_ref() })
+    }
+}
+
+impl<T> ExactSizeIterator for CCArrayIter<'_, T> {}
+
+impl<T> FusedIterator for CCArrayIter<'_, T> {}
+
+pub struct CCArrayIterMut<'a, T> {
+    inner: CCArrayIter<'a, T>,
+}
+
+impl<'a, T> Iterator for CCArrayIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next_ptr()
+            .map(|object| unsafe { &mut *object.as_ptr() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for CCArrayIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next_back_ptr()
+            .map(|object| unsafe { &mut *object.as_ptr() })
+    }
+}
+
+impl<T> ExactSizeIterator for CCArrayIterMut<'_, T> {}
+
+impl<T> FusedIterator for CCArrayIterMut<'_, T> {}
+
+// hands out retained elements, and keeps the array alive until it's dropped
+pub struct CCArrayIntoIter<T: CocosObject> {
+    array: Ref<CCArray>,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<*mut T>,
+}
+
+impl<T: CocosObject> Iterator for CCArrayIntoIter<T> {
+    type Item = Ref<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let object = array_get(self.array.as_non_null(), self.front)?;
+        self.front += 1;
+        Some(Ref::new(object.cast()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back.saturating_sub(self.front);
+        (len, Some(len))
+    }
+}
+
+impl<T: CocosObject> DoubleEndedIterator for CCArrayIntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let object = array_get(self.array.as_non_null(), self.back - 1)?;
+        self.back -= 1;
+        Some(Ref::new(object.cast()))
+    }
+}
+
+impl<T: CocosObject> ExactSizeIterator for CCArrayIntoIter<T> {}
+
+impl<T: CocosObject> FusedIterator for CCArrayIntoIter<T> {}
+
+impl CCNode {
+    // the children that are `T`, see `is_instance_of`
+    pub fn children<'a, T: CocosObject + 'a>(&'a self) -> impl Iterator<Item = &'a T> {
+        CCArrayIter::<CCObject>::new(NonNull::new(self.p_children)).filter_map(|child| {
+            downcast_ptr::<T>(NonNull::from(child)).map(|child| unsafe { child.as_ref() })
+        })
+    }
+
+    pub fn children_mut<'a, T: CocosObject + 'a>(&'a mut self) -> impl Iterator<Item = &'a mut T> {
+        CCArrayIter::<CCObject>::new(NonNull::new(self.p_children)).filter_map(|child| {
+            downcast_ptr::<T>(NonNull::from(child)).map(|child| unsafe { &mut *child.as_ptr() })
+        })
+    }
+}
+
+// cocos2d's `CCDictElement`, an entry in the dictionary's uthash table
+#[repr(C)]
+struct RawDictElement {
+    str_key: [c_char; 256],
+    int_key: isize,
+    object: *mut CCObject,
+    hh: RawHashHandle,
+}
+
+#[repr(C)]
+struct RawHashHandle {
+    tbl: *mut c_void,
+    prev: *mut c_void,
+    // the next element itself, in insertion order
+    next: *mut c_void,
+    hh_prev: *mut c_void,
+    hh_next: *mut c_void,
+    key: *mut c_void,
+    key_len: c_uint,
+    hash: c_uint,
+}
+
+const DICT_TYPE_UNKNOWN: c_int = 0;
+const DICT_TYPE_STR: c_int = 1;
+const DICT_TYPE_INT: c_int = 2;
+
+fn element_raw<'a>(element: NonNull<CCDictElement>) -> &'a RawDictElement {
+    unsafe { element.cast::<RawDictElement>().as_ref() }
+}
+
+fn first_element(dict: NonNull<CCDictionary>) -> Option<NonNull<CCDictElement>> {
+    NonNull::new(unsafe { (*dict.as_ptr()).p_elements })
+}
+
+fn next_element(element: NonNull<CCDictElement>) -> Option<NonNull<CCDictElement>> {
+    NonNull::new(element_raw(element).hh.next.cast())
+}
+
+// What a `CCDictionary` can be keyed by. Lookups and changes go through the bindings so
+// the hash table stays consistent, iteration walks the elements directly. Lookups take a
+// pointer, `get` only has `&self` while values it handed out earlier may still be borrowed.
+pub trait DictKey: Sized {
+    const DICT_TYPE: c_int;
+
+    fn from_element(element: NonNull<CCDictElement>) -> Self;
+    fn lookup(&self, dict: NonNull<CCDictionary>) -> Option<NonNull<CCObject>>;
+    fn insert(&self, dict: &mut CCDictionary, object: &mut CCObject);
+    fn remove(&self, dict: &mut CCDictionary);
+}
+
+impl DictKey for String {
+    const DICT_TYPE: c_int = DICT_TYPE_STR;
+
+    fn from_element(element: NonNull<CCDictElement>) -> Self {
+        let key = unsafe { CStr::from_ptr(element_raw(element).str_key.as_ptr()) };
+        key.to_string_lossy().into_owned()
+    }
+
+    fn lookup(&self, dict: NonNull<CCDictionary>) -> Option<NonNull<CCObject>> {
+        let key = GdString::from(self.as_str());
+        object_for_key(
+            dict,
+            CCDictionary::OBJECT_FOR_KEY_WITH_STRING_ADDR(),
+            "object_for_key_with_string",
+            &key as *const GdString,
+        )
+    }
+
+    fn insert(&self, dict: &mut CCDictionary, object: &mut CCObject) {
+        dict.set_object_with_object_string(object, self);
+    }
+
+    fn remove(&self, dict: &mut CCDictionary) {
+        dict.remove_object_for_key_with_string(self);
+    }
+}
+
+impl DictKey for isize {
+    const DICT_TYPE: c_int = DICT_TYPE_INT;
+
+    fn from_element(element: NonNull<CCDictElement>) -> Self {
+        element_raw(element).int_key
+    }
+
+    fn lookup(&self, dict: NonNull<CCDictionary>) -> Option<NonNull<CCObject>> {
+        object_for_key(
+            dict,
+            CCDictionary::OBJECT_FOR_KEY_WITH_INTPTR_T_ADDR(),
+            "object_for_key_with_intptr_t",
+            *self,
+        )
+    }
+
+    fn insert(&self, dict: &mut CCDictionary, object: &mut CCObject) {
+        dict.set_object_with_object_intptr_t(object, *self);
+    }
+
+    fn remove(&self, dict: &mut CCDictionary) {
+        dict.remove_object_for_key_with_intptr_t(*self);
+    }
+}
+
+fn object_for_key<A>(
+    dict: NonNull<CCDictionary>,
+    address: usize,
+    name: &'static str,
+    key: A,
+) -> Option<NonNull<CCObject>> {
+    if address == 0 {
+        panic!(
+            "{}",
+            unresolved(SymbolResolveError::new("CCDictionary", name))
+        );
+    }
+    let object_for_key: extern "C" fn(*mut CCDictionary, A) -> *mut CCObject =
+        unsafe { std::mem::transmute(address) };
+    NonNull::new(object_for_key(dict.as_ptr(), key))
+}
+
+// A `CCDictionary` from `K` to `V`, like `geode::cocos::CCDictionaryExt`. It keeps the
+// dictionary retained.
+pub struct CCDictionaryExt<K: DictKey, V: CocosObject> {
+    dict: Ref<CCDictionary>,
+    _marker: PhantomData<(K, *mut V)>,
+}
+
+impl<K: DictKey, V: CocosObject> CCDictionaryExt<K, V> {
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    pub fn try_new() -> Result<Self, String> {
+        let dict = CCDictionary::try_resolve_create().map_err(|err| err.to_string())?;
+        let dict = NonNull::new(dict).ok_or("CCDictionary::create returned null")?;
+        Ok(Self {
+            dict: Ref::new(dict),
+            _marker: PhantomData,
+        })
+    }
+
+    // the dictionary has to be keyed by `K` and every value has to be a `V`
+    pub unsafe fn from_dictionary_unchecked(dict: Ref<CCDictionary>) -> Self {
+        Self {
+            dict,
+            _marker: PhantomData,
+        }
+    }
+
+    // checks the key type and every value, and hands the dictionary back on a mismatch
+    pub fn from_dictionary(dict: Ref<CCDictionary>) -> Result<Self, Ref<CCDictionary>> {
+        let dict_type = dict.e_dict_type;
+        if dict_type != DICT_TYPE_UNKNOWN && dict_type != K::DICT_TYPE {
+            return Err(dict);
+        }
+        let valid = Elements::new(Some(dict.as_non_null())).all(|element| {
+            NonNull::new(element_raw(element).object).is_some_and(is_instance_of::<V>)
+        });
+        if valid {
+            Ok(unsafe { Self::from_dictionary_unchecked(dict) })
+        } else {
+            Err(dict)
+        }
+    }
+
+    pub unsafe fn from_raw(dict: *mut CCDictionary) -> Option<Self> {
+        NonNull::new(dict).and_then(|dict| Self::from_dictionary(Ref::new(dict)).ok())
+    }
+
+    pub fn inner(&self) -> &Ref<CCDictionary> {
+        &self.dict
+    }
+
+    pub fn into_inner(self) -> Ref<CCDictionary> {
+        self.dict
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        first_element(self.dict.as_non_null()).is_none()
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        key.lookup(self.dict.as_non_null())
+            .map(|object| unsafe { object.cast().as_ref() })
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        key.lookup(self.dict.as_non_null())
+            .map(|object| unsafe { &mut *object.cast().as_ptr() })
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        key.lookup(self.dict.as_non_null()).is_some()
+    }
+
+    // the value as a `U`, if its RTTI says it is one
+    pub fn get_as<U: CocosObject>(&self, key: &K) -> Option<&U> {
+        let object = key.lookup(self.dict.as_non_null())?;
+        downcast_ptr::<U>(object).map(|object| unsafe { object.as_ref() })
+    }
+
+    // replaces and releases whatever was under `key`
+    pub fn insert(&mut self, key: K, value: impl Into<Ref<V>>) {
+        let value = value.into();
+        let object = unsafe { V::cc_object(value.as_non_null()).as_mut() };
+        key.insert(self.dict_mut(), object);
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<Ref<V>> {
+        let object = key.lookup(self.dict.as_non_null())?;
+        // keep it alive past the dictionary's release
+        let value = Ref::new(object.cast());
+        key.remove(self.dict_mut());
+        Some(value)
+    }
+
+    pub fn clear(&mut self) {
+        self.dict_mut().remove_all_objects();
+    }
+
+    pub fn iter(&self) -> CCDictionaryIter<'_, K, V> {
+        CCDictionaryIter {
+            elements: self.elements(),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> CCDictionaryIterMut<'_, K, V> {
+        CCDictionaryIterMut {
+            elements: self.elements(),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = K> {
+        self.elements().map(K::from_element)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    // changes go through `&mut self`, which also holds back every value borrowed from it
+    fn dict_mut(&mut self) -> &mut CCDictionary {
+        unsafe { self.dict.as_mut() }
+    }
+
+    fn elements(&self) -> Elements {
+        Elements::new(Some(self.dict.as_non_null()))
+    }
+}
+
+impl<K: DictKey, V: CocosObject> Default for CCDictionaryExt<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: CocosObject, K: DictKey, A: Into<Ref<V>>> FromIterator<(K, A)> for CCDictionaryExt<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, A)>>(iter: I) -> Self {
+        let mut dict = Self::new();
+        dict.extend(iter);
+        dict
+    }
+}
+
+impl<V: CocosObject, K: DictKey, A: Into<Ref<V>>> Extend<(K, A)> for CCDictionaryExt<K, V> {
+    fn extend<I: IntoIterator<Item = (K, A)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, K: DictKey, V: CocosObject> IntoIterator for &'a CCDictionaryExt<K, V> {
+    type Item = (K, &'a V);
+    type IntoIter = CCDictionaryIter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K: DictKey, V: CocosObject> IntoIterator for &'a mut CCDictionaryExt<K, V> {
+    type Item = (K, &'a mut V);
+    type IntoIter = CCDictionaryIterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K: DictKey, V: CocosObject> IntoIterator for CCDictionaryExt<K, V> {
+    type Item = (K, Ref<V>);
+    type IntoIter = CCDictionaryIntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        CCDictionaryIntoIter {
+            elements: self.elements(),
+            _dict: self.dict,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K: DictKey + fmt::Debug, V: CocosObject> fmt::Debug for CCDictionaryExt<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.iter().map(|(key, value)| (key, value as *const V)))
+            .finish()
+    }
+}
+
+// walks the uthash chain from `p_elements`
+struct Elements {
+    next: Option<NonNull<CCDictElement>>,
+}
+
+impl Elements {
+    fn new(dict: Option<NonNull<CCDictionary>>) -> Self {
+        Self {
+            next: dict.and_then(first_element),
+        }
+    }
+}
+
+impl Iterator for Elements {
+    type Item = NonNull<CCDictElement>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.next?;
+        self.next = next_element(element);
+        Some(element)
+    }
+}
+
+impl FusedIterator for Elements {}
+
+fn element_value<V>(element: NonNull<CCDictElement>) -> Option<NonNull<V>> {
+    NonNull::new(element_raw(element).object.cast())
+}
+
+pub struct CCDictionaryIter<'a, K, V> {
+    elements: Elements,
+    _marker: PhantomData<(K, &'a V)>,
+}
+
+impl<'a, K: DictKey, V> Iterator for CCDictionaryIter<'a, K, V> {
+    type Item = (K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.elements.next()?;
+        let value = element_value::<V>(element)?;
+        Some((K::from_element(element), unsafe { value.as_ref() }))
+    }
+}
+
+impl<K: DictKey, V> FusedIterator for CCDictionaryIter<'_, K, V> {}
+
+pub struct CCDictionaryIterMut<'a, K, V> {
+    elements: Elements,
+    _marker: PhantomData<(K, &'a mut V)>,
+}
+
+impl<'a, K: DictKey, V> Iterator for CCDictionaryIterMut<'a, K, V> {
+    type Item = (K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.elements.next()?;
+        let value = element_value::<V>(element)?;
+        Some((K::from_element(element), unsafe { &mut *value.as_ptr() }))
+    }
+}
+
+impl<K: DictKey, V> FusedIterator for CCDictionaryIterMut<'_, K, V> {}
+
+// hands out retained values, and keeps the dictionary alive until it's dropped
+pub struct CCDictionaryIntoIter<K: DictKey, V: CocosObject> {
+    elements: Elements,
+    _dict: Ref<CCDictionary>,
+    _marker: PhantomData<(K, *mut V)>,
+}
+
+impl<K: DictKey, V: CocosObject> Iterator for CCDictionaryIntoIter<K, V> {
+    type Item = (K, Ref<V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.elements.next()?;
+        let value = element_value::<V>(element)?;
+        Some((K::from_element(element), Ref::new(value)))
+    }
+}
+
+impl<K: DictKey, V: CocosObject> FusedIterator for CCDictionaryIntoIter<K, V> {}
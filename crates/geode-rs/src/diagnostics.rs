@@ -0,0 +1,178 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::sync::Mutex;
+
+use crate::base::SymbolResolveError;
+
+#[derive(Clone, Copy)]
+struct Binding {
+    owner: &'static str,
+    function: &'static str,
+    resolver: fn() -> usize,
+}
+
+static BINDINGS: Mutex<Vec<Binding>> = Mutex::new(Vec::new());
+static UNRESOLVED: Mutex<Vec<SymbolResolveError>> = Mutex::new(Vec::new());
+
+// Called from the statics the hook macros expand to, for every binding the mod hooks
+pub fn register_binding(owner: &'static str, function: &'static str, resolver: fn() -> usize) {
+    BINDINGS.lock().unwrap().push(Binding {
+        owner,
+        function,
+        resolver,
+    });
+}
+
+// Called by the generated `try_resolve_*` wrappers when their address is 0
+pub fn unresolved(error: SymbolResolveError) -> SymbolResolveError {
+    let mut unresolved = UNRESOLVED.lock().unwrap();
+    if !unresolved.contains(&error) {
+        unresolved.push(error);
+    }
+    error
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BindingReport {
+    pub checked: usize,
+    // keyed by class, free functions are under ""
+    pub unresolved: BTreeMap<&'static str, Vec<SymbolResolveError>>,
+}
+
+impl BindingReport {
+    fn add(&mut self, error: SymbolResolveError) {
+        let errors = self.unresolved.entry(error.owner).or_default();
+        if !errors.contains(&error) {
+            errors.push(error);
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.unresolved.is_empty()
+    }
+
+    pub fn unresolved_count(&self) -> usize {
+        self.unresolved.values().map(Vec::len).sum()
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &SymbolResolveError> {
+        self.unresolved.values().flatten()
+    }
+
+    pub fn merge(mut self, other: BindingReport) -> Self {
+        self.checked += other.checked;
+        for error in other.errors() {
+            self.add(*error);
+        }
+        self
+    }
+
+    // to Geode's log, where stderr would go nowhere on Windows and Android
+    pub fn log(&self) {
+        if self.is_ok() {
+            return;
+        }
+        crate::loader::log::error(&self.to_string());
+    }
+
+    // Fails the mod like an `Err` from #[geode_main] would, through
+    // `lifecycle::report_load_error`, so a broken binding shows up at load time instead of as
+    // a crash in the middle of a level
+    pub fn report_load_error(&self) -> bool {
+        if self.is_ok() {
+            return false;
+        }
+        crate::lifecycle::report_load_error(self);
+        true
+    }
+}
+
+impl fmt::Display for BindingReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_ok() {
+            return write!(f, "all {} checked bindings resolved", self.checked);
+        }
+
+        write!(
+            f,
+            "{} of {} checked bindings failed to resolve:",
+            self.unresolved_count(),
+            self.checked
+        )?;
+        for (owner, errors) in &self.unresolved {
+            let owner = if owner.is_empty() {
+                "free functions"
+            } else {
+                owner
+            };
+            let functions = errors
+                .iter()
+                .map(|error| error.function)
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "\n  {owner}: {functions}")?;
+        }
+        Ok(())
+    }
+}
+
+// Resolves every hooked binding and reports the `try_resolve_*` wrappers that failed so far.
+// Bindings the mod never hooks or calls are left alone, `check_classes` checks those up front.
+// Call it from #[geode_main] and hand the report to `log` or `report_load_error`.
+pub fn check_bindings() -> BindingReport {
+    let mut report = BindingReport::default();
+    let mut checked = HashSet::new();
+
+    // copied out so resolvers don't run with the lock held
+    let bindings = BINDINGS.lock().unwrap().clone();
+    for binding in &bindings {
+        if checked.insert((binding.owner, binding.function)) {
+            report.checked += 1;
+        }
+        if (binding.resolver)() == 0 {
+            report.add(SymbolResolveError::new(binding.owner, binding.function));
+        }
+    }
+
+    let unresolved = UNRESOLVED.lock().unwrap().clone();
+    for error in unresolved {
+        if checked.insert((error.owner, error.function)) {
+            report.checked += 1;
+        }
+        report.add(error);
+    }
+    report
+}
+
+// Resolves every function of the given classes this platform has an address or symbol for,
+// for mods that call into a class without hooking it
+pub fn check_classes(classes: &[&str]) -> BindingReport {
+    let mut report = BindingReport::default();
+    for name in classes {
+        if let Some(class) = crate::reflect::class(name) {
+            check_class(&mut report, class);
+        }
+    }
+    report
+}
+
+// `check_classes` over every generated class, thousands of bindings most mods never touch
+pub fn check_all_classes() -> BindingReport {
+    let mut report = BindingReport::default();
+    for class in crate::reflect::classes() {
+        check_class(&mut report, class);
+    }
+    report
+}
+
+fn check_class(report: &mut BindingReport, class: &crate::reflect::ClassInfo) {
+    for function in class.functions.iter().filter(|function| function.bound) {
+        report.checked += 1;
+        if function.address() == 0 {
+            report.add(SymbolResolveError::new(
+                class.short_name(),
+                function.rust_name,
+            ));
+        }
+    }
+}
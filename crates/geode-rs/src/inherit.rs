@@ -34,6 +34,13 @@ impl<T> Obj<T> {
             ptr: self.ptr.cast(),
         }
     }
+
+    pub fn as_protocol<P>(&mut self) -> &mut P
+    where
+        T: Implements<P>,
+    {
+        unsafe { T::protocol_ptr(self.ptr).as_mut() }
+    }
 }
 
 impl<T> Deref for Obj<T> {
@@ -177,6 +184,150 @@ pub unsafe fn clone_primary_vtable<T>(this: *const T, slots: usize) -> Box<[*mut
     try_clone_primary_vtable(this, slots).unwrap_or_else(|| Box::new([]))
 }
 
+// entries before the address point: the RTTI locator on MSVC, offset-to-top and typeinfo
+// on the Itanium ABI
+#[cfg(target_os = "windows")]
+const VTABLE_PREFIX: usize = 1;
+#[cfg(not(target_os = "windows"))]
+const VTABLE_PREFIX: usize = 2;
+
+pub struct VirtualOverride {
+    pub name: &'static str,
+    pub base_address: usize,
+    pub detour: *mut c_void,
+    // None when the bindings don't know the slot on this platform
+    pub slot: Option<usize>,
+}
+
+pub struct OverrideVtable {
+    entries: Box<[*mut c_void]>,
+    originals: Vec<usize>,
+}
+
+unsafe impl Send for OverrideVtable {}
+unsafe impl Sync for OverrideVtable {}
+
+impl OverrideVtable {
+    // Clones the object's primary vtable and patches one slot per override. The length and the
+    // slots come from the generated `VTABLE_SLOTS`/`<NAME>_SLOT` consts, which are None for the
+    // few classes neither Broma file describes, those need explicit `vtable_slots = ..` and
+    // `slot = ..`. Secondary vtables are left alone, overriding their virtuals doesn't compile.
+    pub fn build<T>(
+        this: NonNull<T>,
+        class: &str,
+        slots: Option<usize>,
+        overrides: &[VirtualOverride],
+    ) -> Result<Self, String> {
+        let slots = slots.ok_or_else(|| {
+            format!("the vtable length of {class} isn't known, specify `vtable_slots = ..`")
+        })?;
+        let vtable = try_primary_vtable(this).ok_or_else(|| "object has no vtable".to_owned())?;
+        let mut entries = Vec::with_capacity(VTABLE_PREFIX + slots);
+        for i in 0..VTABLE_PREFIX + slots {
+            entries.push(unsafe { *vtable.as_ptr().sub(VTABLE_PREFIX).add(i) });
+        }
+
+        let mut originals = Vec::with_capacity(overrides.len());
+        for entry in overrides {
+            let slot = match entry.slot {
+                Some(slot) if slot < slots => slot,
+                Some(slot) => {
+                    return Err(format!(
+                        "slot {slot} of {} is outside the {slots} cloned vtable slots",
+                        entry.name
+                    ));
+                }
+                None => {
+                    return Err(format!(
+                        "the vtable slot of {} isn't known, specify `slot = ..`",
+                        entry.name
+                    ));
+                }
+            };
+            let original = entries[VTABLE_PREFIX + slot] as usize;
+            // an identical-code-folded base implementation can sit at another address, so
+            // this only catches layouts that went wrong in debug builds
+            debug_assert!(
+                entry.base_address == 0 || original == entry.base_address,
+                "slot {slot} of {class} holds {original:#x}, not {} at {:#x}",
+                entry.name,
+                entry.base_address
+            );
+            originals.push(original);
+            entries[VTABLE_PREFIX + slot] = entry.detour;
+        }
+
+        Ok(Self {
+            entries: entries.into_boxed_slice(),
+            originals,
+        })
+    }
+
+    pub fn install<T>(&self, this: NonNull<T>) {
+        let vtable = unsafe { self.entries.as_ptr().add(VTABLE_PREFIX).cast_mut() };
+        try_set_primary_vtable(this, NonNull::new(vtable).expect("vtable is never null"));
+    }
+
+    pub fn is_installed_on<T>(&self, this: NonNull<T>) -> bool {
+        try_primary_vtable(this).is_some_and(|vtable| {
+            std::ptr::eq(vtable.as_ptr(), unsafe {
+                self.entries.as_ptr().add(VTABLE_PREFIX)
+            })
+        })
+    }
+
+    // the function that occupied the slot of the `index`th override before patching
+    pub fn original(&self, index: usize) -> usize {
+        self.originals[index]
+    }
+}
+
+// Implemented by #[inherit(.., implements(P))] for each protocol, `OFFSET` being where the
+// protocol's vtable pointer lives in the object.
+pub unsafe trait Implements<P>: Sized {
+    const OFFSET: usize;
+
+    fn protocol_ptr(this: NonNull<Self>) -> NonNull<P> {
+        unsafe { this.byte_add(Self::OFFSET).cast() }
+    }
+}
+
+pub struct ProtocolVtable {
+    entries: Box<[*mut c_void]>,
+}
+
+unsafe impl Send for ProtocolVtable {}
+unsafe impl Sync for ProtocolVtable {}
+
+impl ProtocolVtable {
+    // The RTTI entry is shared with the primary vtable. On Windows its complete object locator
+    // still describes offset 0, so dynamic_cast through a protocol pointer is unreliable there.
+    pub fn build<T>(this: NonNull<T>, offset: usize, functions: Vec<*mut c_void>) -> Self {
+        let rtti = try_primary_vtable(this).map_or(std::ptr::null_mut(), |vtable| unsafe {
+            *vtable.as_ptr().sub(1)
+        });
+
+        let mut entries = Vec::with_capacity(VTABLE_PREFIX + functions.len());
+        #[cfg(not(target_os = "windows"))]
+        entries.push((offset as isize).wrapping_neg() as *mut c_void);
+        #[cfg(target_os = "windows")]
+        let _ = offset;
+        entries.push(rtti);
+        entries.extend(functions);
+
+        Self {
+            entries: entries.into_boxed_slice(),
+        }
+    }
+
+    pub fn install<T>(&self, this: NonNull<T>, offset: usize) {
+        unsafe {
+            let slot = this.byte_add(offset).cast::<*const *mut c_void>();
+            slot.write(self.entries.as_ptr().add(VTABLE_PREFIX));
+        }
+    }
+}
+
 fn resolve_cxx_operator_new() -> usize {
     resolve_runtime_symbol(CXX_OPERATOR_NEW, &CXX_OPERATOR_NEW_ADDR)
 }
@@ -1,11 +1,19 @@
 pub mod base;
+pub mod collections;
 pub mod convention;
+pub mod diagnostics;
 pub mod fmod;
 pub mod geode_utils;
 pub mod inherit;
+pub mod lifecycle;
 pub mod loader;
 pub mod modify;
+pub mod patch;
+pub mod reflect;
+pub mod refs;
+pub mod scan;
 pub mod stl;
+pub mod symbols;
 pub mod tulip;
 
 #[cfg(target_os = "android")]
@@ -16,14 +24,20 @@ unsafe extern "C" {}
 unsafe extern "C" {}
 
 pub use base::*;
+pub use collections::*;
 pub use convention::*;
 pub use geode_utils::*;
 pub use inherit::*;
 pub use loader::*;
 pub use modify::*;
+pub use patch::*;
+pub use refs::*;
 pub use tulip::*;
 
-pub use geode_macros::{geode_bind, geode_main, inherit, modify};
+pub use geode_macros::{
+    geode_bind, geode_main, hook_at, hook_fn, hook_symbol, inherit, modify, on_disabled,
+    on_enabled, on_loaded, on_save,
+};
 
 pub use ctor;
 
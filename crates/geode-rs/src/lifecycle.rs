@@ -0,0 +1,225 @@
+use std::ffi::c_void;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{Mutex, Once, OnceLock};
+
+use crate::CallingConvention;
+use crate::classes::{AppDelegate, CCScheduler};
+use crate::loader::{Loader, LoadingState, Mod, NotificationIcon, log, notification};
+use crate::modify::{HookOptions, register_hook_with_options, resolve_deferred_orders, run_hook};
+use crate::tulip::{AbstractFunction, AbstractType};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LifecycleEvent {
+    // every mod finished loading
+    Loaded,
+    // the mod was toggled in the mods list
+    Enabled,
+    Disabled,
+    // the game is saving, mod data included
+    Save,
+}
+
+struct Callback {
+    event: LifecycleEvent,
+    name: &'static str,
+    func: fn(),
+}
+
+static CALLBACKS: Mutex<Vec<Callback>> = Mutex::new(Vec::new());
+static LOAD_ERROR: OnceLock<String> = OnceLock::new();
+static STARTED: AtomicBool = AtomicBool::new(false);
+static LOADED_DISPATCHED: AtomicBool = AtomicBool::new(false);
+static SCHEDULER_HOOK: Once = Once::new();
+static SAVE_HOOK: Once = Once::new();
+
+const ENABLED_UNKNOWN: u8 = 0;
+const ENABLED_NO: u8 = 1;
+const ENABLED_YES: u8 = 2;
+static LAST_ENABLED: AtomicU8 = AtomicU8::new(ENABLED_UNKNOWN);
+
+// Called from the statics #[on_loaded], #[on_enabled], #[on_disabled] and #[on_save] expand
+// to, before geodeImplicitEntry flushes the hooks that drive them.
+pub fn register(event: LifecycleEvent, name: &'static str, func: fn()) {
+    CALLBACKS
+        .lock()
+        .unwrap()
+        .push(Callback { event, name, func });
+
+    match event {
+        LifecycleEvent::Loaded | LifecycleEvent::Enabled | LifecycleEvent::Disabled => {
+            watch_scheduler();
+        }
+        LifecycleEvent::Save => {
+            SAVE_HOOK.call_once(|| unsafe {
+                register_hook_with_options(
+                    AppDelegate::TRY_SAVE_GAME_ADDR(),
+                    try_save_game_detour as *mut c_void,
+                    "AppDelegate::trySaveGame",
+                    CallingConvention::Default,
+                    HookOptions {
+                        signature: AbstractFunction::from_types(
+                            AbstractType::VOID,
+                            &[
+                                AbstractType::of::<*mut AppDelegate>(),
+                                AbstractType::of::<bool>(),
+                            ],
+                        ),
+                        ..HookOptions::default()
+                    },
+                );
+            });
+        }
+    }
+}
+
+// The per-frame hook that polls the loader, also used by `modify` to re-resolve hook
+// orderings against mods that load after this one.
+pub(crate) fn watch_scheduler() {
+    SCHEDULER_HOOK.call_once(|| unsafe {
+        register_hook_with_options(
+            CCScheduler::UPDATE_ADDR(),
+            scheduler_update_detour as *mut c_void,
+            "cocos2d::CCScheduler::update",
+            CallingConvention::Default,
+            HookOptions {
+                signature: AbstractFunction::from_types(
+                    AbstractType::VOID,
+                    &[
+                        AbstractType::of::<*mut CCScheduler>(),
+                        AbstractType::of::<f32>(),
+                    ],
+                ),
+                ..HookOptions::default()
+            },
+        );
+    });
+}
+
+pub fn dispatch(event: LifecycleEvent) {
+    // copied out so callbacks can register more callbacks
+    let callbacks: Vec<(&'static str, fn())> = CALLBACKS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|callback| callback.event == event)
+        .map(|callback| (callback.name, callback.func))
+        .collect();
+    for (name, func) in callbacks {
+        run_hook(name, func);
+    }
+}
+
+pub trait MainOutput {
+    fn into_load_result(self) -> Result<(), String>;
+}
+
+impl MainOutput for () {
+    fn into_load_result(self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+impl<E: Display> MainOutput for Result<(), E> {
+    fn into_load_result(self) -> Result<(), String> {
+        self.map_err(|err| err.to_string())
+    }
+}
+
+pub fn run_main<R: MainOutput>(main: fn() -> R) {
+    match std::panic::catch_unwind(main) {
+        Ok(output) => match output.into_load_result() {
+            Ok(()) => start(),
+            Err(err) => report_load_error(err),
+        },
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic payload".to_owned());
+            report_load_error(format!("geode_main panicked: {message}"));
+        }
+    }
+}
+
+// Runs once #[geode_main] returned successfully, events before that are never dispatched.
+pub fn start() {
+    if LOAD_ERROR.get().is_some() {
+        return;
+    }
+    STARTED.store(true, Ordering::Release);
+    poll_state();
+}
+
+// Load problems are only added by the loader itself (`LoaderImpl::addProblem` isn't exported),
+// so a failed #[geode_main] goes to Geode's log and a notification instead. The mod's hooks
+// and patches are switched off and no lifecycle events follow.
+pub fn report_load_error(error: impl Display) {
+    let message = error.to_string();
+    log::error(&format!("mod failed to load: {message}"));
+
+    let name = Mod::get().map_or_else(|| "A mod".to_owned(), Mod::name);
+    if let Err(err) = notification::show(
+        &format!("{name} failed to load: {message}"),
+        NotificationIcon::Error,
+        notification::DEFAULT_TIME * 4.0,
+    ) {
+        log::warn(&format!("couldn't show the load error notification: {err}"));
+    }
+
+    if let Some(current) = Mod::get() {
+        for hook in current.get_hooks() {
+            let _ = hook.disable();
+        }
+        for patch in current.get_patches() {
+            let _ = patch.disable();
+        }
+    }
+    let _ = LOAD_ERROR.set(message);
+}
+
+pub fn load_error() -> Option<&'static str> {
+    LOAD_ERROR.get().map(String::as_str)
+}
+
+fn poll_state() {
+    if !STARTED.load(Ordering::Acquire) {
+        return;
+    }
+
+    if !LOADED_DISPATCHED.load(Ordering::Relaxed)
+        && Loader::get().is_some_and(|loader| loader.loading_state() == LoadingState::Done)
+    {
+        LOADED_DISPATCHED.store(true, Ordering::Relaxed);
+        dispatch(LifecycleEvent::Loaded);
+    }
+
+    let Some(current) = Mod::get() else {
+        return;
+    };
+    let enabled = if current.is_or_will_be_enabled() {
+        ENABLED_YES
+    } else {
+        ENABLED_NO
+    };
+    match LAST_ENABLED.swap(enabled, Ordering::Relaxed) {
+        ENABLED_UNKNOWN => {}
+        previous if previous == enabled => {}
+        _ if enabled == ENABLED_YES => dispatch(LifecycleEvent::Enabled),
+        _ => dispatch(LifecycleEvent::Disabled),
+    }
+}
+
+extern "C" fn scheduler_update_detour(this: *mut CCScheduler, dt: f32) {
+    resolve_deferred_orders();
+    poll_state();
+    unsafe { (*this).update(dt) };
+}
+
+extern "C" fn try_save_game_detour(this: *mut AppDelegate, force: bool) {
+    if STARTED.load(Ordering::Acquire) {
+        dispatch(LifecycleEvent::Save);
+    }
+    unsafe { (*this).try_save_game(force) };
+}
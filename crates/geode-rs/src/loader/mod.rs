@@ -5,6 +5,7 @@
 )]
 
 mod raw;
+mod typed;
 mod types;
 
 use std::ffi::c_void;
@@ -13,7 +14,8 @@ use std::sync::OnceLock;
 
 use crate::CallingConvention;
 use crate::stl::{StlOptional, StlPath, StlSharedPtr, StlSpan, StlString, StlVector};
-use crate::tulip::{HandlerMetadata, HookMetadata, TulipConvention};
+use crate::tulip::{AbstractFunction, HandlerMetadata, HookMetadata, TulipConvention};
+pub use typed::*;
 pub use types::*;
 
 pub type LoaderResult<T> = Result<T, String>;
@@ -88,6 +90,7 @@ impl Hook {
         name: &str,
         convention: CallingConvention,
         priority: i32,
+        signature: AbstractFunction,
     ) -> LoaderResult<Self> {
         let tulip_conv = TulipConvention::from(convention);
         let conv_ptr = unsafe { raw::create_convention(tulip_conv as i32) }
@@ -97,7 +100,7 @@ impl Hook {
         }
 
         let display_name = StlString::from(name);
-        let handler_meta = HandlerMetadata::with_convention(conv_ptr);
+        let handler_meta = HandlerMetadata::new(conv_ptr, signature);
         let hook_meta = HookMetadata::new(priority);
         let hook =
             unsafe { raw::hook_create(address, detour, &display_name, &handler_meta, hook_meta) }
@@ -814,6 +817,43 @@ impl ModSettingsManager {
     }
 }
 
+// Geode's handle on an object in its weak reference pool. The pool keeps the object alive
+// until it is only referenced from there, after which the controller stops being managed.
+#[derive(Clone)]
+pub struct WeakRefController {
+    ptr: *mut c_void,
+    _shared: StlSharedPtr<c_void>,
+}
+
+impl WeakRefController {
+    pub fn manage(object: *mut c_void) -> Option<Self> {
+        if object.is_null() {
+            return None;
+        }
+        let pool = unsafe { raw::weak_ref_pool_get() }?;
+        if pool.is_null() {
+            return None;
+        }
+        let shared = unsafe { raw::weak_ref_pool_manage(pool, object) }?;
+        (!shared.is_null()).then_some(Self {
+            ptr: shared.as_ptr(),
+            _shared: shared,
+        })
+    }
+
+    pub fn is_managed(&self) -> bool {
+        unsafe { raw::weak_ref_controller_is_managed(self.ptr) }.unwrap_or(false)
+    }
+
+    // null once the object is gone
+    pub fn get(&self) -> *mut c_void {
+        if !self.is_managed() {
+            return std::ptr::null_mut();
+        }
+        unsafe { raw::weak_ref_controller_get(self.ptr) }.unwrap_or(std::ptr::null_mut())
+    }
+}
+
 pub mod dirs {
     use super::{raw, stl_path_to_path_buf};
     use std::path::PathBuf;
@@ -870,7 +910,7 @@ pub mod dirs {
 }
 
 pub mod log {
-    use super::{raw, stl_path_to_path_buf};
+    use super::{FmtFormatArgs, FmtStringView, Mod, Severity, raw, stl_path_to_path_buf};
     use std::path::PathBuf;
 
     pub fn current_log_path() -> Option<PathBuf> {
@@ -878,6 +918,60 @@ pub mod log {
             .and_then(|value| unsafe { value.as_ref() })
             .map(stl_path_to_path_buf)
     }
+
+    // Writes to Geode's log under the current mod. Falls back to stderr (logcat on Android)
+    // when the loader doesn't export `log::vlogImpl`, it is only there for fmt 11 builds.
+    pub fn write(severity: Severity, message: &str) {
+        // the message is passed as the format string, braces in it would be replacement fields
+        let format = message.replace('{', "{{").replace('}', "}}");
+        let module = Mod::get().map_or(std::ptr::null_mut(), Mod::as_ptr);
+        let logged = unsafe {
+            raw::log_vlog_impl(
+                severity,
+                module,
+                FmtStringView::new(&format),
+                FmtFormatArgs::EMPTY,
+            )
+        };
+        if logged.is_none() {
+            #[cfg(not(target_os = "android"))]
+            eprintln!("[geode-rs] {message}");
+            #[cfg(target_os = "android")]
+            super::android_log_string(message);
+        }
+    }
+
+    pub fn info(message: &str) {
+        write(Severity::INFO, message);
+    }
+
+    pub fn warn(message: &str) {
+        write(Severity::WARNING, message);
+    }
+
+    pub fn error(message: &str) {
+        write(Severity::ERROR, message);
+    }
+}
+
+pub mod notification {
+    use super::{NotificationIcon, raw};
+    use crate::stl::StlString;
+
+    pub const DEFAULT_TIME: f32 = 1.0;
+
+    // Has to be called on the main thread, the notification is queued behind any that are
+    // already showing.
+    pub fn show(text: &str, icon: NotificationIcon, time: f32) -> Result<(), String> {
+        let text = StlString::from(text);
+        let notification = unsafe { raw::notification_create(&text, icon, time) }
+            .ok_or_else(|| "missing geode::Notification::create".to_owned())?;
+        if notification.is_null() {
+            return Err("geode::Notification::create returned null".to_owned());
+        }
+        unsafe { raw::notification_show(notification) }
+            .ok_or_else(|| "missing geode::Notification::show".to_owned())
+    }
 }
 
 fn stl_string_to_string(value: &StlString) -> String {
@@ -0,0 +1,226 @@
+use super::{Hook, LoaderResult};
+use crate::CallingConvention;
+use crate::tulip::AbstractFunction;
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+pub trait HookFunction: Copy + 'static {
+    type Args;
+    type Output;
+
+    fn as_ptr(self) -> *mut c_void;
+    unsafe fn from_address(address: usize) -> Self;
+    unsafe fn call(self, args: Self::Args) -> Self::Output;
+}
+
+macro_rules! impl_hook_function {
+    ($($arg:ident),*) => {
+        impl<R: 'static, $($arg: 'static),*> HookFunction for extern "C" fn($($arg),*) -> R {
+            type Args = ($($arg,)*);
+            type Output = R;
+
+            fn as_ptr(self) -> *mut c_void {
+                self as *mut c_void
+            }
+
+            unsafe fn from_address(address: usize) -> Self {
+                std::mem::transmute::<usize, Self>(address)
+            }
+
+            #[allow(non_snake_case)]
+            unsafe fn call(self, args: Self::Args) -> R {
+                let ($($arg,)*) = args;
+                self($($arg),*)
+            }
+        }
+    };
+}
+
+impl_hook_function!();
+impl_hook_function!(A0);
+impl_hook_function!(A0, A1);
+impl_hook_function!(A0, A1, A2);
+impl_hook_function!(A0, A1, A2, A3);
+impl_hook_function!(A0, A1, A2, A3, A4);
+impl_hook_function!(A0, A1, A2, A3, A4, A5);
+impl_hook_function!(A0, A1, A2, A3, A4, A5, A6);
+impl_hook_function!(A0, A1, A2, A3, A4, A5, A6, A7);
+impl_hook_function!(A0, A1, A2, A3, A4, A5, A6, A7, A8);
+impl_hook_function!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9);
+impl_hook_function!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10);
+impl_hook_function!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11);
+
+// The type of a function along with its tulip signature. The signature has to be worked out
+// where the types are concrete, so `fn_marker!` builds these, which is also what the
+// generated `X_FN` markers are: `fn_marker!(fn(*mut PlayLayer, f32) -> ())`
+pub struct FnMarker<F> {
+    signature: fn() -> AbstractFunction,
+    _marker: PhantomData<F>,
+}
+
+impl<F> FnMarker<F> {
+    pub const fn new(signature: fn() -> AbstractFunction) -> Self {
+        Self {
+            signature,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn signature(&self) -> AbstractFunction {
+        (self.signature)()
+    }
+}
+
+impl<F> Clone for FnMarker<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for FnMarker<F> {}
+
+// Types tulip has no `HasAbstractType` for are passed as a struct of their size, like in
+// the #[modify] detours, see `tulip::TypeProbe`
+#[macro_export]
+macro_rules! fn_marker {
+    (fn($($arg:ty),* $(,)?) -> $ret:ty) => {
+        $crate::loader::FnMarker::<extern "C" fn($($arg),*) -> $ret>::new(|| {
+            #[allow(unused_imports)]
+            use $crate::tulip::{KnownAbstractType as _, RecordAbstractType as _};
+            $crate::tulip::AbstractFunction::from_types(
+                (&&$crate::tulip::TypeProbe::<$ret>::new()).abstract_type(),
+                &[$((&&$crate::tulip::TypeProbe::<$arg>::new()).abstract_type()),*],
+            )
+        })
+    };
+    (fn($($arg:ty),* $(,)?)) => {
+        $crate::fn_marker!(fn($($arg),*) -> ())
+    };
+}
+
+// An address together with the type of the function that lives there. The generated
+// bindings pair every `X_ADDR` resolver with an `X_FN` marker, so
+// `TypedAddress::method(PlayLayer::POST_UPDATE_ADDR, PlayLayer::POST_UPDATE_FN)` only
+// accepts a detour with the same signature as the bound function.
+pub struct TypedAddress<F> {
+    address: usize,
+    is_static: bool,
+    marker: FnMarker<F>,
+}
+
+impl<F> Clone for TypedAddress<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for TypedAddress<F> {}
+
+impl<F: HookFunction> TypedAddress<F> {
+    pub fn method(resolver: fn() -> usize, marker: FnMarker<F>) -> Self {
+        Self {
+            address: resolver(),
+            is_static: false,
+            marker,
+        }
+    }
+
+    pub fn function(resolver: fn() -> usize, marker: FnMarker<F>) -> Self {
+        Self {
+            address: resolver(),
+            is_static: true,
+            marker,
+        }
+    }
+
+    // a Rust function, `TypedAddress::from_fn(target as extern "C" fn(_) -> _, fn_marker!(..))`
+    pub fn from_fn(func: F, marker: FnMarker<F>) -> Self {
+        Self {
+            address: func.as_ptr() as usize,
+            is_static: true,
+            marker,
+        }
+    }
+
+    // the caller promises that a function of type `F` lives at `address`
+    pub unsafe fn from_raw(address: usize, is_static: bool, marker: FnMarker<F>) -> Self {
+        Self {
+            address,
+            is_static,
+            marker,
+        }
+    }
+
+    pub fn address(&self) -> usize {
+        self.address
+    }
+
+    pub fn is_static(&self) -> bool {
+        self.is_static
+    }
+
+    pub fn convention(&self) -> CallingConvention {
+        CallingConvention::for_member_function(self.is_static)
+    }
+}
+
+#[derive(Clone)]
+pub struct TypedHook<F: HookFunction> {
+    hook: Hook,
+    original: F,
+}
+
+impl<F: HookFunction> TypedHook<F> {
+    // like `Hook::create`, but the convention and tulip signature come from `F`
+    pub fn create(
+        target: TypedAddress<F>,
+        detour: F,
+        name: &str,
+        priority: i32,
+    ) -> LoaderResult<Self> {
+        if target.address == 0 {
+            return Err(format!("cannot hook {name} at address 0"));
+        }
+
+        let hook = Hook::create(
+            target.address as *mut c_void,
+            detour.as_ptr(),
+            name,
+            target.convention(),
+            priority,
+            target.marker.signature(),
+        )?;
+        Ok(Self {
+            hook,
+            original: unsafe { F::from_address(target.address) },
+        })
+    }
+
+    // Calls the hooked address itself. From inside this hook's detour Geode's handler passes
+    // the call on to the next hook in the chain or the original function, anywhere else it
+    // runs the whole chain, this detour included. `original()` is the same address.
+    pub fn call_original(&self, args: F::Args) -> F::Output {
+        unsafe { self.original.call(args) }
+    }
+
+    pub fn original(&self) -> F {
+        self.original
+    }
+
+    pub fn hook(&self) -> &Hook {
+        &self.hook
+    }
+
+    pub fn into_hook(self) -> Hook {
+        self.hook
+    }
+}
+
+impl<F: HookFunction> Deref for TypedHook<F> {
+    type Target = Hook;
+
+    fn deref(&self) -> &Hook {
+        &self.hook
+    }
+}
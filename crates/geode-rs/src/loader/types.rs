@@ -74,6 +74,51 @@ impl Severity {
     pub const ERROR: Self = Self { value: 3 };
 }
 
+// fmt::v11::basic_string_view<char>, what geode::log::vlogImpl takes the format string as
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct FmtStringView {
+    pub data: *const u8,
+    pub size: usize,
+}
+
+impl FmtStringView {
+    pub fn new(value: &str) -> Self {
+        Self {
+            data: value.as_ptr(),
+            size: value.len(),
+        }
+    }
+}
+
+// fmt::v11::basic_format_args<fmt::v11::context>, a packed descriptor and a pointer to the
+// argument values
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct FmtFormatArgs {
+    pub desc: u64,
+    pub args: *const c_void,
+}
+
+impl FmtFormatArgs {
+    pub const EMPTY: Self = Self {
+        desc: 0,
+        args: std::ptr::null(),
+    };
+}
+
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum NotificationIcon {
+    #[default]
+    None = 0,
+    Loading = 1,
+    Success = 2,
+    Warning = 3,
+    Error = 4,
+    Info = 5,
+}
+
 #[repr(i32)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum ModRequestedAction {
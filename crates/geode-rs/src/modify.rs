@@ -1,17 +1,99 @@
 #![allow(unsafe_op_in_unsafe_fn, clippy::missing_safety_doc)]
 
-use std::collections::HashMap;
+use std::cell::UnsafeCell;
 use std::ffi::c_void;
+use std::ops::{Deref, DerefMut};
 use std::panic::AssertUnwindSafe;
-use std::sync::{Mutex, OnceLock};
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, Once, OnceLock, PoisonError};
 
 use crate::CallingConvention;
-use crate::loader::Hook;
+use crate::loader::{FnMarker, Hook, Loader, LoadingState, log};
+use crate::tulip::{AbstractFunction, AbstractType};
+
+const FREE_SLOT: usize = usize::MAX;
+const STORAGE_BUCKETS: usize = 256;
+
+// Slots are boxed and never freed while the storage lives, only reused, so lookups can walk
+// the bucket lists without a lock. `borrowed` hands a slot to one guard at a time: a second
+// borrow fails instead of aliasing the fields, whether it comes from another thread or from a
+// hook further down this thread's stack (the original running another hook on the same key).
+struct Slot<T> {
+    key: AtomicUsize,
+    borrowed: AtomicBool,
+    remove_pending: AtomicBool,
+    value: UnsafeCell<Option<T>>,
+    next: AtomicPtr<Slot<T>>,
+}
+
+impl<T> Slot<T> {
+    fn try_borrow(&self) -> bool {
+        self.borrowed
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+    }
+
+    // must be called with the slot borrowed, frees the slot if a removal was requested
+    // while it was held
+    fn release(&self) {
+        loop {
+            if self.remove_pending.swap(false, Ordering::SeqCst) {
+                let value = unsafe { (*self.value.get()).take() };
+                self.key.store(FREE_SLOT, Ordering::SeqCst);
+                self.borrowed.store(false, Ordering::SeqCst);
+                drop(value);
+                return;
+            }
+
+            self.borrowed.store(false, Ordering::SeqCst);
+            if !self.remove_pending.load(Ordering::SeqCst) || !self.try_borrow() {
+                return;
+            }
+        }
+    }
+}
+
+pub struct FieldsGuard<'a, T> {
+    slot: &'a Slot<T>,
+}
+
+impl<T> Deref for FieldsGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { (*self.slot.value.get()).as_ref().unwrap_unchecked() }
+    }
+}
+
+impl<T> DerefMut for FieldsGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { (*self.slot.value.get()).as_mut().unwrap_unchecked() }
+    }
+}
+
+impl<T> Drop for FieldsGuard<'_, T> {
+    fn drop(&mut self) {
+        self.slot.release();
+    }
+}
+
+enum Borrow<'a, T> {
+    Held(FieldsGuard<'a, T>),
+    // borrowed by another guard
+    Busy,
+    // freed or reused between the lookup and the borrow
+    Gone,
+}
 
 pub struct ModifyStorage<T> {
-    data: OnceLock<Mutex<HashMap<usize, T>>>,
+    buckets: [AtomicPtr<Slot<T>>; STORAGE_BUCKETS],
+    // serializes inserts and removals, lookups never take it
+    writer: Mutex<()>,
 }
 
+unsafe impl<T: Send> Send for ModifyStorage<T> {}
+unsafe impl<T: Send> Sync for ModifyStorage<T> {}
+
 impl<T> Default for ModifyStorage<T> {
     fn default() -> Self {
         Self::new()
@@ -21,37 +103,422 @@ impl<T> Default for ModifyStorage<T> {
 impl<T> ModifyStorage<T> {
     pub const fn new() -> Self {
         Self {
-            data: OnceLock::new(),
+            buckets: [const { AtomicPtr::new(std::ptr::null_mut()) }; STORAGE_BUCKETS],
+            writer: Mutex::new(()),
+        }
+    }
+
+    fn bucket(&self, key: usize) -> &AtomicPtr<Slot<T>> {
+        let hash = (key as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15) >> 56;
+        &self.buckets[hash as usize % STORAGE_BUCKETS]
+    }
+
+    fn find(&self, key: usize) -> Option<&Slot<T>> {
+        let mut ptr = self.bucket(key).load(Ordering::Acquire);
+        while let Some(slot) = unsafe { ptr.as_ref() } {
+            if slot.key.load(Ordering::Acquire) == key {
+                return Some(slot);
+            }
+            ptr = slot.next.load(Ordering::Acquire);
+        }
+        None
+    }
+
+    fn lock_writer(&self) -> MutexGuard<'_, ()> {
+        self.writer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn acquire<'a>(&self, slot: &'a Slot<T>, key: usize) -> Borrow<'a, T> {
+        if !slot.try_borrow() {
+            return Borrow::Busy;
+        }
+        if slot.key.load(Ordering::SeqCst) != key || slot.remove_pending.load(Ordering::SeqCst) {
+            slot.release();
+            return Borrow::Gone;
         }
+        Borrow::Held(FieldsGuard { slot })
     }
 
-    fn get_data(&self) -> &Mutex<HashMap<usize, T>> {
-        self.data.get_or_init(|| Mutex::new(HashMap::new()))
+    // None if there are no fields for `key` or they are already borrowed
+    pub fn get(&self, key: usize) -> Option<FieldsGuard<'_, T>> {
+        match self.acquire(self.find(key)?, key) {
+            Borrow::Held(guard) => Some(guard),
+            Borrow::Busy | Borrow::Gone => None,
+        }
+    }
+
+    // None if the fields for `key` are already borrowed
+    pub fn get_or_default<F: FnOnce() -> T>(&self, key: usize, f: F) -> Option<FieldsGuard<'_, T>> {
+        if let Some(slot) = self.find(key) {
+            match self.acquire(slot, key) {
+                Borrow::Held(guard) => return Some(guard),
+                Borrow::Busy => return None,
+                Borrow::Gone => {}
+            }
+        }
+        self.insert(key, f())
+    }
+
+    fn insert(&self, key: usize, value: T) -> Option<FieldsGuard<'_, T>> {
+        loop {
+            let writer = self.lock_writer();
+            if let Some(slot) = self.find(key) {
+                drop(writer);
+                match self.acquire(slot, key) {
+                    Borrow::Held(guard) => return Some(guard),
+                    Borrow::Busy => return None,
+                    Borrow::Gone => continue,
+                }
+            }
+
+            let bucket = self.bucket(key);
+            let mut ptr = bucket.load(Ordering::Acquire);
+            let mut free = None;
+            while let Some(slot) = unsafe { ptr.as_ref() } {
+                if slot.key.load(Ordering::SeqCst) == FREE_SLOT && slot.try_borrow() {
+                    if slot.key.load(Ordering::SeqCst) == FREE_SLOT {
+                        free = Some(slot);
+                        break;
+                    }
+                    slot.release();
+                }
+                ptr = slot.next.load(Ordering::Acquire);
+            }
+
+            let slot = free.unwrap_or_else(|| {
+                let slot = Box::into_raw(Box::new(Slot {
+                    key: AtomicUsize::new(FREE_SLOT),
+                    borrowed: AtomicBool::new(true),
+                    remove_pending: AtomicBool::new(false),
+                    value: UnsafeCell::new(None),
+                    next: AtomicPtr::new(bucket.load(Ordering::Acquire)),
+                }));
+                bucket.store(slot, Ordering::Release);
+                unsafe { &*slot }
+            });
+
+            unsafe { *slot.value.get() = Some(value) };
+            slot.key.store(key, Ordering::SeqCst);
+            drop(writer);
+            return Some(FieldsGuard { slot });
+        }
     }
 
-    #[allow(clippy::mut_from_ref)]
-    pub fn get(&self, key: usize) -> Option<&mut T> {
-        let mut map = self.get_data().lock().unwrap();
-        map.get_mut(&key).map(|v| unsafe { &mut *(v as *mut T) })
+    pub fn with<R>(&self, key: usize, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        self.get(key).map(|mut guard| f(&mut guard))
     }
 
-    pub fn get_or_default<F: FnOnce() -> T>(&self, key: usize, f: F) -> &'static mut T {
-        let mut map = self.get_data().lock().unwrap();
-        let entry = map.entry(key).or_insert_with(f);
-        unsafe { &mut *(entry as *mut T) }
+    pub fn with_or_default<R>(
+        &self,
+        key: usize,
+        init: impl FnOnce() -> T,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> Option<R> {
+        self.get_or_default(key, init)
+            .map(|mut guard| f(&mut guard))
     }
 
+    // a slot that is currently borrowed is freed once its guard is dropped
     pub fn remove(&self, key: usize) {
-        let mut map = self.get_data().lock().unwrap();
-        map.remove(&key);
+        if self.find(key).is_none() {
+            return;
+        }
+
+        let writer = self.lock_writer();
+        let Some(slot) = self.find(key) else {
+            return;
+        };
+        slot.remove_pending.store(true, Ordering::SeqCst);
+        // drop outside the lock, the value may release objects whose destructors remove
+        // their own entries
+        drop(writer);
+        if slot.try_borrow() {
+            slot.release();
+        }
+    }
+}
+
+impl<T> Drop for ModifyStorage<T> {
+    fn drop(&mut self) {
+        for bucket in &mut self.buckets {
+            let mut ptr = *bucket.get_mut();
+            while !ptr.is_null() {
+                let slot = unsafe { Box::from_raw(ptr) };
+                ptr = slot.next.load(Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+trait InstanceStorage: Sync {
+    fn remove_instance(&self, key: usize);
+}
+
+impl<T: Send> InstanceStorage for ModifyStorage<T> {
+    fn remove_instance(&self, key: usize) {
+        self.remove(key);
+    }
+}
+
+// registered from constructors, then frozen into INSTANCE_STORAGES when the hooks are flushed
+// so the destructor hook reads a plain slice
+static INSTANCE_REGISTRATIONS: Mutex<Vec<&'static dyn InstanceStorage>> = Mutex::new(Vec::new());
+static INSTANCE_STORAGES: OnceLock<Box<[&'static dyn InstanceStorage]>> = OnceLock::new();
+static INSTANCE_CLEANUP_HOOK: Once = Once::new();
+
+// Storages keyed by `this` register here so their entries are dropped when the object dies,
+// through one shared hook on CCObject's destructor. Objects whose CCObject base is not at
+// offset 0 are not matched and still need an explicit `free`.
+pub fn track_instances<T: Send>(storage: &'static ModifyStorage<T>) {
+    if INSTANCE_STORAGES.get().is_some() {
+        log::warn(&format!(
+            "#[modify] fields of type {} were registered after the hooks were flushed, their \
+             entries have to be freed explicitly",
+            std::any::type_name::<T>()
+        ));
+        return;
+    }
+    INSTANCE_REGISTRATIONS.lock().unwrap().push(storage);
+
+    INSTANCE_CLEANUP_HOOK.call_once(|| unsafe {
+        register_hook_with_options(
+            crate::classes::CCObject::CC_OBJECT_DTOR_ADDR(),
+            instance_cleanup_detour as *mut c_void,
+            "cocos2d::CCObject::~CCObject",
+            CallingConvention::Default,
+            HookOptions {
+                signature: AbstractFunction::from_types(
+                    AbstractType::VOID,
+                    &[AbstractType::of::<*mut crate::classes::CCObject>()],
+                ),
+                ..HookOptions::default()
+            },
+        );
+    });
+}
+
+fn freeze_instance_storages() {
+    INSTANCE_STORAGES.get_or_init(|| {
+        std::mem::take(&mut *INSTANCE_REGISTRATIONS.lock().unwrap()).into_boxed_slice()
+    });
+}
+
+extern "C" fn instance_cleanup_detour(this: *mut crate::classes::CCObject) {
+    run_hook("cocos2d::CCObject::~CCObject", || {
+        for storage in INSTANCE_STORAGES.get().into_iter().flatten() {
+            storage.remove_instance(this as usize);
+        }
+    });
+    // outside the catch, a panicking storage must not keep the object alive
+    unsafe { (*this).cc_object_dtor() };
+}
+
+// mirrors geode::Priority, lower values run first
+pub mod priority {
+    pub const FIRST: i32 = -3000;
+    pub const VERY_EARLY: i32 = -2000;
+    pub const EARLY: i32 = -1000;
+    pub const NORMAL: i32 = 0;
+    pub const LATE: i32 = 1000;
+    pub const VERY_LATE: i32 = 2000;
+    pub const LAST: i32 = 3000;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookOrder {
+    Priority(i32),
+    Before(String),
+    After(String),
+}
+
+impl Default for HookOrder {
+    fn default() -> Self {
+        Self::Priority(priority::NORMAL)
+    }
+}
+
+impl HookOrder {
+    // `Before`/`After` are resolved against the other mod's hooks on the same address, None
+    // while it has none there
+    fn resolve(&self, address: usize) -> Option<i32> {
+        let (id, before) = match self {
+            Self::Priority(priority) => return Some(*priority),
+            Self::Before(id) => (id, true),
+            Self::After(id) => (id, false),
+        };
+
+        let priorities = Loader::get()
+            .and_then(|loader| loader.get_loaded_mod(id))
+            .map(|other| {
+                other
+                    .get_hooks()
+                    .into_iter()
+                    .filter(|hook| hook.address() == address)
+                    .map(|hook| hook.priority())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        if before {
+            priorities.iter().min().map(|p| p.saturating_sub(1))
+        } else {
+            priorities.iter().max().map(|p| p.saturating_add(1))
+        }
+    }
+
+    fn target_loaded(&self) -> bool {
+        match self {
+            Self::Priority(_) => true,
+            Self::Before(id) | Self::After(id) => {
+                Loader::get().is_some_and(|loader| loader.get_loaded_mod(id).is_some())
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for HookOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Priority(priority) => write!(f, "priority {priority}"),
+            Self::Before(id) => write!(f, "before {id}"),
+            Self::After(id) => write!(f, "after {id}"),
+        }
+    }
+}
+
+// hooks ordered against a mod that wasn't loaded yet when they were created, they run at the
+// normal priority until `resolve_deferred_orders` finds the mod's hooks
+struct DeferredOrder {
+    hook: Hook,
+    order: HookOrder,
+    address: usize,
+    name: String,
+}
+
+static DEFERRED_ORDERS: Mutex<Vec<DeferredOrder>> = Mutex::new(Vec::new());
+static HAS_DEFERRED_ORDERS: AtomicBool = AtomicBool::new(false);
+
+fn defer_order(hook: &Hook, order: &HookOrder, address: usize, name: &str) {
+    if order.target_loaded() {
+        log::warn(&format!(
+            "dropping the `{order}` ordering of hook {name}, that mod has no hook at {address:#x}"
+        ));
+        return;
+    }
+
+    log::info(&format!(
+        "hook {name} is ordered `{order}` but that mod isn't loaded yet, it runs at normal \
+         priority until it is"
+    ));
+    DEFERRED_ORDERS.lock().unwrap().push(DeferredOrder {
+        hook: hook.clone(),
+        order: order.clone(),
+        address,
+        name: name.to_owned(),
+    });
+    HAS_DEFERRED_ORDERS.store(true, Ordering::Release);
+    crate::lifecycle::watch_scheduler();
+}
+
+// Called every frame from the lifecycle scheduler hook. Orderings whose mod has loaded are
+// applied, and once loading is done the ones that still can't be resolved are dropped.
+pub(crate) fn resolve_deferred_orders() {
+    if !HAS_DEFERRED_ORDERS.load(Ordering::Acquire) {
+        return;
+    }
+
+    let done = Loader::get().is_some_and(|loader| loader.loading_state() == LoadingState::Done);
+    let mut deferred = DEFERRED_ORDERS.lock().unwrap();
+    deferred.retain(|entry| {
+        if let Some(priority) = entry.order.resolve(entry.address) {
+            entry.hook.set_priority(priority);
+            return false;
+        }
+        if !done && !entry.order.target_loaded() {
+            return true;
+        }
+        let reason = if entry.order.target_loaded() {
+            format!("that mod has no hook at {:#x}", entry.address)
+        } else {
+            "that mod never loaded".to_owned()
+        };
+        log::warn(&format!(
+            "dropping the `{}` ordering of hook {}, {reason}",
+            entry.order, entry.name
+        ));
+        false
+    });
+    HAS_DEFERRED_ORDERS.store(!deferred.is_empty(), Ordering::Release);
+}
+
+pub struct HookRegistry {
+    hooks: OnceLock<Mutex<Vec<(&'static str, Hook)>>>,
+}
+
+impl Default for HookRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HookRegistry {
+    pub const fn new() -> Self {
+        Self {
+            hooks: OnceLock::new(),
+        }
+    }
+
+    fn get_hooks(&self) -> &Mutex<Vec<(&'static str, Hook)>> {
+        self.hooks.get_or_init(|| Mutex::new(Vec::new()))
+    }
+
+    fn insert(&self, key: &'static str, hook: Hook) {
+        self.get_hooks().lock().unwrap().push((key, hook));
+    }
+
+    // empty until `flush_pending_hooks` has run
+    pub fn all(&self) -> Vec<(&'static str, Hook)> {
+        self.get_hooks().lock().unwrap().clone()
+    }
+
+    pub fn get(&self, key: &str) -> Option<Hook> {
+        self.get_hooks()
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, hook)| hook.clone())
+    }
+}
+
+pub struct HookOptions {
+    pub order: HookOrder,
+    pub enabled: bool,
+    pub registry: Option<(&'static HookRegistry, &'static str)>,
+    // what tulip needs to know about the detour's arguments and return value, the macros
+    // fill it in from the detour signature
+    pub signature: AbstractFunction,
+}
+
+impl Default for HookOptions {
+    fn default() -> Self {
+        Self {
+            order: HookOrder::default(),
+            enabled: true,
+            registry: None,
+            signature: AbstractFunction::void_return(),
+        }
     }
 }
 
 struct PendingHook {
     address: *mut c_void,
+    // resolved when flushed instead, see `register_hook_resolver`
+    resolver: Option<fn() -> usize>,
     detour: *mut c_void,
     name: String,
     convention: CallingConvention,
+    options: HookOptions,
 }
 
 unsafe impl Send for PendingHook {}
@@ -63,6 +530,16 @@ pub unsafe fn register_hook(
     detour: *mut c_void,
     name: &str,
     convention: CallingConvention,
+) {
+    register_hook_with_options(address, detour, name, convention, HookOptions::default());
+}
+
+pub unsafe fn register_hook_with_options(
+    address: usize,
+    detour: *mut c_void,
+    name: &str,
+    convention: CallingConvention,
+    options: HookOptions,
 ) {
     if address == 0 {
         #[cfg(not(target_os = "android"))]
@@ -75,17 +552,72 @@ pub unsafe fn register_hook(
     let hooks = PENDING_HOOKS.get_or_init(|| Mutex::new(Vec::new()));
     hooks.lock().unwrap().push(PendingHook {
         address: address as *mut c_void,
+        resolver: None,
         detour,
         name: name.to_string(),
         convention,
+        options,
     });
 }
 
+// What the hook macros register through. Hosts resolve the address when the hook is
+// flushed, so a test can install its `SymbolProvider` after the registering
+// constructors have already run.
+pub unsafe fn register_hook_resolver(
+    resolver: fn() -> usize,
+    detour: *mut c_void,
+    name: &str,
+    convention: CallingConvention,
+    options: HookOptions,
+) {
+    #[cfg(not(any(
+        target_os = "windows",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "android"
+    )))]
+    {
+        let hooks = PENDING_HOOKS.get_or_init(|| Mutex::new(Vec::new()));
+        hooks.lock().unwrap().push(PendingHook {
+            address: std::ptr::null_mut(),
+            resolver: Some(resolver),
+            detour,
+            name: name.to_string(),
+            convention,
+            options,
+        });
+    }
+
+    #[cfg(any(
+        target_os = "windows",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "android"
+    ))]
+    {
+        let address = resolver();
+        if address == 0 {
+            #[cfg(not(target_os = "android"))]
+            eprintln!("[geode-rs] WARN: address for {name} is 0, skipping hook");
+            #[cfg(target_os = "android")]
+            crate::loader::android_log_string(&format!(
+                "WARN: address for {name} resolved to 0, skipping hook"
+            ));
+            return;
+        }
+        register_hook_with_options(address, detour, name, convention, options);
+    }
+}
+
 pub fn flush_pending_hooks() {
+    freeze_instance_storages();
     let hooks = PENDING_HOOKS.get_or_init(|| Mutex::new(Vec::new()));
     let pending: Vec<PendingHook> = hooks.lock().unwrap().drain(..).collect();
 
-    for hook in pending {
+    for mut hook in pending {
+        if let Some(resolver) = hook.resolver {
+            hook.address = resolver() as *mut c_void;
+        }
         if hook.address.is_null() {
             #[cfg(not(target_os = "android"))]
             eprintln!(
@@ -97,17 +629,38 @@ pub fn flush_pending_hooks() {
             continue;
         }
 
-        match Hook::create(hook.address, hook.detour, &hook.name, hook.convention, 0) {
+        let order = hook.options.order.resolve(hook.address as usize);
+        let priority = order.unwrap_or(priority::NORMAL);
+        match Hook::create(
+            hook.address,
+            hook.detour,
+            &hook.name,
+            hook.convention,
+            priority,
+            hook.options.signature,
+        ) {
             Ok(h) => {
-                if let Err(err) = h.enable() {
+                let result = if hook.options.enabled {
+                    h.enable()
+                } else {
+                    h.set_auto_enable(false);
+                    if h.is_enabled() { h.disable() } else { Ok(()) }
+                };
+                if let Err(err) = result {
                     #[cfg(not(target_os = "android"))]
-                    eprintln!("[geode-rs] failed to enable hook {}: {err}", hook.name);
+                    eprintln!("[geode-rs] failed to toggle hook {}: {err}", hook.name);
                     #[cfg(target_os = "android")]
                     crate::loader::android_log_string(&format!(
-                        "flush_pending_hooks: enable FAILED for {} at {:p}: {err}",
+                        "flush_pending_hooks: toggle FAILED for {} at {:p}: {err}",
                         hook.name, hook.address
                     ));
                 }
+                if order.is_none() {
+                    defer_order(&h, &hook.options.order, hook.address as usize, &hook.name);
+                }
+                if let Some((registry, key)) = hook.options.registry {
+                    registry.insert(key, h);
+                }
             }
             Err(err) => {
                 #[cfg(not(target_os = "android"))]
@@ -123,19 +676,422 @@ pub fn flush_pending_hooks() {
             }
         }
     }
+
+    // deferring an ordering registers the scheduler hook that re-resolves it
+    if !hooks.lock().unwrap().is_empty() {
+        flush_pending_hooks();
+    }
 }
 
-pub fn run_hook<R: Default>(name: &str, f: impl FnOnce() -> R) -> R {
-    match std::panic::catch_unwind(AssertUnwindSafe(f)) {
-        Ok(value) => value,
-        Err(_) => {
-            #[cfg(not(target_os = "android"))]
-            eprintln!("[geode-rs] panic in hook {name}; returning default value");
-            #[cfg(target_os = "android")]
-            crate::loader::android_log_string(&format!(
-                "panic in hook {name}; returning default value"
-            ));
-            R::default()
+pub trait DetourSignature {
+    type Output;
+    const ARITY: usize;
+}
+
+#[diagnostic::on_unimplemented(
+    message = "the bound function `{Self}` has fewer arguments than this #[modify] hook"
+)]
+pub trait DetourArg<const N: usize> {
+    type Type;
+}
+
+macro_rules! impl_detour_signature {
+    ($($arg:ident),*) => {
+        impl<R, $($arg),*> DetourSignature for extern "C" fn($($arg),*) -> R {
+            type Output = R;
+            const ARITY: usize = <[&str]>::len(&[$(stringify!($arg)),*]);
+        }
+        impl_detour_signature!(@arg [$($arg),*] 0; $($arg)*);
+    };
+    (@arg [$($all:ident),*] $index:expr; $head:ident $($tail:ident)*) => {
+        impl<R, $($all),*> DetourArg<{ $index }> for extern "C" fn($($all),*) -> R {
+            type Type = $head;
+        }
+        impl_detour_signature!(@arg [$($all),*] $index + 1; $($tail)*);
+    };
+    (@arg [$($all:ident),*] $index:expr;) => {};
+}
+
+impl_detour_signature!();
+impl_detour_signature!(A0);
+impl_detour_signature!(A0, A1);
+impl_detour_signature!(A0, A1, A2);
+impl_detour_signature!(A0, A1, A2, A3);
+impl_detour_signature!(A0, A1, A2, A3, A4);
+impl_detour_signature!(A0, A1, A2, A3, A4, A5);
+impl_detour_signature!(A0, A1, A2, A3, A4, A5, A6);
+impl_detour_signature!(A0, A1, A2, A3, A4, A5, A6, A7);
+impl_detour_signature!(A0, A1, A2, A3, A4, A5, A6, A7, A8);
+impl_detour_signature!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9);
+impl_detour_signature!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10);
+impl_detour_signature!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11);
+impl_detour_signature!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12);
+impl_detour_signature!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13);
+impl_detour_signature!(
+    A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14
+);
+impl_detour_signature!(
+    A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15
+);
+impl_detour_signature!(
+    A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15, A16
+);
+impl_detour_signature!(
+    A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15, A16, A17
+);
+impl_detour_signature!(
+    A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15, A16, A17, A18
+);
+impl_detour_signature!(
+    A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15, A16, A17, A18, A19
+);
+impl_detour_signature!(
+    A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15, A16, A17, A18, A19, A20
+);
+impl_detour_signature!(
+    A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15, A16, A17, A18, A19, A20,
+    A21
+);
+impl_detour_signature!(
+    A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15, A16, A17, A18, A19, A20,
+    A21, A22
+);
+impl_detour_signature!(
+    A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15, A16, A17, A18, A19, A20,
+    A21, A22, A23
+);
+
+// used by #[modify] to check each detour argument against the generated `*_FN` marker,
+// so a mismatch is reported at the offending parameter
+pub const fn detour_arity<F: DetourSignature>(_: FnMarker<F>) -> usize {
+    F::ARITY
+}
+
+pub fn assert_detour_arg<const N: usize, T, F: DetourArg<N, Type = T>>(_: FnMarker<F>) {}
+
+pub fn assert_detour_output<T, F: DetourSignature<Output = T>>(_: FnMarker<F>) {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PanicAction {
+    // hand the call on to the next hook or the original function, as if the detour was not
+    // there. Detours whose arguments can't be copied, or that panicked after calling the
+    // original themselves, fall back to `ReturnDefault`.
+    CallOriginal,
+    ReturnDefault,
+    Abort,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PanicPolicy {
+    pub action: PanicAction,
+    // the hook is disabled after this many panics, 0 keeps it running
+    pub disable_after: u32,
+}
+
+impl PanicPolicy {
+    pub const DEFAULT: Self = Self {
+        action: PanicAction::CallOriginal,
+        disable_after: 3,
+    };
+}
+
+impl Default for PanicPolicy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+pub struct HookPanic<'a> {
+    pub hook: &'a str,
+    pub message: String,
+    // captured where the panic happened, empty if another panic hook replaced ours
+    pub backtrace: String,
+    // how often this hook has panicked so far, including this time
+    pub count: u32,
+    pub action: PanicAction,
+    pub disabled: bool,
+}
+
+static PANIC_POLICY: Mutex<PanicPolicy> = Mutex::new(PanicPolicy::DEFAULT);
+static PANIC_REPORTER: Mutex<Option<fn(&HookPanic)>> = Mutex::new(None);
+static PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+    static HOOK_DEPTH: std::cell::Cell<u32> = const { std::cell::Cell::new(0) };
+    // hooked address and `this` of every detour running on this thread, and whether its body
+    // has called through to the next hook or the original yet
+    static DETOUR_FRAMES: std::cell::RefCell<Vec<(usize, usize, bool)>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+    static LAST_PANIC: std::cell::RefCell<Option<(String, String)>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+pub fn set_panic_policy(policy: PanicPolicy) {
+    *PANIC_POLICY.lock().unwrap_or_else(PoisonError::into_inner) = policy;
+}
+
+pub fn panic_policy() -> PanicPolicy {
+    *PANIC_POLICY.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+// Replaces the default report, which goes to Geode's log, e.g. to forward hook panics to a
+// crash reporter.
+pub fn set_panic_reporter(reporter: fn(&HookPanic)) {
+    *PANIC_REPORTER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(reporter);
+}
+
+fn report_panic(panic: &HookPanic) {
+    let reporter = *PANIC_REPORTER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some(reporter) = reporter {
+        reporter(panic);
+        return;
+    }
+
+    let outcome = match panic.action {
+        PanicAction::CallOriginal => "calling the original",
+        PanicAction::ReturnDefault => "returning default value",
+        PanicAction::Abort => "aborting",
+    };
+    let disabled = if panic.disabled {
+        ", hook disabled"
+    } else {
+        ""
+    };
+    let report = format!(
+        "panic in hook {} (#{}): {}; {outcome}{disabled}\n{}",
+        panic.hook, panic.count, panic.message, panic.backtrace
+    );
+    crate::loader::log::error(report.trim_end());
+}
+
+// The backtrace is only available while the panic unwinds, so panics inside hooks are
+// recorded by a panic hook and picked up once `catch_unwind` returns. Panics elsewhere go
+// to the previous hook as before.
+fn install_panic_hook() {
+    PANIC_HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if HOOK_DEPTH.with(|depth| depth.get()) == 0 {
+                previous(info);
+                return;
+            }
+            let message = info
+                .payload()
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| info.payload().downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic payload".to_owned());
+            let message = match info.location() {
+                Some(location) => format!("{message} at {location}"),
+                None => message,
+            };
+            let backtrace = std::backtrace::Backtrace::force_capture().to_string();
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some((message, backtrace)));
+        }));
+    });
+}
+
+fn catch_hook_panic<R>(f: impl FnOnce() -> R) -> Result<R, (String, String)> {
+    install_panic_hook();
+    HOOK_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let result = std::panic::catch_unwind(AssertUnwindSafe(f));
+    HOOK_DEPTH.with(|depth| depth.set(depth.get() - 1));
+
+    result.map_err(|payload| {
+        LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_else(|| {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|message| message.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "unknown panic payload".to_owned());
+                (message, String::new())
+            })
+    })
+}
+
+// Per-detour state, #[modify], #[hook_fn], #[hook_at] and hook_symbol! emit one per hook.
+// `action` and `disable_after` override the global policy when set.
+pub struct HookSite {
+    name: &'static str,
+    address: fn() -> usize,
+    action: Option<PanicAction>,
+    disable_after: Option<u32>,
+    panics: AtomicU32,
+}
+
+impl HookSite {
+    pub const fn new(
+        name: &'static str,
+        address: fn() -> usize,
+        action: Option<PanicAction>,
+        disable_after: Option<u32>,
+    ) -> Self {
+        Self {
+            name,
+            address,
+            action,
+            disable_after,
+            panics: AtomicU32::new(0),
+        }
+    }
+
+    pub fn address(&self) -> usize {
+        (self.address)()
+    }
+
+    pub fn panics(&self) -> u32 {
+        self.panics.load(Ordering::Relaxed)
+    }
+
+    fn disable(&self) -> bool {
+        let address = self.address();
+        let Some(current) = crate::loader::Mod::get() else {
+            return false;
+        };
+        let mut disabled = false;
+        for hook in current.get_hooks() {
+            if hook.address() == address && hook.display_name() == self.name {
+                disabled |= hook.disable().is_ok();
+            }
+        }
+        disabled
+    }
+}
+
+// Called by a detour's generated `_orig` function right before it calls `address`, so a
+// detour that panics after reaching the next hook or the original doesn't run it a second
+// time. `this` is null for free and static functions.
+pub fn note_original(address: usize, this: *const c_void) {
+    let this = this as usize;
+    let _ = DETOUR_FRAMES.try_with(|frames| {
+        if let Some(frame) = frames
+            .borrow_mut()
+            .iter_mut()
+            .rev()
+            .find(|(hooked, hooked_this, _)| *hooked == address && *hooked_this == this)
+        {
+            frame.2 = true;
         }
+    });
+}
+
+// `original` calls the hooked address again, which reaches the next hook or the original
+// function. It is None when the detour's arguments can't be copied.
+pub fn run_detour<R: Default>(
+    site: &HookSite,
+    this: *const c_void,
+    body: impl FnOnce() -> R,
+    original: Option<impl FnOnce() -> R>,
+) -> R {
+    let address = site.address();
+    DETOUR_FRAMES.with(|frames| frames.borrow_mut().push((address, this as usize, false)));
+    let result = catch_hook_panic(body);
+    let reached = DETOUR_FRAMES
+        .with(|frames| frames.borrow_mut().pop())
+        .is_some_and(|(_, _, reached)| reached);
+    let (message, backtrace) = match result {
+        Ok(value) => return value,
+        Err(panic) => panic,
+    };
+
+    let global = panic_policy();
+    let action = match site.action.unwrap_or(global.action) {
+        PanicAction::CallOriginal if original.is_none() || address == 0 || reached => {
+            PanicAction::ReturnDefault
+        }
+        action => action,
+    };
+    let disable_after = site.disable_after.unwrap_or(global.disable_after);
+    let count = site.panics.fetch_add(1, Ordering::Relaxed) + 1;
+    let disabled = disable_after != 0 && count == disable_after && site.disable();
+
+    report_panic(&HookPanic {
+        hook: site.name,
+        message,
+        backtrace,
+        count,
+        action,
+        disabled,
+    });
+
+    match action {
+        PanicAction::Abort => std::process::abort(),
+        PanicAction::CallOriginal => original.map_or_else(R::default, |original| original()),
+        PanicAction::ReturnDefault => R::default(),
+    }
+}
+
+// For callbacks and thunks that have no original to fall back to, `CallOriginal` returns the
+// default value instead.
+pub fn run_hook<R: Default>(name: &str, f: impl FnOnce() -> R) -> R {
+    let (message, backtrace) = match catch_hook_panic(f) {
+        Ok(value) => return value,
+        Err(panic) => panic,
+    };
+
+    let action = panic_policy().action;
+    report_panic(&HookPanic {
+        hook: name,
+        message,
+        backtrace,
+        count: 1,
+        action: match action {
+            PanicAction::Abort => PanicAction::Abort,
+            _ => PanicAction::ReturnDefault,
+        },
+        disabled: false,
+    });
+    if action == PanicAction::Abort {
+        std::process::abort();
+    }
+    R::default()
+}
+
+// For thunks whose return type has no value to fall back to, like a C++ reference, a panic
+// is reported and aborts whatever the policy.
+pub fn run_hook_or_abort<R>(name: &str, f: impl FnOnce() -> R) -> R {
+    let (message, backtrace) = match catch_hook_panic(f) {
+        Ok(value) => return value,
+        Err(panic) => panic,
+    };
+
+    report_panic(&HookPanic {
+        hook: name,
+        message,
+        backtrace,
+        count: 1,
+        action: PanicAction::Abort,
+        disabled: false,
+    });
+    std::process::abort()
+}
+
+// Lets a detour copy its arguments for `PanicAction::CallOriginal` when they are `Copy`,
+// `(&&ArgProbe(&arg)).copied()` is None for the rest.
+pub struct ArgProbe<'a, T>(pub &'a T);
+
+pub trait CopyArg<T> {
+    fn copied(&self) -> Option<T>;
+}
+
+impl<T: Copy> CopyArg<T> for &ArgProbe<'_, T> {
+    fn copied(&self) -> Option<T> {
+        Some(*self.0)
+    }
+}
+
+pub trait MoveArg<T> {
+    fn copied(&self) -> Option<T>;
+}
+
+impl<T> MoveArg<T> for ArgProbe<'_, T> {
+    fn copied(&self) -> Option<T> {
+        None
     }
 }
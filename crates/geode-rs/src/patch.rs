@@ -0,0 +1,397 @@
+use crate::base::Module;
+use crate::loader::{LoaderResult, Patch};
+use std::ffi::c_void;
+use std::ops::Deref;
+
+// Offsets of the same patch site on each platform, the one for the platform being built
+// for is picked at runtime:
+// `PlatformOffsets::new().win(0x2f1a30).android64(0x6c4e18)`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PlatformOffsets {
+    pub win: Option<usize>,
+    pub mac_intel: Option<usize>,
+    pub mac_arm: Option<usize>,
+    pub ios: Option<usize>,
+    pub android32: Option<usize>,
+    pub android64: Option<usize>,
+}
+
+impl PlatformOffsets {
+    pub const fn new() -> Self {
+        Self {
+            win: None,
+            mac_intel: None,
+            mac_arm: None,
+            ios: None,
+            android32: None,
+            android64: None,
+        }
+    }
+
+    pub const fn win(mut self, offset: usize) -> Self {
+        self.win = Some(offset);
+        self
+    }
+
+    pub const fn mac_intel(mut self, offset: usize) -> Self {
+        self.mac_intel = Some(offset);
+        self
+    }
+
+    pub const fn mac_arm(mut self, offset: usize) -> Self {
+        self.mac_arm = Some(offset);
+        self
+    }
+
+    pub const fn ios(mut self, offset: usize) -> Self {
+        self.ios = Some(offset);
+        self
+    }
+
+    pub const fn android32(mut self, offset: usize) -> Self {
+        self.android32 = Some(offset);
+        self
+    }
+
+    pub const fn android64(mut self, offset: usize) -> Self {
+        self.android64 = Some(offset);
+        self
+    }
+
+    #[allow(unreachable_code)]
+    pub const fn current(&self) -> Option<usize> {
+        #[cfg(target_os = "windows")]
+        return self.win;
+        #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+        return self.mac_intel;
+        #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+        return self.mac_arm;
+        #[cfg(target_os = "ios")]
+        return self.ios;
+        #[cfg(all(target_os = "android", target_arch = "arm"))]
+        return self.android32;
+        #[cfg(all(target_os = "android", target_arch = "aarch64"))]
+        return self.android64;
+        None
+    }
+}
+
+impl Patch {
+    pub fn at(module: Module, offset: usize) -> PatchBuilder {
+        let base = module.base();
+        if base == 0 {
+            return PatchBuilder::failed(format!("{module:?} is not loaded"));
+        }
+        PatchBuilder::new(base + offset)
+    }
+
+    pub fn at_platform(module: Module, offsets: PlatformOffsets) -> PatchBuilder {
+        match offsets.current() {
+            Some(offset) => Self::at(module, offset),
+            None => PatchBuilder::failed("no patch offset for this platform".to_owned()),
+        }
+    }
+
+    pub fn at_address(address: usize) -> PatchBuilder {
+        PatchBuilder::new(address)
+    }
+}
+
+// Collects the bytes of a patch, encoding relative branches against the address they end
+// up at. Errors (unresolved modules, branches out of range) are kept until `apply`.
+#[must_use]
+pub struct PatchBuilder {
+    address: usize,
+    bytes: Vec<u8>,
+    expected: Option<Vec<u8>>,
+    error: Option<String>,
+}
+
+impl PatchBuilder {
+    pub fn new(address: usize) -> Self {
+        Self {
+            address,
+            bytes: Vec::new(),
+            expected: None,
+            error: (address == 0).then(|| "cannot patch address 0".to_owned()),
+        }
+    }
+
+    fn failed(error: String) -> Self {
+        Self {
+            address: 0,
+            bytes: Vec::new(),
+            expected: None,
+            error: Some(error),
+        }
+    }
+
+    fn fail(mut self, error: String) -> Self {
+        self.error.get_or_insert(error);
+        self
+    }
+
+    pub fn address(&self) -> usize {
+        self.address
+    }
+
+    // where the next written byte goes
+    pub fn cursor(&self) -> usize {
+        self.address + self.bytes.len()
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn bytes(mut self, bytes: &[u8]) -> Self {
+        self.bytes.extend_from_slice(bytes);
+        self
+    }
+
+    // the original bytes the patch site must contain, checked before patching so a game
+    // update doesn't get random code overwritten
+    pub fn expect(mut self, original: &[u8]) -> Self {
+        self.expected = Some(original.to_vec());
+        self
+    }
+
+    pub fn value<T: PatchValue>(mut self, value: T) -> Self {
+        value.write_to(&mut self.bytes);
+        self
+    }
+
+    pub fn int(self, value: i32) -> Self {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    pub fn float(self, value: f32) -> Self {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    pub fn double(self, value: f64) -> Self {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    // `len` bytes of the current architecture's nop, which has to be a whole number of
+    // instructions on ARM
+    pub fn nop(self, len: usize) -> Self {
+        #[cfg(target_arch = "aarch64")]
+        const NOP: &[u8] = &0xd503201fu32.to_le_bytes();
+        #[cfg(target_arch = "arm")]
+        const NOP: &[u8] = &0xbf00u16.to_le_bytes();
+        #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
+        const NOP: &[u8] = &[0x90];
+
+        if !len.is_multiple_of(NOP.len()) {
+            return self.fail(format!(
+                "nop length {len} is not a multiple of {}",
+                NOP.len()
+            ));
+        }
+        let bytes = NOP.repeat(len / NOP.len());
+        self.bytes(&bytes)
+    }
+
+    // x86 `jmp rel32`
+    pub fn jmp(self, to: usize) -> Self {
+        self.x86_relative(0xe9, to)
+    }
+
+    // x86 `call rel32`
+    pub fn call(self, to: usize) -> Self {
+        self.x86_relative(0xe8, to)
+    }
+
+    fn x86_relative(self, opcode: u8, to: usize) -> Self {
+        let from = self.cursor() + 5;
+        match i32::try_from(to as i64 - from as i64) {
+            Ok(rel) => self.bytes(&[opcode]).int(rel),
+            Err(_) => self.fail(format!("{to:#x} is out of rel32 range from {from:#x}")),
+        }
+    }
+
+    // ARM64 `b`
+    pub fn arm64_b(self, to: usize) -> Self {
+        self.arm64_branch(0x14000000, to)
+    }
+
+    // ARM64 `bl`
+    pub fn arm64_bl(self, to: usize) -> Self {
+        self.arm64_branch(0x94000000, to)
+    }
+
+    fn arm64_branch(self, opcode: u32, to: usize) -> Self {
+        let from = self.cursor();
+        let offset = to as i64 - from as i64;
+        if offset % 4 != 0 || !(-(1 << 27)..(1 << 27)).contains(&offset) {
+            return self.fail(format!("{to:#x} is out of b/bl range from {from:#x}"));
+        }
+        let imm26 = ((offset >> 2) as u32) & 0x3ffffff;
+        self.bytes(&(opcode | imm26).to_le_bytes())
+    }
+
+    // ARMv7 Thumb-2 `b.w`, which is what GD is compiled to on 32-bit Android
+    pub fn armv7_b(self, to: usize) -> Self {
+        self.thumb_branch(0x9000, to)
+    }
+
+    // ARMv7 Thumb-2 `bl`
+    pub fn armv7_bl(self, to: usize) -> Self {
+        self.thumb_branch(0xd000, to)
+    }
+
+    fn thumb_branch(self, opcode: u16, to: usize) -> Self {
+        // thumb addresses have the low bit set, the pc reads 4 bytes ahead
+        let from = (self.cursor() & !1) + 4;
+        let offset = (to & !1) as i64 - from as i64;
+        if !(-(1 << 24)..(1 << 24)).contains(&offset) {
+            return self.fail(format!("{to:#x} is out of b.w/bl range from {from:#x}"));
+        }
+
+        let offset = offset as u32;
+        let s = (offset >> 24) & 1;
+        let j1 = (!(offset >> 23) ^ s) & 1;
+        let j2 = (!(offset >> 22) ^ s) & 1;
+        let imm10 = (offset >> 12) & 0x3ff;
+        let imm11 = (offset >> 1) & 0x7ff;
+
+        let first = 0xf000 | (s << 10) as u16 | imm10 as u16;
+        let second = opcode | (j1 << 13) as u16 | (j2 << 11) as u16 | imm11 as u16;
+        self.bytes(&first.to_le_bytes())
+            .bytes(&second.to_le_bytes())
+    }
+
+    // the architecture's unconditional relative branch
+    pub fn branch(self, to: usize) -> Self {
+        #[cfg(target_arch = "aarch64")]
+        {
+            self.arm64_b(to)
+        }
+        #[cfg(target_arch = "arm")]
+        {
+            self.armv7_b(to)
+        }
+        #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
+        {
+            self.jmp(to)
+        }
+    }
+
+    fn verify(&self) -> LoaderResult<()> {
+        let Some(expected) = &self.expected else {
+            return Ok(());
+        };
+        let current =
+            unsafe { std::slice::from_raw_parts(self.address as *const u8, expected.len()) };
+        if current != expected.as_slice() {
+            return Err(format!(
+                "unexpected bytes at {:#x}: expected {}, found {}",
+                self.address,
+                hex(expected),
+                hex(current)
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn create(self) -> LoaderResult<Patch> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        if self.bytes.is_empty() {
+            return Err(format!("patch at {:#x} has no bytes", self.address));
+        }
+        self.verify()?;
+        Patch::create(self.address as *mut c_void, &self.bytes)
+    }
+
+    pub fn apply(self) -> LoaderResult<Patch> {
+        let patch = self.create()?;
+        if !patch.is_enabled() {
+            patch.enable()?;
+        }
+        Ok(patch)
+    }
+
+    pub fn apply_scoped(self) -> LoaderResult<ScopedPatch> {
+        self.apply().map(ScopedPatch::new)
+    }
+}
+
+// What `PatchBuilder::value` writes: plain numbers, in the little-endian order of every
+// platform the game runs on. Anything with padding would leak uninitialized bytes into the
+// patch, so structs go through `bytes` instead.
+pub trait PatchValue: Copy {
+    fn write_to(self, bytes: &mut Vec<u8>);
+}
+
+macro_rules! impl_patch_value {
+    ($($ty:ty),*) => {
+        $(
+            impl PatchValue for $ty {
+                fn write_to(self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_patch_value!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+fn hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Restores the original bytes when dropped, `Patch` on its own stays applied for as long
+// as the mod is loaded
+pub struct ScopedPatch {
+    patch: Option<Patch>,
+}
+
+impl ScopedPatch {
+    pub fn new(patch: Patch) -> Self {
+        Self { patch: Some(patch) }
+    }
+
+    pub fn patch(&self) -> &Patch {
+        self.patch.as_ref().unwrap()
+    }
+
+    // keep the patch applied after all
+    pub fn into_inner(mut self) -> Patch {
+        self.patch.take().unwrap()
+    }
+}
+
+impl Deref for ScopedPatch {
+    type Target = Patch;
+
+    fn deref(&self) -> &Patch {
+        self.patch()
+    }
+}
+
+impl Drop for ScopedPatch {
+    fn drop(&mut self) {
+        if let Some(patch) = self.patch.take()
+            && let Err(err) = patch.disable()
+        {
+            #[cfg(not(target_os = "android"))]
+            eprintln!(
+                "[geode-rs] failed to restore patch at {:#x}: {err}",
+                patch.address()
+            );
+            #[cfg(target_os = "android")]
+            crate::loader::android_log_string(&format!(
+                "ScopedPatch: restore FAILED at {:#x}: {err}",
+                patch.address()
+            ));
+        }
+    }
+}
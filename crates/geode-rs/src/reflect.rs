@@ -0,0 +1,60 @@
+pub struct ClassInfo {
+    pub name: &'static str,
+    pub bases: &'static [&'static str],
+    pub size: usize,
+    pub fields: &'static [FieldInfo],
+    pub functions: &'static [FunctionInfo],
+}
+
+pub struct FieldInfo {
+    pub name: &'static str,
+    pub cpp_type: &'static str,
+    pub rust_type: &'static str,
+    pub offset: usize,
+}
+
+pub struct FunctionInfo {
+    pub name: &'static str,
+    pub rust_name: &'static str,
+    pub signature: &'static str,
+    pub resolver: fn() -> usize,
+    // whether this platform has an address or a symbol for it, the resolver returns 0
+    // otherwise
+    pub bound: bool,
+}
+
+impl ClassInfo {
+    pub fn short_name(&self) -> &'static str {
+        self.name.rsplit("::").next().unwrap_or(self.name)
+    }
+
+    pub fn field(&self, name: &str) -> Option<&'static FieldInfo> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+    pub fn function(&self, name: &str) -> Option<&'static FunctionInfo> {
+        self.functions
+            .iter()
+            .find(|func| func.rust_name == name)
+            .or_else(|| self.functions.iter().find(|func| func.name == name))
+    }
+
+    pub fn base(&self) -> Option<&'static ClassInfo> {
+        self.bases.first().and_then(|base| class(base))
+    }
+}
+
+impl FunctionInfo {
+    pub fn address(&self) -> usize {
+        (self.resolver)()
+    }
+}
+
+pub fn classes() -> &'static [ClassInfo] {
+    crate::reflection::CLASSES
+}
+pub fn class(name: &str) -> Option<&'static ClassInfo> {
+    classes()
+        .iter()
+        .find(|info| info.name == name)
+        .or_else(|| classes().iter().find(|info| info.short_name() == name))
+}
@@ -0,0 +1,349 @@
+#![allow(clippy::missing_safety_doc)]
+
+use std::collections::HashMap;
+use std::ffi::{CStr, c_char, c_void};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ptr::NonNull;
+use std::sync::{OnceLock, RwLock};
+
+use crate::classes::CCObject;
+use crate::inherit::{Obj, try_primary_vtable};
+use crate::loader::WeakRefController;
+
+// Implemented for every class whose primary base chain ends in `CCObject`, which puts the
+// `CCObject` at offset 0 of the object.
+pub unsafe trait CocosObject {
+    // the C++ class, which is what checked downcasts compare against. Classes defined in
+    // Rust keep their base's RTTI, so they leave this as None.
+    const CLASS_NAME: Option<&'static str> = None;
+
+    fn cc_object(this: NonNull<Self>) -> NonNull<CCObject> {
+        this.cast()
+    }
+
+    // whether `object` is a `Self`. #[inherit] classes recognize their objects by the vtable
+    // they install, one without overrides shares its base's vtable and never matches.
+    fn is_instance(object: NonNull<CCObject>) -> bool {
+        Self::CLASS_NAME.is_some_and(|target| is_class_instance(object, target))
+    }
+}
+
+// A retained pointer to a Cocos object, like `geode::Ref`. It keeps the object alive until
+// dropped, unlike `Obj` which is only valid until the autorelease pool is drained. Clones
+// point at the same object, so a `Ref` only hands out shared references.
+pub struct Ref<T: CocosObject> {
+    ptr: NonNull<T>,
+}
+
+impl<T: CocosObject> Ref<T> {
+    pub fn new(ptr: NonNull<T>) -> Self {
+        unsafe { T::cc_object(ptr).as_mut() }.retain();
+        Self { ptr }
+    }
+
+    pub unsafe fn from_raw(ptr: *mut T) -> Option<Self> {
+        NonNull::new(ptr).map(Self::new)
+    }
+
+    // takes over a reference the caller already owns instead of retaining
+    pub unsafe fn adopt(ptr: NonNull<T>) -> Self {
+        Self { ptr }
+    }
+
+    // gives up the reference without releasing it, `adopt` takes it back
+    pub fn into_raw(self) -> *mut T {
+        let this = std::mem::ManuallyDrop::new(self);
+        this.ptr.as_ptr()
+    }
+
+    pub fn as_ptr(&self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    pub fn as_non_null(&self) -> NonNull<T> {
+        self.ptr
+    }
+
+    // nothing else may use the object while the reference is alive, including other `Ref`s
+    // and `WeakRef`s to it
+    pub unsafe fn as_mut(&mut self) -> &mut T {
+        unsafe { self.ptr.as_mut() }
+    }
+
+    pub fn downgrade(&self) -> WeakRef<T> {
+        WeakRef::new(self.ptr)
+    }
+
+    pub fn upcast(self) -> Ref<CCObject> {
+        let ptr = T::cc_object(self.ptr);
+        std::mem::forget(self);
+        Ref { ptr }
+    }
+
+    pub fn downcast<U: CocosObject>(self) -> Result<Ref<U>, Self> {
+        match downcast_ptr::<U>(T::cc_object(self.ptr)) {
+            Some(ptr) => {
+                std::mem::forget(self);
+                Ok(Ref { ptr })
+            }
+            None => Err(self),
+        }
+    }
+}
+
+impl<T: CocosObject> Clone for Ref<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.ptr)
+    }
+}
+
+impl<T: CocosObject> Drop for Ref<T> {
+    fn drop(&mut self) {
+        unsafe { T::cc_object(self.ptr).as_mut() }.release();
+    }
+}
+
+impl<T: CocosObject> Deref for Ref<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T: CocosObject> From<Obj<T>> for Ref<T> {
+    fn from(obj: Obj<T>) -> Self {
+        Self::new(obj.as_non_null())
+    }
+}
+
+impl<T: CocosObject> From<&Obj<T>> for Ref<T> {
+    fn from(obj: &Obj<T>) -> Self {
+        Self::new(obj.as_non_null())
+    }
+}
+
+impl<T: CocosObject> From<NonNull<T>> for Ref<T> {
+    fn from(ptr: NonNull<T>) -> Self {
+        Self::new(ptr)
+    }
+}
+
+impl<T: CocosObject> PartialEq for Ref<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr == other.ptr
+    }
+}
+
+impl<T: CocosObject> Eq for Ref<T> {}
+
+impl<T: CocosObject> Hash for Ref<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ptr.hash(state);
+    }
+}
+
+impl<T: CocosObject> fmt::Debug for Ref<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Ref").field(&self.ptr).finish()
+    }
+}
+
+// A reference through Geode's weak ref pool, like `geode::WeakRef`. It doesn't keep the
+// object alive, `lock` hands out a `Ref` for as long as something else does.
+pub struct WeakRef<T: CocosObject> {
+    // None for null objects, or when Geode's pool couldn't be reached
+    controller: Option<WeakRefController>,
+    _marker: PhantomData<*mut T>,
+}
+
+impl<T: CocosObject> WeakRef<T> {
+    pub fn new(ptr: NonNull<T>) -> Self {
+        Self {
+            controller: WeakRefController::manage(T::cc_object(ptr).as_ptr().cast()),
+            _marker: PhantomData,
+        }
+    }
+
+    pub unsafe fn from_raw(ptr: *mut T) -> Self {
+        NonNull::new(ptr).map_or_else(Self::empty, Self::new)
+    }
+
+    pub fn empty() -> Self {
+        Self {
+            controller: None,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn lock(&self) -> Option<Ref<T>> {
+        let object = self.controller.as_ref()?.get();
+        // CCObject sits at offset 0, so this is the pointer `new` was given
+        NonNull::new(object.cast::<T>()).map(Ref::new)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.controller
+            .as_ref()
+            .is_some_and(WeakRefController::is_managed)
+    }
+}
+
+impl<T: CocosObject> Clone for WeakRef<T> {
+    fn clone(&self) -> Self {
+        Self {
+            controller: self.controller.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: CocosObject> Default for WeakRef<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T: CocosObject> From<&Ref<T>> for WeakRef<T> {
+    fn from(strong: &Ref<T>) -> Self {
+        strong.downgrade()
+    }
+}
+
+impl<T: CocosObject> From<Obj<T>> for WeakRef<T> {
+    fn from(obj: Obj<T>) -> Self {
+        Self::new(obj.as_non_null())
+    }
+}
+
+impl<T: CocosObject> From<NonNull<T>> for WeakRef<T> {
+    fn from(ptr: NonNull<T>) -> Self {
+        Self::new(ptr)
+    }
+}
+
+impl<T: CocosObject> fmt::Debug for WeakRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakRef")
+            .field("valid", &self.is_valid())
+            .finish()
+    }
+}
+
+// Checks the object's RTTI: its dynamic class has to be `U` or have it somewhere up its
+// primary base chain, which is the only case where the cast keeps the pointer as is.
+// Classes the bindings don't know about can't be walked and fail the check.
+pub fn is_instance_of<U: CocosObject>(object: NonNull<CCObject>) -> bool {
+    U::is_instance(object)
+}
+
+fn is_class_instance(object: NonNull<CCObject>, target: &'static str) -> bool {
+    if target == "cocos2d::CCObject" {
+        return true;
+    }
+    let Some(vtable) = try_primary_vtable(object) else {
+        return false;
+    };
+
+    // the vtable pins down the dynamic class, so the answer is the same for every object
+    // sharing it
+    let key = (vtable.as_ptr() as usize, target);
+    let checks = INSTANCE_CHECKS.get_or_init(Default::default);
+    if let Some(&known) = checks.read().unwrap().get(&key) {
+        return known;
+    }
+    let result = dynamic_class_name(object).is_some_and(|name| derives_from(&name, target));
+    checks.write().unwrap().insert(key, result);
+    result
+}
+
+pub fn downcast_ptr<U: CocosObject>(object: NonNull<CCObject>) -> Option<NonNull<U>> {
+    is_instance_of::<U>(object).then(|| object.cast())
+}
+
+static INSTANCE_CHECKS: OnceLock<RwLock<HashMap<(usize, &'static str), bool>>> = OnceLock::new();
+
+fn derives_from(class: &str, target: &str) -> bool {
+    let mut name = class;
+    for _ in 0..64 {
+        if name == target {
+            return true;
+        }
+        match crate::reflect::class(name).and_then(|info| info.bases.first()) {
+            Some(base) => name = base,
+            None => return false,
+        }
+    }
+    false
+}
+
+// The dynamic class of the object as `cocos2d::CCNode`, read from the type info the
+// vtable points to.
+pub fn dynamic_class_name(object: NonNull<CCObject>) -> Option<String> {
+    let vtable = try_primary_vtable(object)?;
+    let rtti = unsafe { *vtable.as_ptr().sub(1) };
+    if rtti.is_null() {
+        return None;
+    }
+    let name = unsafe { CStr::from_ptr(rtti_type_name(rtti)) };
+    demangle_type_name(name.to_str().ok()?)
+}
+
+// vtable[-1] is the complete object locator, whose type descriptor holds a name like
+// `.?AVCCNode@cocos2d@@` after its vtable and spare pointers
+#[cfg(target_os = "windows")]
+unsafe fn rtti_type_name(locator: *mut c_void) -> *const c_char {
+    let locator = locator.cast::<u8>();
+    let signature = unsafe { locator.cast::<u32>().read_unaligned() };
+    let descriptor = if signature == 1 {
+        // x64 stores image relative offsets, and the locator's own offset to find the image
+        let type_offset = unsafe { locator.add(12).cast::<u32>().read_unaligned() } as usize;
+        let self_offset = unsafe { locator.add(20).cast::<u32>().read_unaligned() } as usize;
+        (locator as usize).wrapping_sub(self_offset) + type_offset
+    } else {
+        unsafe { locator.add(12).cast::<u32>().read_unaligned() as usize }
+    };
+    (descriptor + 2 * std::mem::size_of::<usize>()) as *const c_char
+}
+
+// vtable[-1] is the std::type_info, whose name like `N7cocos2d6CCNodeE` follows its vtable
+#[cfg(not(target_os = "windows"))]
+unsafe fn rtti_type_name(type_info: *mut c_void) -> *const c_char {
+    let name = unsafe { *type_info.cast::<usize>().add(1) };
+    // arm64 Apple sets the top bit on names that aren't unique across images
+    #[cfg(all(target_vendor = "apple", target_arch = "aarch64"))]
+    let name = name & !(1 << 63);
+    name as *const c_char
+}
+
+#[cfg(target_os = "windows")]
+fn demangle_type_name(name: &str) -> Option<String> {
+    let name = name
+        .strip_prefix(".?AV")
+        .or_else(|| name.strip_prefix(".?AU"))?
+        .strip_suffix("@@")?;
+    // templates and other special names
+    if name.contains(['?', '$']) {
+        return None;
+    }
+    Some(name.rsplit('@').collect::<Vec<_>>().join("::"))
+}
+
+#[cfg(not(target_os = "windows"))]
+fn demangle_type_name(name: &str) -> Option<String> {
+    let mut rest = name
+        .strip_prefix('N')
+        .and_then(|nested| nested.strip_suffix('E'))
+        .unwrap_or(name);
+    let mut parts = Vec::new();
+    while !rest.is_empty() {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let len: usize = rest[..digits].parse().ok()?;
+        parts.push(rest.get(digits..digits + len)?);
+        rest = &rest[digits + len..];
+    }
+    Some(parts.join("::"))
+}
@@ -0,0 +1,528 @@
+#![allow(unsafe_op_in_unsafe_fn, clippy::missing_safety_doc)]
+
+use crate::base::Module;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
+
+// An IDA-style byte pattern, `48 8B ?? ?? E8` where `??` (or `?`) matches any byte
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern {
+    bytes: Vec<Option<u8>>,
+    // index of the byte candidates are looked up by, chosen to be a rare one
+    anchor: usize,
+}
+
+impl Pattern {
+    pub fn parse(pattern: &str) -> Result<Self, String> {
+        let bytes = pattern
+            .split_whitespace()
+            .map(|token| match token {
+                "?" | "??" => Ok(None),
+                _ if token.len() == 2 => u8::from_str_radix(token, 16)
+                    .map(Some)
+                    .map_err(|_| format!("invalid byte `{token}` in pattern `{pattern}`")),
+                _ => Err(format!("invalid byte `{token}` in pattern `{pattern}`")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // common filler and prefix bytes make for a lot of false candidates
+        let anchor = bytes
+            .iter()
+            .position(|byte| byte.is_some_and(|b| !matches!(b, 0x00 | 0xff | 0xcc | 0x90 | 0x48)))
+            .or_else(|| bytes.iter().position(Option::is_some))
+            .ok_or_else(|| format!("pattern `{pattern}` has no concrete bytes"))?;
+
+        Ok(Self { bytes, anchor })
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn matches(&self, window: &[u8]) -> bool {
+        window.len() >= self.bytes.len()
+            && self
+                .bytes
+                .iter()
+                .zip(window)
+                .all(|(expected, actual)| expected.is_none_or(|b| b == *actual))
+    }
+
+    pub fn find(&self, haystack: &[u8]) -> Option<usize> {
+        self.find_from(haystack, 0)
+    }
+
+    pub fn find_all(&self, haystack: &[u8]) -> Vec<usize> {
+        let mut found = Vec::new();
+        let mut start = 0;
+        while let Some(offset) = self.find_from(haystack, start) {
+            found.push(offset);
+            start = offset + 1;
+        }
+        found
+    }
+
+    // Compares the anchor byte against 32 candidates at a time into a bitmask, which
+    // compiles down to vector compares, and only checks the full pattern on hits
+    fn find_from(&self, haystack: &[u8], start: usize) -> Option<usize> {
+        let last = haystack.len().checked_sub(self.bytes.len())?;
+        let anchor = self.bytes[self.anchor]?;
+
+        let mut offset = start;
+        while offset <= last {
+            let end = (offset + 32).min(last + 1);
+            let candidates = &haystack[offset + self.anchor..end + self.anchor];
+
+            let mut mask = eq_mask(candidates, anchor);
+            while mask != 0 {
+                let candidate = offset + mask.trailing_zeros() as usize;
+                if self.matches(&haystack[candidate..]) {
+                    return Some(candidate);
+                }
+                mask &= mask - 1;
+            }
+            offset = end;
+        }
+        None
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = String;
+
+    fn from_str(pattern: &str) -> Result<Self, String> {
+        Self::parse(pattern)
+    }
+}
+
+fn eq_mask(bytes: &[u8], needle: u8) -> u32 {
+    if let Ok(chunk) = <&[u8; 32]>::try_from(bytes) {
+        let mut mask = 0u32;
+        for (i, byte) in chunk.iter().enumerate() {
+            mask |= ((*byte == needle) as u32) << i;
+        }
+        return mask;
+    }
+
+    bytes.iter().enumerate().fold(0, |mask, (i, byte)| {
+        mask | (((*byte == needle) as u32) << i)
+    })
+}
+
+fn read<const N: usize>(bytes: &[u8], offset: usize) -> Option<[u8; N]> {
+    bytes.get(offset..offset.checked_add(N)?)?.try_into().ok()
+}
+
+fn u16_at(bytes: &[u8], offset: usize) -> Option<u16> {
+    read(bytes, offset).map(u16::from_le_bytes)
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> Option<u32> {
+    read(bytes, offset).map(u32::from_le_bytes)
+}
+
+fn u64_at(bytes: &[u8], offset: usize) -> Option<u64> {
+    read(bytes, offset).map(u64::from_le_bytes)
+}
+
+const MACHO_MAGIC_64: u32 = 0xfeedfacf;
+
+// How many bytes from the start of an image are needed to read its section or segment
+// table. With a prefix that is too short to tell, returns how much is needed to get
+// further, so callers grow their slice until the result stops changing.
+pub fn headers_len(headers: &[u8]) -> Option<usize> {
+    if headers.starts_with(b"MZ") {
+        let Some(pe) = u32_at(headers, 0x3c) else {
+            return Some(0x40);
+        };
+        let pe = pe as usize;
+        let (Some(sections), Some(optional)) = (u16_at(headers, pe + 6), u16_at(headers, pe + 20))
+        else {
+            return Some(pe + 24);
+        };
+        return Some(pe + 24 + optional as usize + 40 * sections as usize);
+    }
+
+    if headers.starts_with(b"\x7fELF") {
+        let is_64 = headers.get(4) == Some(&2);
+        let fields = if is_64 {
+            (
+                u64_at(headers, 32),
+                u16_at(headers, 54),
+                u16_at(headers, 56),
+            )
+        } else {
+            (
+                u32_at(headers, 28).map(u64::from),
+                u16_at(headers, 42),
+                u16_at(headers, 44),
+            )
+        };
+        let (Some(phoff), Some(phentsize), Some(phnum)) = fields else {
+            return Some(64);
+        };
+        return Some(phoff as usize + phentsize as usize * phnum as usize);
+    }
+
+    if u32_at(headers, 0) == Some(MACHO_MAGIC_64) {
+        let Some(commands) = u32_at(headers, 20) else {
+            return Some(32);
+        };
+        return Some(32 + commands as usize);
+    }
+
+    if headers.len() < 4 { Some(4) } else { None }
+}
+
+// The readable address ranges of an image loaded at `base`, from its PE sections, ELF
+// program headers or Mach-O segments
+pub fn image_ranges(headers: &[u8], base: usize) -> Result<Vec<Range<usize>>, String> {
+    let truncated = || "image headers are truncated".to_owned();
+
+    if headers.starts_with(b"MZ") {
+        const IMAGE_SCN_MEM_READ: u32 = 0x40000000;
+
+        let pe = u32_at(headers, 0x3c).ok_or_else(truncated)? as usize;
+        if headers.get(pe..pe + 4) != Some(b"PE\0\0".as_slice()) {
+            return Err("missing PE signature".to_owned());
+        }
+        let sections = u16_at(headers, pe + 6).ok_or_else(truncated)? as usize;
+        let optional = u16_at(headers, pe + 20).ok_or_else(truncated)? as usize;
+        let table = pe + 24 + optional;
+
+        let mut ranges = Vec::new();
+        for i in 0..sections {
+            let section = table + i * 40;
+            let size = u32_at(headers, section + 8).ok_or_else(truncated)? as usize;
+            let address = u32_at(headers, section + 12).ok_or_else(truncated)? as usize;
+            let flags = u32_at(headers, section + 36).ok_or_else(truncated)?;
+            if flags & IMAGE_SCN_MEM_READ != 0 && size != 0 {
+                ranges.push(base + address..base + address + size);
+            }
+        }
+        return Ok(ranges);
+    }
+
+    if headers.starts_with(b"\x7fELF") {
+        const PT_LOAD: u32 = 1;
+        const PF_R: u32 = 4;
+
+        let is_64 = headers.get(4) == Some(&2);
+        let (phoff, phentsize, phnum) = if is_64 {
+            (
+                u64_at(headers, 32).ok_or_else(truncated)? as usize,
+                u16_at(headers, 54).ok_or_else(truncated)? as usize,
+                u16_at(headers, 56).ok_or_else(truncated)? as usize,
+            )
+        } else {
+            (
+                u32_at(headers, 28).ok_or_else(truncated)? as usize,
+                u16_at(headers, 42).ok_or_else(truncated)? as usize,
+                u16_at(headers, 44).ok_or_else(truncated)? as usize,
+            )
+        };
+
+        let mut segments = Vec::new();
+        for i in 0..phnum {
+            let header = phoff + i * phentsize;
+            let kind = u32_at(headers, header).ok_or_else(truncated)?;
+            let (flags, vaddr, memsz) = if is_64 {
+                (
+                    u32_at(headers, header + 4),
+                    u64_at(headers, header + 16).map(|v| v as usize),
+                    u64_at(headers, header + 40).map(|v| v as usize),
+                )
+            } else {
+                (
+                    u32_at(headers, header + 24),
+                    u32_at(headers, header + 8).map(|v| v as usize),
+                    u32_at(headers, header + 20).map(|v| v as usize),
+                )
+            };
+            let (flags, vaddr, memsz) = (
+                flags.ok_or_else(truncated)?,
+                vaddr.ok_or_else(truncated)?,
+                memsz.ok_or_else(truncated)?,
+            );
+            if kind == PT_LOAD {
+                segments.push((flags, vaddr, memsz));
+            }
+        }
+
+        // `base` is where the first loadable segment got mapped
+        let first = segments.iter().map(|(_, vaddr, _)| vaddr & !0xfff).min();
+        let bias = base.wrapping_sub(first.unwrap_or(0));
+        return Ok(segments
+            .into_iter()
+            .filter(|(flags, _, memsz)| flags & PF_R != 0 && *memsz != 0)
+            .map(|(_, vaddr, memsz)| bias + vaddr..bias + vaddr + memsz)
+            .collect());
+    }
+
+    if u32_at(headers, 0) == Some(MACHO_MAGIC_64) {
+        const LC_SEGMENT_64: u32 = 0x19;
+        const VM_PROT_READ: u32 = 1;
+
+        let count = u32_at(headers, 16).ok_or_else(truncated)?;
+        let mut segments = Vec::new();
+        let mut command = 32;
+        for _ in 0..count {
+            let kind = u32_at(headers, command).ok_or_else(truncated)?;
+            let size = u32_at(headers, command + 4).ok_or_else(truncated)? as usize;
+            if kind == LC_SEGMENT_64 {
+                let name = read::<16>(headers, command + 8).ok_or_else(truncated)?;
+                let vmaddr = u64_at(headers, command + 24).ok_or_else(truncated)? as usize;
+                let vmsize = u64_at(headers, command + 32).ok_or_else(truncated)? as usize;
+                let prot = u32_at(headers, command + 60).ok_or_else(truncated)?;
+                segments.push((name, vmaddr, vmsize, prot));
+            }
+            if size == 0 {
+                break;
+            }
+            command += size;
+        }
+
+        // `base` is the address of the mach header, which is the start of __TEXT
+        let text = segments
+            .iter()
+            .find(|(name, ..)| name.starts_with(b"__TEXT\0"))
+            .map(|(_, vmaddr, ..)| *vmaddr)
+            .ok_or_else(|| "missing __TEXT segment".to_owned())?;
+        let slide = base.wrapping_sub(text);
+        return Ok(segments
+            .into_iter()
+            .filter(|(_, _, vmsize, prot)| prot & VM_PROT_READ != 0 && *vmsize != 0)
+            .map(|(_, vmaddr, vmsize, _)| {
+                let start = vmaddr.wrapping_add(slide);
+                start..start + vmsize
+            })
+            .collect());
+    }
+
+    Err("unknown image format".to_owned())
+}
+
+unsafe fn mapped_headers(base: usize) -> Option<&'static [u8]> {
+    let mut len = 4;
+    loop {
+        let headers = std::slice::from_raw_parts(base as *const u8, len);
+        let needed = headers_len(headers)?;
+        if needed <= len {
+            return Some(headers);
+        }
+        len = needed;
+    }
+}
+
+fn range_cache() -> &'static Mutex<HashMap<Module, Vec<Range<usize>>>> {
+    static RANGES: OnceLock<Mutex<HashMap<Module, Vec<Range<usize>>>>> = OnceLock::new();
+    RANGES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+type MatchCache = Mutex<HashMap<(Module, String), Option<usize>>>;
+
+fn match_cache() -> &'static MatchCache {
+    static MATCHES: OnceLock<MatchCache> = OnceLock::new();
+    MATCHES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+pub fn module_ranges(module: Module) -> Result<Vec<Range<usize>>, String> {
+    if let Some(ranges) = range_cache().lock().unwrap().get(&module) {
+        return Ok(ranges.clone());
+    }
+
+    let base = module.base();
+    if base == 0 {
+        return Err(format!("{module:?} is not loaded"));
+    }
+    let headers = unsafe { mapped_headers(base) }
+        .ok_or_else(|| format!("{module:?} at {base:#x} is not a known image format"))?;
+    let ranges = image_ranges(headers, base)?;
+
+    range_cache().lock().unwrap().insert(module, ranges.clone());
+    Ok(ranges)
+}
+
+fn scan_module(
+    module: Module,
+    pattern: &Pattern,
+    mut on_match: impl FnMut(usize) -> bool,
+) -> Result<(), String> {
+    for range in module_ranges(module)? {
+        let memory = unsafe { std::slice::from_raw_parts(range.start as *const u8, range.len()) };
+        let mut start = 0;
+        while let Some(offset) = pattern.find_from(memory, start) {
+            if !on_match(range.start + offset) {
+                return Ok(());
+            }
+            start = offset + 1;
+        }
+    }
+    Ok(())
+}
+
+// The first match of `pattern` in the module. Results (including misses) are cached per
+// module and pattern, so calling this from a hot path only scans once.
+pub fn find(module: Module, pattern: &str) -> Result<usize, String> {
+    let key = (module, pattern.to_owned());
+    if let Some(cached) = match_cache().lock().unwrap().get(&key) {
+        return cached.ok_or_else(|| format!("pattern `{pattern}` not found in {module:?}"));
+    }
+
+    let parsed = Pattern::parse(pattern)?;
+    let mut found = None;
+    scan_module(module, &parsed, |address| {
+        found = Some(address);
+        false
+    })?;
+
+    match_cache().lock().unwrap().insert(key, found);
+    found.ok_or_else(|| format!("pattern `{pattern}` not found in {module:?}"))
+}
+
+pub fn find_all(module: Module, pattern: &str) -> Result<Vec<usize>, String> {
+    let parsed = Pattern::parse(pattern)?;
+    let mut found = Vec::new();
+    scan_module(module, &parsed, |address| {
+        found.push(address);
+        true
+    })?;
+    Ok(found)
+}
+
+// Finds `pattern` and follows the branch at `offset` bytes into the match
+pub fn find_branch_target(module: Module, pattern: &str, offset: usize) -> Result<usize, String> {
+    let address = find(module, pattern)? + offset;
+    unsafe { branch_target(address) }
+        .ok_or_else(|| format!("no branch at {address:#x} for pattern `{pattern}`"))
+}
+
+// Finds `pattern` and resolves the rip-relative operand of the instruction at `offset`
+pub fn find_rip_relative(
+    module: Module,
+    pattern: &str,
+    offset: usize,
+    disp_offset: usize,
+    insn_len: usize,
+) -> Result<usize, String> {
+    let address = find(module, pattern)? + offset;
+    unsafe { rip_relative(address, disp_offset, insn_len) }
+        .ok_or_else(|| format!("no rip-relative operand at {address:#x}"))
+}
+
+pub fn clear_cache() {
+    range_cache().lock().unwrap().clear();
+    match_cache().lock().unwrap().clear();
+}
+
+// `code` is the instruction at `address`, the 32-bit displacement at `disp_offset` is
+// relative to the end of the instruction
+pub fn rel32_target(
+    code: &[u8],
+    address: usize,
+    disp_offset: usize,
+    insn_len: usize,
+) -> Option<usize> {
+    let disp = i32::from_le_bytes(read(code, disp_offset)?);
+    Some(
+        address
+            .wrapping_add(insn_len)
+            .wrapping_add_signed(disp as isize),
+    )
+}
+
+// x86 `call`/`jmp rel32`, `jmp rel8` and `jcc rel32`
+pub fn x86_branch_target(code: &[u8], address: usize) -> Option<usize> {
+    match code {
+        [0xe8 | 0xe9, ..] => rel32_target(code, address, 1, 5),
+        [0xeb, rel, ..] => Some(
+            address
+                .wrapping_add(2)
+                .wrapping_add_signed(*rel as i8 as isize),
+        ),
+        [0x0f, 0x80..=0x8f, ..] => rel32_target(code, address, 2, 6),
+        _ => None,
+    }
+}
+
+// ARM64 `b`/`bl`
+pub fn arm64_branch_target(code: &[u8], address: usize) -> Option<usize> {
+    let insn = u32_at(code, 0)?;
+    if insn & 0x7c000000 != 0x14000000 {
+        return None;
+    }
+    // sign extend the 26-bit word offset
+    let offset = ((insn << 6) as i32 >> 6) as isize * 4;
+    Some(address.wrapping_add_signed(offset))
+}
+
+// ARM64 `adrp` followed by the `add` or 64-bit `ldr` that completes the address
+pub fn arm64_adrp_target(code: &[u8], address: usize) -> Option<usize> {
+    let adrp = u32_at(code, 0)?;
+    if adrp & 0x9f000000 != 0x90000000 {
+        return None;
+    }
+    let immlo = (adrp >> 29) & 0x3;
+    let immhi = (adrp >> 5) & 0x7ffff;
+    let pages = ((((immhi << 2) | immlo) << 11) as i32 >> 11) as isize;
+    let page = (address & !0xfff).wrapping_add_signed(pages << 12);
+
+    let Some(next) = u32_at(code, 4) else {
+        return Some(page);
+    };
+    let imm12 = ((next >> 10) & 0xfff) as usize;
+    if next & 0xff800000 == 0x91000000 {
+        let shift = if next & (1 << 22) != 0 { 12 } else { 0 };
+        Some(page + (imm12 << shift))
+    } else if next & 0xffc00000 == 0xf9400000 {
+        Some(page + imm12 * 8)
+    } else {
+        Some(page)
+    }
+}
+
+// ARMv7 Thumb-2 `b.w`/`bl`, the target keeps the thumb bit
+pub fn thumb_branch_target(code: &[u8], address: usize) -> Option<usize> {
+    let first = u16_at(code, 0)? as u32;
+    let second = u16_at(code, 2)? as u32;
+    if first & 0xf800 != 0xf000 || second & 0xd000 != 0x9000 && second & 0xd000 != 0xd000 {
+        return None;
+    }
+
+    let s = (first >> 10) & 1;
+    let i1 = !(((second >> 13) & 1) ^ s) & 1;
+    let i2 = !(((second >> 11) & 1) ^ s) & 1;
+    let imm10 = first & 0x3ff;
+    let imm11 = second & 0x7ff;
+    let offset = (s << 24) | (i1 << 23) | (i2 << 22) | (imm10 << 12) | (imm11 << 1);
+    let offset = ((offset << 7) as i32 >> 7) as isize;
+    Some(((address & !1) + 4).wrapping_add_signed(offset) | 1)
+}
+
+// The target of the relative branch at `address`, decoded for the current architecture
+pub unsafe fn branch_target(address: usize) -> Option<usize> {
+    let code = std::slice::from_raw_parts(address as *const u8, 8);
+    #[cfg(target_arch = "aarch64")]
+    {
+        arm64_branch_target(code, address).or_else(|| arm64_adrp_target(code, address))
+    }
+    #[cfg(target_arch = "arm")]
+    {
+        thumb_branch_target(code, address)
+    }
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
+    {
+        x86_branch_target(code, address)
+    }
+}
+
+pub unsafe fn rip_relative(address: usize, disp_offset: usize, insn_len: usize) -> Option<usize> {
+    let code = std::slice::from_raw_parts(address as *const u8, disp_offset + 4);
+    rel32_target(code, address, disp_offset, insn_len)
+}
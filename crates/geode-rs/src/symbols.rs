@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::base::{Module, SymbolScope};
+use crate::loader::HookFunction;
+
+// Asked before the platform's own symbol lookup. With one installed, bindings, hooks and
+// loader calls resolve to whatever it hands out, which is how they run against Rust fakes
+// in `cargo test` on a host that has no game or Geode loaded.
+pub trait SymbolProvider: Send + Sync {
+    // `name` is the symbol as the platform spells it, without the trailing nul
+    fn resolve(&self, scope: SymbolScope, name: &str) -> Option<usize>;
+
+    fn module_base(&self, _module: Module) -> Option<usize> {
+        None
+    }
+}
+
+static PROVIDER: RwLock<Option<Box<dyn SymbolProvider>>> = RwLock::new(None);
+// keeps the lookup down to one atomic load when nothing is installed, which is always
+// the case in the game
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+// Provided addresses are never written to the per-binding caches, so a provider can be
+// swapped out between tests. Misses still fall through to the platform lookup.
+pub fn set_symbol_provider(provider: impl SymbolProvider + 'static) {
+    *PROVIDER.write().unwrap() = Some(Box::new(provider));
+    INSTALLED.store(true, Ordering::Release);
+}
+
+pub fn clear_symbol_provider() {
+    INSTALLED.store(false, Ordering::Release);
+    *PROVIDER.write().unwrap() = None;
+}
+
+pub fn has_symbol_provider() -> bool {
+    INSTALLED.load(Ordering::Acquire)
+}
+
+pub fn provided_symbol(scope: SymbolScope, name: &[u8]) -> Option<usize> {
+    if !has_symbol_provider() {
+        return None;
+    }
+    let name = name.strip_suffix(b"\0").unwrap_or(name);
+    let name = std::str::from_utf8(name).ok()?;
+    let provider = PROVIDER.read().unwrap();
+    provider
+        .as_ref()?
+        .resolve(scope, name)
+        .filter(|&address| address != 0)
+}
+
+pub fn provided_module_base(module: Module) -> Option<usize> {
+    if !has_symbol_provider() {
+        return None;
+    }
+    let provider = PROVIDER.read().unwrap();
+    provider
+        .as_ref()?
+        .module_base(module)
+        .filter(|&base| base != 0)
+}
+
+// A provider backed by plain maps, for tests. Fakes are `extern "C"` functions registered
+// under the symbol the binding looks up: `TestSymbols::new().symbol(name, fake as usize)`
+#[derive(Clone, Debug, Default)]
+pub struct TestSymbols {
+    symbols: HashMap<String, usize>,
+    scoped: HashMap<(SymbolScope, String), usize>,
+    bases: HashMap<Module, usize>,
+}
+
+impl TestSymbols {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // resolves in every scope
+    pub fn symbol(mut self, name: impl Into<String>, address: usize) -> Self {
+        self.symbols.insert(name.into(), address);
+        self
+    }
+
+    // only resolves in `scope`, and takes precedence over `symbol` there
+    pub fn symbol_in(
+        mut self,
+        scope: SymbolScope,
+        name: impl Into<String>,
+        address: usize,
+    ) -> Self {
+        self.scoped.insert((scope, name.into()), address);
+        self
+    }
+
+    pub fn function<F: HookFunction>(self, name: impl Into<String>, function: F) -> Self {
+        self.symbol(name, function.as_ptr() as usize)
+    }
+
+    pub fn function_in<F: HookFunction>(
+        self,
+        scope: SymbolScope,
+        name: impl Into<String>,
+        function: F,
+    ) -> Self {
+        self.symbol_in(scope, name, function.as_ptr() as usize)
+    }
+
+    // what `get()`, `get_geode()` and `Module::base` return for `module`, so offset-based
+    // bindings and patches can point into a buffer owned by the test
+    pub fn base(mut self, module: Module, base: usize) -> Self {
+        self.bases.insert(module, base);
+        self
+    }
+
+    pub fn install(self) {
+        set_symbol_provider(self);
+    }
+}
+
+impl SymbolProvider for TestSymbols {
+    fn resolve(&self, scope: SymbolScope, name: &str) -> Option<usize> {
+        self.scoped
+            .get(&(scope, name.to_owned()))
+            .or_else(|| self.symbols.get(name))
+            .copied()
+    }
+
+    fn module_base(&self, module: Module) -> Option<usize> {
+        self.bases.get(&module).copied()
+    }
+}
@@ -1,5 +1,6 @@
 use crate::stl::{StlSharedPtr, StlVector};
 use std::ffi::c_void;
+use std::marker::PhantomData;
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -67,6 +68,14 @@ impl AbstractFunction {
             m_parameters: StlVector::new(),
         }
     }
+
+    pub fn from_types(return_type: AbstractType, params: &[AbstractType]) -> Self {
+        let mut parameters = StlVector::new();
+        for param in params {
+            parameters.push_back(*param);
+        }
+        Self::new(return_type, parameters)
+    }
 }
 
 impl Default for AbstractFunction {
@@ -77,17 +86,12 @@ impl Default for AbstractFunction {
 
 impl Clone for AbstractFunction {
     fn clone(&self) -> Self {
-        Self {
-            m_return: AbstractType {
-                m_size: self.m_return.m_size,
-                m_kind: self.m_return.m_kind,
-            },
-            m_parameters: StlVector::new(),
-        }
+        Self::from_types(self.m_return, &self.m_parameters)
     }
 }
 
 #[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct AbstractType {
     pub m_size: usize,
     pub m_kind: AbstractTypeKind,
@@ -125,6 +129,10 @@ impl AbstractType {
     pub const fn other(size: usize) -> Self {
         Self::new(size, AbstractTypeKind::Other)
     }
+
+    pub const fn of<T: HasAbstractType>() -> Self {
+        T::ABSTRACT_TYPE
+    }
 }
 
 impl Default for AbstractType {
@@ -133,6 +141,120 @@ impl Default for AbstractType {
     }
 }
 
+// Types tulip has to treat as something other than a struct of their size: integers and
+// pointers go in general purpose registers, floats in vector registers
+pub trait HasAbstractType {
+    const ABSTRACT_TYPE: AbstractType;
+}
+
+impl HasAbstractType for () {
+    const ABSTRACT_TYPE: AbstractType = AbstractType::VOID;
+}
+
+macro_rules! impl_abstract_type {
+    ($kind:ident: $($ty:ty),*) => {
+        $(impl HasAbstractType for $ty {
+            const ABSTRACT_TYPE: AbstractType = AbstractType::$kind(size_of::<$ty>());
+        })*
+    };
+}
+
+impl_abstract_type!(primitive: bool, i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
+impl_abstract_type!(floating_point: f32, f64);
+
+impl<T: ?Sized> HasAbstractType for *const T {
+    const ABSTRACT_TYPE: AbstractType = AbstractType::primitive(size_of::<*const T>());
+}
+
+impl<T: ?Sized> HasAbstractType for *mut T {
+    const ABSTRACT_TYPE: AbstractType = AbstractType::primitive(size_of::<*mut T>());
+}
+
+impl<T: ?Sized> HasAbstractType for &T {
+    const ABSTRACT_TYPE: AbstractType = AbstractType::primitive(size_of::<&T>());
+}
+
+impl<T: ?Sized> HasAbstractType for &mut T {
+    const ABSTRACT_TYPE: AbstractType = AbstractType::primitive(size_of::<&mut T>());
+}
+
+impl<T> HasAbstractType for Option<&T> {
+    const ABSTRACT_TYPE: AbstractType = AbstractType::primitive(size_of::<usize>());
+}
+
+impl<T> HasAbstractType for Option<&mut T> {
+    const ABSTRACT_TYPE: AbstractType = AbstractType::primitive(size_of::<usize>());
+}
+
+macro_rules! impl_abstract_type_fn {
+    ($($arg:ident),*) => {
+        impl<R, $($arg),*> HasAbstractType for extern "C" fn($($arg),*) -> R {
+            const ABSTRACT_TYPE: AbstractType = AbstractType::primitive(size_of::<usize>());
+        }
+        impl<R, $($arg),*> HasAbstractType for Option<extern "C" fn($($arg),*) -> R> {
+            const ABSTRACT_TYPE: AbstractType = AbstractType::primitive(size_of::<usize>());
+        }
+    };
+}
+
+impl_abstract_type_fn!();
+impl_abstract_type_fn!(A0);
+impl_abstract_type_fn!(A0, A1);
+impl_abstract_type_fn!(A0, A1, A2);
+impl_abstract_type_fn!(A0, A1, A2, A3);
+impl_abstract_type_fn!(A0, A1, A2, A3, A4);
+impl_abstract_type_fn!(A0, A1, A2, A3, A4, A5);
+
+// cocos value types that bound functions take and return by value
+impl HasAbstractType for crate::types::CCPoint {
+    const ABSTRACT_TYPE: AbstractType = AbstractType::other(size_of::<Self>());
+}
+
+impl HasAbstractType for crate::types::CCSize {
+    const ABSTRACT_TYPE: AbstractType = AbstractType::other(size_of::<Self>());
+}
+
+impl HasAbstractType for crate::types::CCRect {
+    const ABSTRACT_TYPE: AbstractType = AbstractType::other(size_of::<Self>());
+}
+
+// Generated detours and `fn_marker!` use `(&&TypeProbe::<T>::new()).abstract_type()`, which picks
+// `HasAbstractType` when `T` implements it and treats everything else (`CCPoint`, strings
+// and other records passed by value) as a struct of its size.
+pub struct TypeProbe<T>(PhantomData<T>);
+
+impl<T> TypeProbe<T> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for TypeProbe<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub trait KnownAbstractType {
+    fn abstract_type(&self) -> AbstractType;
+}
+
+impl<T: HasAbstractType> KnownAbstractType for &TypeProbe<T> {
+    fn abstract_type(&self) -> AbstractType {
+        T::ABSTRACT_TYPE
+    }
+}
+
+pub trait RecordAbstractType {
+    fn abstract_type(&self) -> AbstractType;
+}
+
+impl<T> RecordAbstractType for TypeProbe<T> {
+    fn abstract_type(&self) -> AbstractType {
+        AbstractType::other(size_of::<T>())
+    }
+}
+
 #[repr(i32)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum TulipConvention {
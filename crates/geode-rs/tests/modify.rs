@@ -0,0 +1,127 @@
+use geode_rs::classes::CCNode;
+use geode_rs::modify;
+
+#[modify(CCNode)]
+struct NodeFields {
+    calls: Vec<&'static str>,
+}
+
+#[modify(CCNode, unique)]
+struct UniqueNodeFields {
+    entered: u32,
+}
+
+#[modify(CCNode, unique)]
+impl UniqueNodeFields {
+    fn on_enter(&mut self, this: &mut CCNode) {
+        self.entered += 1;
+        // stands in for the original running the same hook on this object again
+        if self.entered == 1 {
+            __detour_cc_node_on_enter(this);
+        }
+    }
+}
+
+#[test]
+fn test_nested_borrow_fails() {
+    // non-unique storage uses one key for every instance
+    let mut outer = NodeFields::get_or_default(0x1000 as *mut CCNode).unwrap();
+    outer.calls.push("outer");
+    assert!(NodeFields::get(0x2000 as *mut CCNode).is_none());
+    assert!(NodeFields::get_or_default(0x2000 as *mut CCNode).is_none());
+    assert!(NodeFields::with_fields(0x2000 as *mut CCNode, |_| ()).is_none());
+    drop(outer);
+
+    let fields = NodeFields::get(0x3000 as *mut CCNode).unwrap();
+    assert_eq!(fields.calls, ["outer"]);
+}
+
+#[test]
+fn test_removal_while_borrowed() {
+    let this = 0x4000 as *mut CCNode;
+    // freed while the guard is alive, the slot goes once it drops
+    {
+        let _held = UniqueNodeFields::get_or_default(this).unwrap();
+        UniqueNodeFields::free(this);
+    }
+    assert!(UniqueNodeFields::get(this).is_none());
+}
+
+#[test]
+fn test_fields_borrowed_by_another_thread() {
+    let this = 0x5000 as *mut CCNode;
+    let held = UniqueNodeFields::get_or_default(this);
+    let key = this as usize;
+    let other = std::thread::spawn(move || UniqueNodeFields::get(key as *mut CCNode).is_some());
+    assert!(!other.join().unwrap());
+    drop(held);
+    assert!(UniqueNodeFields::get(this).is_some());
+}
+
+static ORIGINAL_CALLS: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+
+extern "C" fn original_on_enter(_this: *mut CCNode) {
+    ORIGINAL_CALLS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+}
+
+// macOS and iOS bind onEnter by offset, there's no symbol to point at the fake
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+#[test]
+fn test_nested_detour_steps_aside() {
+    let original = original_on_enter as extern "C" fn(*mut CCNode);
+    geode_rs::symbols::TestSymbols::new()
+        .function("_ZN7cocos2d6CCNode7onEnterEv", original)
+        .function("?onEnter@CCNode@cocos2d@@UEAAXXZ", original)
+        .install();
+
+    // the nested call finds the fields borrowed by the outer one and goes straight on to the
+    // original instead of handing out a second `&mut self`
+    let mut node: CCNode = unsafe { std::mem::zeroed() };
+    let this = &mut node as *mut CCNode;
+    __detour_cc_node_on_enter(this);
+    assert_eq!(UniqueNodeFields::get(this).unwrap().entered, 1);
+    assert_eq!(ORIGINAL_CALLS.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
+
+static REACHED_SITE: geode_rs::modify::HookSite =
+    geode_rs::modify::HookSite::new("reached", || 0x9000, None, None);
+
+#[test]
+fn test_panic_after_original_returns_default() {
+    use geode_rs::modify::{note_original, run_detour};
+
+    let this = 0x7000 as *const std::ffi::c_void;
+    let other = 0x8000 as *const std::ffi::c_void;
+
+    // the original is only called again if the detour hadn't reached it
+    let before = run_detour(
+        &REACHED_SITE,
+        this,
+        || -> i32 { panic!("before") },
+        Some(|| 5),
+    );
+    assert_eq!(before, 5);
+
+    let after = run_detour(
+        &REACHED_SITE,
+        this,
+        || -> i32 {
+            note_original(0x9000, this);
+            panic!("after")
+        },
+        Some(|| 5),
+    );
+    assert_eq!(after, 0);
+
+    // calling the same method on another object doesn't reach this detour's original
+    let other_object = run_detour(
+        &REACHED_SITE,
+        this,
+        || -> i32 {
+            note_original(0x9000, other);
+            panic!("other object")
+        },
+        Some(|| 5),
+    );
+    assert_eq!(other_object, 5);
+}
@@ -0,0 +1,134 @@
+use geode_rs::patch::PatchBuilder;
+use geode_rs::scan::{arm64_branch_target, thumb_branch_target, x86_branch_target};
+
+#[test]
+fn test_values() {
+    let patch = PatchBuilder::new(0x1000)
+        .value(0x11223344u32)
+        .value(-2i16)
+        .value(1.5f32)
+        .value(7u8);
+    assert_eq!(
+        patch.as_bytes(),
+        [
+            0x44, 0x33, 0x22, 0x11, 0xfe, 0xff, 0x00, 0x00, 0xc0, 0x3f, 0x07
+        ]
+    );
+}
+
+#[test]
+fn test_x86_branches() {
+    // each branch is encoded against where it lands in the patch
+    let patch = PatchBuilder::new(0x140001000)
+        .jmp(0x140002000)
+        .call(0x140000800)
+        .bytes(&[0x90]);
+    let bytes = patch.as_bytes();
+    assert_eq!(bytes[..5], [0xe9, 0xfb, 0x0f, 0x00, 0x00]);
+    assert_eq!(bytes[5], 0xe8);
+    assert_eq!(
+        x86_branch_target(&bytes[..5], 0x140001000),
+        Some(0x140002000)
+    );
+    assert_eq!(
+        x86_branch_target(&bytes[5..], 0x140001005),
+        Some(0x140000800)
+    );
+
+    let err = PatchBuilder::new(0x1000)
+        .jmp(0x1000 + (1 << 32))
+        .create()
+        .err()
+        .expect("patch should fail");
+    assert!(err.contains("out of rel32 range"), "{err}");
+}
+
+#[test]
+fn test_arm64_branches() {
+    let patch = PatchBuilder::new(0x100000)
+        .arm64_b(0x100008)
+        .arm64_bl(0x0ffffc);
+    let bytes = patch.as_bytes();
+    assert_eq!(bytes[..4], 0x14000002u32.to_le_bytes());
+    assert_eq!(bytes[4..], 0x97fffffeu32.to_le_bytes());
+    assert_eq!(arm64_branch_target(&bytes[..4], 0x100000), Some(0x100008));
+    assert_eq!(arm64_branch_target(&bytes[4..], 0x100004), Some(0x0ffffc));
+
+    for target in [0x1000_0000 + (1 << 27), 0x1000_0002] {
+        let err = PatchBuilder::new(0x1000_0000)
+            .arm64_b(target)
+            .create()
+            .err()
+            .expect("patch should fail");
+        assert!(err.contains("out of b/bl range"), "{err}");
+    }
+}
+
+#[test]
+fn test_thumb_branches() {
+    // thumb addresses carry the low bit, the decoder hands it back set
+    for (from, to) in [
+        (0x200001, 0x200101),
+        (0x200001, 0x1ff001),
+        (0x400001, 0x400001 + 0xfffffe),
+        (0x1400001, 0x400009),
+    ] {
+        let patch = PatchBuilder::new(from).armv7_b(to).armv7_bl(to);
+        let bytes = patch.as_bytes();
+        assert_eq!(
+            thumb_branch_target(&bytes[..4], from),
+            Some(to),
+            "b.w {from:#x} -> {to:#x}"
+        );
+        assert_eq!(
+            thumb_branch_target(&bytes[4..], from + 4),
+            Some(to),
+            "bl {from:#x} -> {to:#x}"
+        );
+    }
+    // b.w #0 and bl #0
+    let patch = PatchBuilder::new(0x1000).armv7_b(0x1004).armv7_bl(0x1008);
+    assert_eq!(
+        patch.as_bytes(),
+        [0x00, 0xf0, 0x00, 0xb8, 0x00, 0xf0, 0x00, 0xf8]
+    );
+
+    let err = PatchBuilder::new(0x1000_0001)
+        .armv7_b(0x1000_0001 + (1 << 24) + 4)
+        .create()
+        .err()
+        .expect("patch should fail");
+    assert!(err.contains("out of b.w/bl range"), "{err}");
+}
+
+#[test]
+fn test_nops() {
+    let patch = PatchBuilder::new(0x1000).nop(4).branch(0x1000);
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        assert_eq!(patch.as_bytes()[..4], 0xd503201fu32.to_le_bytes());
+        assert_eq!(
+            arm64_branch_target(&patch.as_bytes()[4..], 0x1004),
+            Some(0x1000)
+        );
+        assert!(PatchBuilder::new(0x1000).nop(6).create().is_err());
+    }
+    #[cfg(target_arch = "arm")]
+    {
+        assert_eq!(patch.as_bytes()[..4], [0x00, 0xbf, 0x00, 0xbf]);
+        assert_eq!(
+            thumb_branch_target(&patch.as_bytes()[4..], 0x1004),
+            Some(0x1001)
+        );
+        assert!(PatchBuilder::new(0x1000).nop(3).create().is_err());
+    }
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
+    {
+        assert_eq!(patch.as_bytes()[..4], [0x90; 4]);
+        assert_eq!(
+            x86_branch_target(&patch.as_bytes()[4..], 0x1004),
+            Some(0x1000)
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use geode_rs::scan::{
+    Pattern, arm64_adrp_target, arm64_branch_target, headers_len, image_ranges,
+    thumb_branch_target, x86_branch_target,
+};
+
+#[test]
+fn test_parse_pattern() {
+    let pattern = Pattern::parse("48 8B ?? ? E8").expect("failed to parse pattern");
+    assert_eq!(pattern.len(), 5);
+    assert!(pattern.matches(&[0x48, 0x8b, 0x01, 0x02, 0xe8]));
+    assert!(!pattern.matches(&[0x48, 0x8b, 0x01, 0x02, 0xe9]));
+    assert!(!pattern.matches(&[0x48, 0x8b]));
+
+    assert!(Pattern::parse("48 8G").is_err());
+    assert!(Pattern::parse("488B").is_err());
+    assert!(Pattern::parse("?? ??").is_err());
+    assert!(Pattern::parse("").is_err());
+}
+
+#[test]
+fn test_find_pattern() {
+    let mut haystack = vec![0xccu8; 200];
+    haystack[70..75].copy_from_slice(&[0x48, 0x8b, 0x05, 0x10, 0xe8]);
+    haystack[150..155].copy_from_slice(&[0x48, 0x8b, 0x0d, 0x20, 0xe8]);
+    // a near miss right before the first match
+    haystack[60..64].copy_from_slice(&[0x48, 0x8b, 0x05, 0x10]);
+
+    let pattern: Pattern = "48 8B ?? ?? E8".parse().unwrap();
+    assert_eq!(pattern.find(&haystack), Some(70));
+    assert_eq!(pattern.find_all(&haystack), vec![70, 150]);
+
+    let pattern = Pattern::parse("E8 CC").unwrap();
+    assert_eq!(pattern.find_all(&haystack), vec![74, 154]);
+    assert_eq!(Pattern::parse("E9").unwrap().find(&haystack), None);
+}
+
+#[test]
+fn test_find_pattern_at_edges() {
+    let pattern = Pattern::parse("?? 12 34").unwrap();
+    for len in [3, 31, 32, 33, 64, 65] {
+        let mut haystack = vec![0u8; len];
+        haystack[len - 2] = 0x12;
+        haystack[len - 1] = 0x34;
+        assert_eq!(pattern.find(&haystack), Some(len - 3), "len {len}");
+    }
+    assert_eq!(pattern.find(&[0x12, 0x34]), None);
+}
+
+#[test]
+fn test_x86_branch_target() {
+    // call -5 and jmp +0x100 at 0x1000
+    assert_eq!(
+        x86_branch_target(&[0xe8, 0xfb, 0xff, 0xff, 0xff], 0x1000),
+        Some(0x1000)
+    );
+    assert_eq!(
+        x86_branch_target(&[0xe9, 0x00, 0x01, 0x00, 0x00], 0x1000),
+        Some(0x1105)
+    );
+    assert_eq!(x86_branch_target(&[0xeb, 0xfe], 0x1000), Some(0x1000));
+    assert_eq!(
+        x86_branch_target(&[0x0f, 0x84, 0x10, 0x00, 0x00, 0x00], 0x1000),
+        Some(0x1016)
+    );
+    assert_eq!(x86_branch_target(&[0x90, 0x90], 0x1000), None);
+}
+
+#[test]
+fn test_arm64_branch_target() {
+    // b #8 and bl #-8
+    assert_eq!(
+        arm64_branch_target(&0x14000002u32.to_le_bytes(), 0x100000),
+        Some(0x100008)
+    );
+    assert_eq!(
+        arm64_branch_target(&0x97fffffeu32.to_le_bytes(), 0x100004),
+        Some(0x0ffffc)
+    );
+    assert_eq!(
+        arm64_branch_target(&0xd503201fu32.to_le_bytes(), 0x100000),
+        None
+    );
+
+    // adrp x8, #0x2000 ; add x8, x8, #0x10
+    let mut bytes = 0xd0000008u32.to_le_bytes().to_vec();
+    bytes.extend_from_slice(&0x91004108u32.to_le_bytes());
+    assert_eq!(arm64_adrp_target(&bytes, 0x100123), Some(0x102010));
+}
+
+#[test]
+fn test_thumb_branch_target() {
+    // bl and b.w encoded at 0x100008 and 0x10000c
+    assert_eq!(
+        thumb_branch_target(&[0x00, 0xf0, 0xfa, 0xff], 0x100008),
+        Some(0x101001)
+    );
+    assert_eq!(
+        thumb_branch_target(&[0xff, 0xf7, 0xf8, 0xbf], 0x10000c),
+        Some(0x100001)
+    );
+    assert_eq!(
+        thumb_branch_target(&[0x00, 0xbf, 0x00, 0xbf], 0x100000),
+        None
+    );
+}
+
+#[test]
+fn test_elf_image_ranges() {
+    let mut headers = vec![0u8; 64 + 3 * 56];
+    headers[..4].copy_from_slice(b"\x7fELF");
+    headers[4] = 2;
+    headers[32..40].copy_from_slice(&64u64.to_le_bytes());
+    headers[54..56].copy_from_slice(&56u16.to_le_bytes());
+    headers[56..58].copy_from_slice(&3u16.to_le_bytes());
+
+    // PT_LOAD r-x, PT_LOAD rw-, PT_DYNAMIC
+    for (i, (kind, flags, vaddr, memsz)) in [
+        (1u32, 5u32, 0u64, 0x1000u64),
+        (1, 6, 0x3000, 0x800),
+        (2, 6, 0x3100, 0x100),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let header = 64 + i * 56;
+        headers[header..header + 4].copy_from_slice(&kind.to_le_bytes());
+        headers[header + 4..header + 8].copy_from_slice(&flags.to_le_bytes());
+        headers[header + 16..header + 24].copy_from_slice(&vaddr.to_le_bytes());
+        headers[header + 40..header + 48].copy_from_slice(&memsz.to_le_bytes());
+    }
+
+    assert_eq!(headers_len(&headers[..4]), Some(64));
+    assert_eq!(headers_len(&headers), Some(headers.len()));
+    let ranges = image_ranges(&headers, 0x7000_0000).unwrap();
+    assert_eq!(
+        ranges,
+        vec![0x7000_0000..0x7000_1000, 0x7000_3000..0x7000_3800]
+    );
+}
+
+#[test]
+fn test_pe_image_ranges() {
+    let mut headers = vec![0u8; 0x80 + 24 + 0xf0 + 2 * 40];
+    headers[..2].copy_from_slice(b"MZ");
+    headers[0x3c..0x40].copy_from_slice(&0x80u32.to_le_bytes());
+    headers[0x80..0x84].copy_from_slice(b"PE\0\0");
+    headers[0x86..0x88].copy_from_slice(&2u16.to_le_bytes());
+    headers[0x94..0x96].copy_from_slice(&0xf0u16.to_le_bytes());
+
+    // .text readable, .reloc discardable without read access
+    for (i, (size, address, flags)) in [
+        (0x2000u32, 0x1000u32, 0x6000_0020u32),
+        (0x100, 0x3000, 0x0200_0000),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let section = 0x80 + 24 + 0xf0 + i * 40;
+        headers[section + 8..section + 12].copy_from_slice(&size.to_le_bytes());
+        headers[section + 12..section + 16].copy_from_slice(&address.to_le_bytes());
+        headers[section + 36..section + 40].copy_from_slice(&flags.to_le_bytes());
+    }
+
+    assert_eq!(headers_len(&headers[..0x40]), Some(0x80 + 24));
+    assert_eq!(headers_len(&headers), Some(headers.len()));
+    let ranges = image_ranges(&headers, 0x1_4000_0000).unwrap();
+    assert_eq!(ranges, vec![0x1_4000_1000..0x1_4000_3000]);
+}
+
+#[test]
+fn test_macho_image_ranges() {
+    let mut headers = vec![0u8; 32 + 3 * 72];
+    headers[..4].copy_from_slice(&0xfeedfacfu32.to_le_bytes());
+    headers[16..20].copy_from_slice(&3u32.to_le_bytes());
+    headers[20..24].copy_from_slice(&(3u32 * 72).to_le_bytes());
+
+    for (i, (name, vmaddr, vmsize, prot)) in [
+        (&b"__PAGEZERO"[..], 0u64, 0x1_0000_0000u64, 0u32),
+        (b"__TEXT", 0x1_0000_0000, 0x4000, 5),
+        (b"__DATA", 0x1_0000_4000, 0x1000, 3),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let command = 32 + i * 72;
+        headers[command..command + 4].copy_from_slice(&0x19u32.to_le_bytes());
+        headers[command + 4..command + 8].copy_from_slice(&72u32.to_le_bytes());
+        headers[command + 8..command + 8 + name.len()].copy_from_slice(name);
+        headers[command + 24..command + 32].copy_from_slice(&vmaddr.to_le_bytes());
+        headers[command + 32..command + 40].copy_from_slice(&vmsize.to_le_bytes());
+        headers[command + 60..command + 64].copy_from_slice(&prot.to_le_bytes());
+    }
+
+    assert_eq!(headers_len(&headers), Some(headers.len()));
+    let ranges = image_ranges(&headers, 0x1_0a00_0000).unwrap();
+    assert_eq!(
+        ranges,
+        vec![0x1_0a00_0000..0x1_0a00_4000, 0x1_0a00_4000..0x1_0a00_5000]
+    );
+
+    assert!(image_ranges(b"\0\0\0\0", 0).is_err());
+}
@@ -0,0 +1,80 @@
+use std::sync::atomic::AtomicUsize;
+
+use geode_rs::base::{
+    Module, SymbolScope, android_resolve_symbol_abs, resolve_dylib_symbol_abs, resolve_symbol,
+    resolve_windows_symbol_abs,
+};
+use geode_rs::symbols::{SymbolProvider, TestSymbols, clear_symbol_provider, set_symbol_provider};
+
+extern "C" fn fake_add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+extern "C" fn fake_geode_add(a: i32, b: i32) -> i32 {
+    a * b
+}
+
+struct Fixed;
+
+impl SymbolProvider for Fixed {
+    fn resolve(&self, _scope: SymbolScope, name: &str) -> Option<usize> {
+        (name == "fixed").then_some(0x1234)
+    }
+}
+
+// one test, the provider is process-wide
+#[test]
+fn test_symbol_provider() {
+    static SLOT: AtomicUsize = AtomicUsize::new(0);
+
+    assert_eq!(
+        resolve_symbol(SymbolScope::Process, b"_Z3addii\0", &SLOT),
+        0
+    );
+
+    TestSymbols::new()
+        .function("_Z3addii", fake_add as extern "C" fn(i32, i32) -> i32)
+        .function_in(
+            SymbolScope::Geode,
+            "_Z3addii",
+            fake_geode_add as extern "C" fn(i32, i32) -> i32,
+        )
+        .base(Module::Game, 0x10000)
+        .install();
+
+    // a miss before the provider was installed doesn't stick
+    let addr = resolve_symbol(SymbolScope::Process, b"_Z3addii\0", &SLOT);
+    assert_eq!(addr, fake_add as extern "C" fn(i32, i32) -> i32 as usize);
+    let add: extern "C" fn(i32, i32) -> i32 = unsafe { std::mem::transmute(addr) };
+    assert_eq!(add(2, 3), 5);
+
+    let addr = resolve_symbol(SymbolScope::Geode, b"_Z3addii\0", &SLOT);
+    let mul: extern "C" fn(i32, i32) -> i32 = unsafe { std::mem::transmute(addr) };
+    assert_eq!(mul(2, 3), 6);
+
+    assert_ne!(android_resolve_symbol_abs(b"_Z3addii\0", &SLOT), 0);
+    assert_ne!(resolve_dylib_symbol_abs(b"_Z3addii\0", &SLOT), 0);
+    assert_ne!(resolve_windows_symbol_abs(0, b"_Z3addii\0", &SLOT), 0);
+    assert_eq!(
+        resolve_symbol(SymbolScope::Process, b"_Z3subii\0", &SLOT),
+        0
+    );
+
+    assert_eq!(Module::Game.base(), 0x10000);
+    assert_eq!(geode_rs::base::get(), 0x10000);
+    assert_eq!(Module::Geode.base(), 0);
+
+    set_symbol_provider(Fixed);
+    assert_eq!(
+        resolve_symbol(SymbolScope::Cocos, b"fixed\0", &SLOT),
+        0x1234
+    );
+    assert_eq!(
+        resolve_symbol(SymbolScope::Process, b"_Z3addii\0", &SLOT),
+        0
+    );
+
+    clear_symbol_provider();
+    assert_eq!(resolve_symbol(SymbolScope::Cocos, b"fixed\0", &SLOT), 0);
+    assert_eq!(Module::Game.base(), 0);
+}
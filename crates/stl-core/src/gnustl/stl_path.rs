@@ -11,6 +11,7 @@ impl path {
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(data: &str) -> Self {
         Self {
             inner: crate::gnustl::string::from(data),